@@ -207,6 +207,15 @@ pub fn metaverse_land_minting_config() -> MintingRateInfo {
 	}
 }
 
+pub fn estate_staking_expectation() -> MintingRange<Balance> {
+	// Expected total stake on estates
+	MintingRange {
+		min: 100_000 * DOLLARS,
+		ideal: 1_000_000 * DOLLARS,
+		max: 10_000_000 * DOLLARS,
+	}
+}
+
 /// Configure initial storage state for FRAME modules.
 fn testnet_genesis(
 	initial_authorities: Vec<(AccountId, AuraId, GrandpaId)>,
@@ -276,6 +285,7 @@ fn testnet_genesis(
 		},
 		estate: EstateConfig {
 			minting_rate_config: metaverse_land_minting_config(),
+			staking_expectation: estate_staking_expectation(),
 		},
 		oracle_membership: OracleMembershipConfig {
 			members: vec![],
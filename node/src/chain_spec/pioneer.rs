@@ -14,7 +14,7 @@ use sp_runtime::{
 	Perbill,
 };
 
-use metaverse_runtime::MintingRateInfo;
+use metaverse_runtime::{MintingRange, MintingRateInfo};
 use pioneer_runtime::{
 	constants::currency::*, AccountId, AuraConfig, BalancesConfig, GenesisConfig, SessionKeys, Signature, SudoConfig,
 	SystemConfig, EXISTENTIAL_DEPOSIT, WASM_BINARY,
@@ -141,6 +141,15 @@ pub fn metaverse_land_minting_config() -> MintingRateInfo {
 	}
 }
 
+pub fn estate_staking_expectation() -> MintingRange<Balance> {
+	// Expected total stake on estates
+	MintingRange {
+		min: 100_000 * DOLLARS,
+		ideal: 1_000_000 * DOLLARS,
+		max: 10_000_000 * DOLLARS,
+	}
+}
+
 fn pioneer_genesis(
 	root_key: AccountId,
 	initial_authorities: Vec<(AccountId, AuraId)>,
@@ -189,6 +198,7 @@ fn pioneer_genesis(
 		//		},
 		//		estate: EstateConfig {
 		//			minting_rate_config: metaverse_land_minting_config(),
+		//			staking_expectation: estate_staking_expectation(),
 		//		},
 	}
 }
@@ -245,6 +255,7 @@ fn testnet_genesis(
 		//		},
 		//		estate: EstateConfig {
 		//			minting_rate_config: metaverse_land_minting_config(),
+		//			staking_expectation: estate_staking_expectation(),
 		//		},
 	}
 }
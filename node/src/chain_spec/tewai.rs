@@ -15,8 +15,8 @@ use sp_runtime::{
 use tewai_runtime::{
 	constants::currency::*, wasm_binary_unwrap, AuthorityDiscoveryConfig, BabeConfig, BalancesConfig, Block,
 	ContinuumConfig, CouncilConfig, DemocracyConfig, ElectionsConfig, EstateConfig, GrandpaConfig, ImOnlineConfig,
-	IndicesConfig, MintingRateInfo, SessionConfig, SessionKeys, StakerStatus, StakingConfig, SudoConfig, SystemConfig,
-	TechnicalCommitteeConfig, MAX_NOMINATIONS,
+	IndicesConfig, MintingRange, MintingRateInfo, SessionConfig, SessionKeys, StakerStatus, StakingConfig, SudoConfig,
+	SystemConfig, TechnicalCommitteeConfig, MAX_NOMINATIONS,
 };
 
 pub use primitives::{AccountId, Balance, Signature};
@@ -213,6 +213,15 @@ pub fn metaverse_land_minting_config() -> MintingRateInfo {
 	}
 }
 
+pub fn estate_staking_expectation() -> MintingRange<Balance> {
+	// Expected total stake on estates
+	MintingRange {
+		min: 100_000 * DOLLARS,
+		ideal: 1_000_000 * DOLLARS,
+		max: 10_000_000 * DOLLARS,
+	}
+}
+
 /// Helper function to create GenesisConfig for testing
 pub fn testnet_genesis(
 	initial_authorities: Vec<(
@@ -345,6 +354,7 @@ pub fn testnet_genesis(
 		},
 		estate: EstateConfig {
 			minting_rate_config: metaverse_land_minting_config(),
+			staking_expectation: estate_staking_expectation(),
 		},
 	}
 }
@@ -3,6 +3,7 @@ pub use rpc_metaverse::{create_full, open_frontier_backend, overrides_handle, Fu
 #[cfg(feature = "with-pioneer-runtime")]
 pub use rpc_pioneer::{create_full as pioneer_crate_full, FullDeps as pioneer_fulldeps};
 
+pub mod auction;
 #[cfg(feature = "with-metaverse-runtime")]
 mod rpc_metaverse;
 #[cfg(feature = "with-pioneer-runtime")]
@@ -0,0 +1,113 @@
+//! RPC interface to query auctions and listings through the auction runtime API.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use auction_manager::AuctionApi as AuctionRuntimeApi;
+use auction_manager::{AuctionItem, ListingLevel};
+use primitives::{AuctionId, ItemId};
+
+/// Error code of a failed runtime API call
+const RUNTIME_ERROR: i64 = 1;
+
+#[rpc]
+pub trait AuctionApi<BlockHash, AccountId, Balance, BlockNumber> {
+	/// Active auctions with their item, only the ones at `listing_level` if given
+	#[rpc(name = "auction_activeAuctions")]
+	fn active_auctions(
+		&self,
+		listing_level: Option<ListingLevel<AccountId>>,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(AuctionId, AuctionItem<AccountId, BlockNumber, Balance>)>>;
+
+	/// Current highest bidder and bid price of an auction
+	#[rpc(name = "auction_highestBid")]
+	fn highest_bid(&self, auction_id: AuctionId, at: Option<BlockHash>) -> Result<Option<(AccountId, Balance)>>;
+
+	/// Active auctions an account holds the highest bid or a sealed bid on
+	#[rpc(name = "auction_auctionsBidBy")]
+	fn auctions_bid_by(&self, who: AccountId, at: Option<BlockHash>) -> Result<Vec<AuctionId>>;
+
+	/// Whether an item is listed, on its own or as part of a bundle
+	#[rpc(name = "auction_isItemListed")]
+	fn is_item_listed(&self, item_id: ItemId, at: Option<BlockHash>) -> Result<bool>;
+}
+
+/// Auction RPC handler
+pub struct Auction<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> Auction<C, B> {
+	/// Create a new auction RPC handler
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: "Runtime error".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber> AuctionApi<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber>
+	for Auction<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AuctionRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	AccountId: Codec,
+	Balance: Codec,
+	BlockNumber: Codec,
+{
+	fn active_auctions(
+		&self,
+		listing_level: Option<ListingLevel<AccountId>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(AuctionId, AuctionItem<AccountId, BlockNumber, Balance>)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.active_auctions(&at, listing_level)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn highest_bid(
+		&self,
+		auction_id: AuctionId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<(AccountId, Balance)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.highest_bid(&at, auction_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn auctions_bid_by(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<AuctionId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.auctions_bid_by(&at, who).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn is_item_listed(&self, item_id: ItemId, at: Option<<Block as BlockT>::Hash>) -> Result<bool> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.is_item_listed(&at, item_id).map_err(runtime_error_into_rpc_err)
+	}
+}
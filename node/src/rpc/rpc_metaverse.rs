@@ -26,6 +26,8 @@ use sp_blockchain::{Backend as BlockchainBackend, Error as BlockChainError, Head
 use sp_runtime::traits::BlakeTwo256;
 use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
+use super::auction::{Auction, AuctionApi};
+
 pub fn open_frontier_backend(config: &sc_service::Configuration) -> Result<Arc<fc_db::Backend<Block>>, String> {
 	let config_dir = config
 		.base_path
@@ -116,6 +118,7 @@ where
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
 		+ BlockBuilder<Block>,
 	C::Api: pallet_contracts_rpc::ContractsRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash>,
+	C::Api: super::auction::AuctionRuntimeApi<Block, AccountId, Balance, BlockNumber>,
 	P: TransactionPool<Block = Block> + Sync + Send + 'static,
 	BE: Backend<Block> + 'static,
 	BE::State: StateBackend<BlakeTwo256>,
@@ -198,5 +201,8 @@ where
 	// Contracts RPC API extension
 	io.extend_with(ContractsApi::to_delegate(Contracts::new(client.clone())));
 
+	// Auction RPC API extension
+	io.extend_with(AuctionApi::to_delegate(Auction::new(client.clone())));
+
 	io
 }
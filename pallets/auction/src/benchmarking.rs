@@ -21,7 +21,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::traits::{Currency, Get};
+use frame_support::traits::{Currency, Get, Hooks};
 use frame_system::RawOrigin;
 use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto};
 use sp_runtime::Perbill;
@@ -125,6 +125,10 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 	fn update_metaverse_token(metaverse_id: u64, currency_id: FungibleTokenId) -> Result<(), DispatchError> {
 		Ok(())
 	}
+
+	fn get_land_connectivity(_metaverse_id: u64) -> primitives::estate::LandConnectivity {
+		primitives::estate::LandConnectivity::FourConnected
+	}
 }
 
 benchmarks! {
@@ -152,7 +156,7 @@ benchmarks! {
 		let bidder = funded_account::<T>("bidder", 0);
 		mint_NFT::<T>(caller.clone());
 
-		crate::Pallet::<T>::create_new_auction(RawOrigin::Signed(caller.clone()).into(), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global);
+		crate::Pallet::<T>::create_new_auction(RawOrigin::Signed(caller.clone()).into(), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global, None, FungibleTokenId::NativeToken(0));
 	}: _(RawOrigin::Signed(bidder.clone()), 0u32.into(), 100u32.into())
 
 	// buy_now
@@ -163,8 +167,56 @@ benchmarks! {
 		let bidder = funded_account::<T>("bidder", 0);
 		mint_NFT::<T>(caller.clone());
 
-		crate::Pallet::<T>::create_new_buy_now(RawOrigin::Signed(caller.clone()).into(), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global);
+		crate::Pallet::<T>::create_new_buy_now(RawOrigin::Signed(caller.clone()).into(), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global, FungibleTokenId::NativeToken(0));
 	}: _(RawOrigin::Signed(bidder.clone()), 0u32.into(), 100u32.into())
+
+	// make_offer
+	make_offer{
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		let caller = funded_account::<T>("caller", 0);
+		let offeror = funded_account::<T>("offeror", 0);
+		mint_NFT::<T>(caller.clone());
+	}: _(RawOrigin::Signed(offeror.clone()), ItemId::NFT(0, 0), 100u32.into(), FungibleTokenId::NativeToken(0), 10u32.into())
+
+	// withdraw_offer
+	withdraw_offer{
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		let caller = funded_account::<T>("caller", 0);
+		let offeror = funded_account::<T>("offeror", 0);
+		mint_NFT::<T>(caller.clone());
+
+		crate::Pallet::<T>::make_offer(RawOrigin::Signed(offeror.clone()).into(), ItemId::NFT(0, 0), 100u32.into(), FungibleTokenId::NativeToken(0), 10u32.into());
+	}: _(RawOrigin::Signed(offeror.clone()), ItemId::NFT(0, 0))
+
+	// accept_offer
+	accept_offer{
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		let caller = funded_account::<T>("caller", 0);
+		let offeror = funded_account::<T>("offeror", 0);
+		mint_NFT::<T>(caller.clone());
+
+		crate::Pallet::<T>::make_offer(RawOrigin::Signed(offeror.clone()).into(), ItemId::NFT(0, 0), 100u32.into(), FungibleTokenId::NativeToken(0), 10u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), ItemId::NFT(0, 0), offeror.clone())
+
+	// expire offers in on_initialize
+	expire_offers{
+		let n in 1 .. T::MaxOffersPerBlock::get();
+
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		let caller = funded_account::<T>("caller", 0);
+		mint_NFT::<T>(caller.clone());
+
+		for i in 0 .. n {
+			let offeror = funded_account::<T>("offeror", i);
+			crate::Pallet::<T>::make_offer(RawOrigin::Signed(offeror.clone()).into(), ItemId::NFT(0, 0), 100u32.into(), FungibleTokenId::NativeToken(0), 10u32.into());
+		}
+	}: {
+		crate::Pallet::<T>::on_initialize(10u32.into());
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::benchmarking::tests::new_test_ext(), crate::mock::Test);
@@ -25,20 +25,23 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::traits::{Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency};
+use frame_support::traits::{Currency, LockableCurrency, ReservableCurrency};
 use frame_support::{ensure, pallet_prelude::*, transactional};
 use frame_system::{self as system, ensure_signed};
 use sp_core::sp_std::convert::TryInto;
 use sp_runtime::SaturatedConversion;
 use sp_runtime::{
-	traits::{CheckedDiv, One, Saturating, Zero},
+	traits::{One, Saturating, Zero},
 	DispatchError, DispatchResult, Perbill,
 };
 
-use auction_manager::{Auction, AuctionHandler, AuctionInfo, AuctionItem, AuctionType, Change, OnNewBidResult};
+use auction_manager::{
+	Auction, AuctionHandler, AuctionInfo, AuctionItem, AuctionType, Change, DutchAuctionConfig, Offer, OnNewBidResult,
+	SealedBid,
+};
 pub use pallet::*;
 use pallet_nft::Pallet as NFTModule;
-use primitives::{continuum::Continuum, estate::Estate, AuctionId, ItemId};
+use primitives::{continuum::Continuum, estate::Estate, AuctionId, BundleId, ItemId};
 pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -101,13 +104,14 @@ pub mod migration_v2 {
 pub mod pallet {
 	use frame_support::dispatch::DispatchResultWithPostInfo;
 	use frame_support::log;
-	use frame_support::sp_runtime::traits::CheckedSub;
+	use frame_support::sp_runtime::traits::{CheckedAdd, CheckedSub, Hash};
 	use frame_system::pallet_prelude::OriginFor;
 	use orml_traits::{MultiCurrency, MultiReservableCurrency};
 
 	use auction_manager::{CheckAuctionItemHandler, ListingLevel};
 	use core_primitives::{MetaverseTrait, NFTTrait};
 	use primitives::{AssetId, Balance, ClassId, FungibleTokenId, MetaverseId, TokenId};
+	use sp_std::vec::Vec;
 
 	use crate::migration_v2::V1ItemId;
 
@@ -132,7 +136,7 @@ pub mod pallet {
 			+ LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
 		/// Continuum protocol handler
 		type ContinuumHandler: Continuum<Self::AccountId>;
-		/// Multi-fungible token currency
+		/// Multi-fungible token currency, pays listings in native or any fungible token
 		type FungibleTokenCurrency: MultiReservableCurrency<
 			Self::AccountId,
 			CurrencyId = FungibleTokenId,
@@ -144,13 +148,35 @@ pub mod pallet {
 		type MinimumAuctionDuration: Get<Self::BlockNumber>;
 		/// Handle Estate logic
 		type EstateHandler: Estate<Self::AccountId>;
-		/// Loyalty fee in percentage applied NFT promotion
-		#[pallet::constant]
-		type RoyaltyFee: Get<u16>;
 		#[pallet::constant]
 		type MaxFinality: Get<u32>;
+		/// Soft close window before the auction end. A bid placed within this window extends the
+		/// auction end to the bid block plus this window. Zero disables the extension.
+		#[pallet::constant]
+		type AntiSnipeDuration: Get<Self::BlockNumber>;
+		/// Deposit reserved with each sealed bid commitment
+		#[pallet::constant]
+		type SealedBidDeposit: Get<BalanceOf<Self>>;
+		/// Length of the reveal phase that follows the bidding phase of a sealed bid auction
+		#[pallet::constant]
+		type SealedBidRevealDuration: Get<Self::BlockNumber>;
+		/// Maximum number of sealed bids per auction
+		#[pallet::constant]
+		type MaxSealedBids: Get<u32>;
+		/// Maximum number of items in a bundle
+		#[pallet::constant]
+		type MaxBundleItems: Get<u32>;
+		/// Blocks after a failed settlement during which it can be retried before it can be
+		/// abandoned
+		#[pallet::constant]
+		type SettlementTimeout: Get<Self::BlockNumber>;
 		/// NFT Handler
 		type NFTHandler: NFTTrait<Self::AccountId, BalanceOf<Self>, ClassId = ClassId, TokenId = TokenId>;
+		/// Maximum number of offers expiring in the same block
+		#[pallet::constant]
+		type MaxOffersPerBlock: Get<u32>;
+		/// Weight implementation for auction extrinsics
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::storage]
@@ -181,6 +207,71 @@ pub mod pallet {
 	pub(super) type AuctionEndTime<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, AuctionId, (), OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn dutch_auction_config)]
+	/// Price schedule of Dutch auctions
+	pub(super) type DutchAuctionConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, DutchAuctionConfig<BalanceOf<T>, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn reserve_price)]
+	/// Optional reserve price of auctions, the item is not sold if the highest bid is lower
+	pub(super) type ReservePrices<T: Config> = StorageMap<_, Twox64Concat, AuctionId, BalanceOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sealed_bid_commit_end)]
+	/// End of the bidding phase of sealed bid auctions, the reveal phase runs until the auction end
+	pub(super) type SealedBidCommitEnd<T: Config> = StorageMap<_, Twox64Concat, AuctionId, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sealed_bids)]
+	/// Sealed bids of each auction by bidder
+	pub(super) type SealedBids<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionId,
+		Twox64Concat,
+		T::AccountId,
+		SealedBid<T::Hash, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_settlement)]
+	/// Ended auctions that could not be settled, with the high bidder and price to retry with and
+	/// the block from which the settlement can be abandoned
+	pub(super) type PendingSettlements<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, (T::AccountId, BalanceOf<T>, T::BlockNumber), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn offers)]
+	/// Offers on unlisted items by offeror
+	pub(super) type Offers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ItemId,
+		Twox64Concat,
+		T::AccountId,
+		Offer<BalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn offer_end_time)]
+	/// Index offers by expiry block.
+	pub(super) type OfferEndTime<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, (ItemId, T::AccountId), (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bundles)]
+	/// Items of each bundle, a bundle is listed and sold as one lot
+	pub(super) type Bundles<T: Config> = StorageMap<_, Twox64Concat, BundleId, Vec<ItemId>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bundles_index)]
+	/// Track the next bundle ID.
+	pub(super) type BundlesIndex<T: Config> = StorageValue<_, BundleId, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn authorised_metaverse_collection)]
 	/// Local marketplace collection authorisation
@@ -205,6 +296,34 @@ pub mod pallet {
 		AuctionFinalizedNoBid(AuctionId),
 		CollectionAuthorizedInMetaverse(ClassId, MetaverseId),
 		CollectionAuthorizationRemoveInMetaverse(ClassId, MetaverseId),
+		/// Auction end is extended by a late bid. [auction_id, new_end_block]
+		AuctionExtended(AuctionId, T::BlockNumber),
+		/// A sealed bid is committed. [auction_id, bidder]
+		SealedBidCommitted(AuctionId, T::AccountId),
+		/// A sealed bid is revealed. [auction_id, bidder, bidding_amount]
+		SealedBidRevealed(AuctionId, T::AccountId, BalanceOf<T>),
+		/// Deposit of a sealed bid that was never revealed is slashed. [auction_id, bidder, deposit]
+		SealedBidDepositSlashed(AuctionId, T::AccountId, BalanceOf<T>),
+		/// Auction ended without sale as the reserve price is not met. [auction_id, high_bidder,
+		/// high_bid_price]
+		AuctionFinalizedReserveNotMet(AuctionId, T::AccountId, BalanceOf<T>),
+		/// Listing is cancelled by the seller. [auction_id, seller]
+		AuctionCancelled(AuctionId, T::AccountId),
+		/// Ended auction could not be settled and is kept for retry. [auction_id, error]
+		SettlementFailed(AuctionId, DispatchError),
+		/// Failed settlement is abandoned, the bid is released and the item unlocked. [auction_id,
+		/// high_bidder, high_bid_price]
+		SettlementAbandoned(AuctionId, T::AccountId, BalanceOf<T>),
+		/// An offer is made on an unlisted item. [item_id, offeror, amount, currency_id]
+		OfferMade(ItemId, T::AccountId, BalanceOf<T>, FungibleTokenId),
+		/// An offer is accepted by the item owner. [item_id, owner, offeror, amount]
+		OfferAccepted(ItemId, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// An offer is withdrawn by the offeror. [item_id, offeror]
+		OfferWithdrawn(ItemId, T::AccountId),
+		/// An offer expired and its funds are unreserved. [item_id, offeror]
+		OfferExpired(ItemId, T::AccountId),
+		/// A bundle of items is created. [bundle_id, owner, items]
+		BundleCreated(BundleId, T::AccountId, Vec<ItemId>),
 	}
 
 	/// Errors inform users that something went wrong.
@@ -244,12 +363,66 @@ pub mod pallet {
 		CollectionAlreadyAuthorised,
 		/// Collection is not authorised
 		CollectionIsNotAuthorised,
+		/// Floor price of Dutch auction is higher than the start price
+		InvalidDutchAuctionPrice,
+		/// Price step of Dutch auction is zero or longer than the auction
+		InvalidDutchAuctionPriceStep,
+		/// Sealed bid auction is not in its bidding phase
+		NotInBiddingPhase,
+		/// Sealed bid auction is not in its reveal phase
+		NotInRevealPhase,
+		/// Bidder has already committed a sealed bid
+		SealedBidAlreadyCommitted,
+		/// Sealed bid does not exist
+		SealedBidNotFound,
+		/// Sealed bid has already been revealed
+		SealedBidAlreadyRevealed,
+		/// Revealed amount and salt do not match the commitment
+		InvalidSealedBidReveal,
+		/// Auction has reached the maximum number of sealed bids
+		TooManySealedBids,
+		/// Reserve price is lower than the starting price
+		InvalidReservePrice,
+		/// Only the seller can cancel a listing
+		NoPermissionToCancelAuction,
+		/// Listing has bids and can not be cancelled
+		AuctionHasBids,
+		/// Auction has no failed settlement to retry
+		SettlementNotPending,
+		/// Failed settlement can still be retried and can not be abandoned yet
+		SettlementNotExpired,
+		/// Offeror already has an offer on this item
+		OfferAlreadyExists,
+		/// Offer does not exist
+		OfferNotFound,
+		/// Offer has expired
+		OfferIsExpired,
+		/// Offer end block is not in the future
+		InvalidOfferEndBlock,
+		/// Item owner can not make an offer on the item
+		NoPermissionToMakeOffer,
+		/// Maximum number of offers expiring in the offer end block has been reached
+		TooManyOffersInBlock,
+		/// Only the item owner can accept an offer
+		NoPermissionToAcceptOffer,
+		/// Bundle does not exist
+		BundleNotExist,
+		/// Bundle is empty, too large, nested or has duplicate items
+		InvalidBundle,
+		/// Only the owner of every item can bundle them
+		NoPermissionToCreateBundle,
+		/// No available bundle id
+		NoAvailableBundleId,
+		/// Soulbound or equipped NFTs can not be listed or traded
+		ItemIsNotTransferable,
+		/// Leased estates and land units can not be listed
+		ItemIsLeased,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// User can bid on listing
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[pallet::weight(T::WeightInfo::bid())]
 		#[transactional]
 		pub fn bid(origin: OriginFor<T>, id: AuctionId, value: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
@@ -285,15 +458,43 @@ pub mod pallet {
 				ensure!(bid_result.accept_bid, Error::<T>::BidNotAccepted);
 
 				ensure!(
-					<T as Config>::Currency::free_balance(&from) >= value,
+					Self::free_balance_in_currency(auction_item.currency_id, &from) >= value,
 					Error::<T>::InsufficientFreeBalance
 				);
 
-				Self::auction_bid_handler(block_number, id, (from.clone(), value), auction.bid.clone())?;
+				if auction_item.currency_id == FungibleTokenId::NativeToken(0) {
+					Self::auction_bid_handler(block_number, id, (from.clone(), value), auction.bid.clone())?;
+				} else {
+					Self::local_auction_bid_handler(
+						block_number,
+						id,
+						(from.clone(), value),
+						auction.bid.clone(),
+						auction_item.currency_id,
+					)?;
+				}
 
 				auction.bid = Some((from.clone(), value));
 				Self::deposit_event(Event::Bid(id, from, value));
 
+				// Soft close - move the auction end forward if the bid landed close to the end
+				if let Change::NewValue(Some(new_end)) = bid_result.auction_end_change {
+					if let Some(current_end) = auction.end {
+						if new_end > current_end {
+							<AuctionEndTime<T>>::remove(&current_end, id);
+							<AuctionEndTime<T>>::insert(&new_end, id, ());
+							<AuctionItems<T>>::mutate(id, |maybe_auction_item| {
+								if let Some(auction_item) = maybe_auction_item {
+									auction_item.end_time = new_end;
+								}
+							});
+							auction.end = Some(new_end);
+
+							Self::deposit_event(Event::AuctionExtended(id, new_end));
+						}
+					}
+				}
+
 				Ok(())
 			})?;
 
@@ -301,7 +502,8 @@ pub mod pallet {
 		}
 
 		/// User can buy now on listing
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[pallet::weight(T::WeightInfo::buy_now())]
+		#[transactional]
 		pub fn buy_now(origin: OriginFor<T>, auction_id: AuctionId, value: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
@@ -309,7 +511,7 @@ pub mod pallet {
 			let auction_item = Self::get_auction_item(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
 
 			ensure!(
-				auction_item.auction_type == AuctionType::BuyNow,
+				matches!(auction_item.auction_type, AuctionType::BuyNow | AuctionType::Dutch),
 				Error::<T>::InvalidAuctionType
 			);
 
@@ -322,102 +524,116 @@ pub mod pallet {
 				ensure!(block_number < auction_end, Error::<T>::AuctionIsExpired);
 			}
 
-			ensure!(value == auction_item.amount, Error::<T>::InvalidBuyItNowPrice);
+			let value = if auction_item.auction_type == AuctionType::Dutch {
+				if let ListingLevel::NetworkSpot(allowed_buyers) = &auction_item.listing_level {
+					ensure!(allowed_buyers.contains(&from), Error::<T>::BidNotAccepted);
+				}
+
+				// Buyer pays the current price, value is the highest price the buyer accepts
+				let current_price =
+					Self::dutch_auction_price(auction_id, block_number).ok_or(Error::<T>::AuctionNotExist)?;
+				ensure!(value >= current_price, Error::<T>::InvalidBuyItNowPrice);
+				current_price
+			} else {
+				ensure!(value == auction_item.amount, Error::<T>::InvalidBuyItNowPrice);
+				value
+			};
+
 			ensure!(
-				<T as Config>::Currency::free_balance(&from) >= value,
+				Self::free_balance_in_currency(auction_item.currency_id, &from) >= value,
 				Error::<T>::InsufficientFunds
 			);
 
-			Self::remove_auction(auction_id.clone(), auction_item.item_id);
-
 			// Transfer balance from buy it now user to asset owner
-			let currency_transfer = <T as Config>::Currency::transfer(
-				&from,
-				&auction_item.recipient,
+			Self::transfer_in_currency(auction_item.currency_id, &from, &auction_item.recipient, value)?;
+
+			Self::collect_listing_royalty_fee(
 				value,
-				ExistenceRequirement::KeepAlive,
-			);
-			match currency_transfer {
-				Err(_e) => {}
-				Ok(_v) => {
-					// Transfer asset from asset owner to buy it now user
-					<ItemsInAuction<T>>::remove(auction_item.item_id);
-					match auction_item.item_id {
-						ItemId::NFT(class_id, token_id) => {
-							Self::collect_royalty_fee(
-								&value,
-								&auction_item.recipient,
-								&(class_id, token_id),
-								FungibleTokenId::NativeToken(0),
-							);
+				&auction_item.recipient,
+				auction_item.item_id,
+				auction_item.currency_id,
+			)?;
+
+			// Transfer asset from asset owner to buy it now user
+			Self::transfer_auction_item(auction_item.item_id, &auction_item.recipient, &from)?;
+
+			Self::remove_auction(auction_id.clone(), auction_item.item_id);
+			Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
+
+			Ok(().into())
+		}
+
+		/// Retry the settlement of an ended auction that could not be finalized
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn retry_settlement(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let (high_bidder, high_bid_price, _) =
+				Self::pending_settlement(auction_id).ok_or(Error::<T>::SettlementNotPending)?;
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+
+			Self::settle_auction(&auction_item, &high_bidder, high_bid_price)?;
+
+			PendingSettlements::<T>::remove(auction_id);
+			Self::remove_auction(auction_id, auction_item.item_id);
+			Self::deposit_event(Event::AuctionFinalized(auction_id, high_bidder, high_bid_price));
 
-							let asset_transfer =
-								T::NFTHandler::transfer_nft(&auction_item.recipient, &from, &(class_id, token_id));
-							match asset_transfer {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::Spot(spot_id, metaverse_id) => {
-							let continuum_spot = T::ContinuumHandler::transfer_spot(
-								spot_id,
-								&auction_item.recipient,
-								&(from.clone(), metaverse_id),
-							);
-							match continuum_spot {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::Estate(estate_id) => {
-							let estate =
-								T::EstateHandler::transfer_estate(estate_id, &auction_item.recipient, &from.clone());
-							match estate {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::LandUnit(coordinate, metaverse_id) => {
-							let land_unit = T::EstateHandler::transfer_landunit(
-								coordinate,
-								&auction_item.recipient,
-								&(from.clone(), metaverse_id),
-							);
-							match land_unit {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						_ => {} // Future implementation for Land, Metaverse
-					}
-				}
-			}
 			Ok(().into())
 		}
 
+		/// Abandon a failed settlement once its retry window has passed. The high bid is released
+		/// and the item is unlocked for the seller.
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn abandon_settlement(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let (high_bidder, high_bid_price, abandon_at) =
+				Self::pending_settlement(auction_id).ok_or(Error::<T>::SettlementNotPending)?;
+			ensure!(
+				<system::Pallet<T>>::block_number() >= abandon_at,
+				Error::<T>::SettlementNotExpired
+			);
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+
+			Self::unreserve_in_currency(auction_item.currency_id, &high_bidder, high_bid_price);
+
+			PendingSettlements::<T>::remove(auction_id);
+			Self::remove_auction(auction_id, auction_item.item_id);
+			Self::deposit_event(Event::SettlementAbandoned(auction_id, high_bidder, high_bid_price));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::create_new_auction())]
 		pub fn create_new_auction(
 			origin: OriginFor<T>,
 			item_id: ItemId,
 			value: BalanceOf<T>,
 			end_time: T::BlockNumber,
 			listing_level: ListingLevel<T::AccountId>,
+			reserve_price: Option<BalanceOf<T>>,
+			currency_id: FungibleTokenId,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
 			ensure!(
-				matches!(item_id, ItemId::NFT(_, _)),
+				matches!(
+					item_id,
+					ItemId::NFT(_, _)
+						| ItemId::Estate(_)
+						| ItemId::LandUnit(_, _)
+						| ItemId::Spot(_, _)
+						| ItemId::Bundle(_)
+				),
 				Error::<T>::NoPermissionToCreateAuction
 			);
 
+			if let Some(reserve_price) = reserve_price {
+				ensure!(reserve_price >= value, Error::<T>::InvalidReservePrice);
+			}
+
 			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
 
 			let remaining_time: T::BlockNumber = end_time.checked_sub(&start_time).ok_or(Error::<T>::Overflow)?;
@@ -427,7 +643,7 @@ pub mod pallet {
 				Error::<T>::AuctionEndIsLessThanMinimumDuration
 			);
 
-			Self::create_auction(
+			let auction_id = Self::create_auction(
 				AuctionType::Auction,
 				item_id,
 				Some(end_time),
@@ -435,21 +651,49 @@ pub mod pallet {
 				value.clone(),
 				start_time,
 				listing_level.clone(),
+				currency_id,
 			)?;
+
+			if let Some(reserve_price) = reserve_price {
+				ReservePrices::<T>::insert(auction_id, reserve_price);
+			}
+
 			Ok(().into())
 		}
 
+		/// Seller can cancel a listing before the first bid
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn cancel_listing(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+
+			ensure!(auction_item.recipient == from, Error::<T>::NoPermissionToCancelAuction);
+			ensure!(
+				auction.bid.is_none() && SealedBids::<T>::iter_prefix(auction_id).next().is_none(),
+				Error::<T>::AuctionHasBids
+			);
+
+			Self::remove_auction(auction_id, auction_item.item_id);
+			Self::deposit_event(Event::AuctionCancelled(auction_id, from));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::create_new_buy_now())]
 		pub fn create_new_buy_now(
 			origin: OriginFor<T>,
 			item_id: ItemId,
 			value: BalanceOf<T>,
 			end_time: T::BlockNumber,
 			listing_level: ListingLevel<T::AccountId>,
+			currency_id: FungibleTokenId,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 			ensure!(
-				matches!(item_id, ItemId::NFT(_, _)),
+				matches!(item_id, ItemId::NFT(_, _) | ItemId::Bundle(_)),
 				Error::<T>::NoPermissionToCreateAuction
 			);
 
@@ -469,402 +713,683 @@ pub mod pallet {
 				value.clone(),
 				start_time,
 				listing_level.clone(),
+				currency_id,
 			)?;
 
 			Ok(().into())
 		}
 
+		/// Create a Dutch auction, the price falls from `start_price` to `floor_price` by
+		/// `end_time`, dropping every `price_step` blocks. The first buyer pays the current price.
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn authorise_metaverse_collection(
+		pub fn create_new_dutch_auction(
 			origin: OriginFor<T>,
-			class_id: ClassId,
-			metaverse_id: MetaverseId,
+			item_id: ItemId,
+			start_price: BalanceOf<T>,
+			floor_price: BalanceOf<T>,
+			end_time: T::BlockNumber,
+			price_step: T::BlockNumber,
+			listing_level: ListingLevel<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
+
 			ensure!(
-				T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
-				Error::<T>::NoPermissionToAuthoriseCollection
+				matches!(
+					item_id,
+					ItemId::NFT(_, _)
+						| ItemId::Estate(_)
+						| ItemId::LandUnit(_, _)
+						| ItemId::Spot(_, _)
+						| ItemId::Bundle(_)
+				),
+				Error::<T>::NoPermissionToCreateAuction
 			);
 
 			ensure!(
-				!MetaverseCollection::<T>::contains_key(metaverse_id, class_id),
-				Error::<T>::CollectionAlreadyAuthorised
+				!floor_price.is_zero() && floor_price <= start_price,
+				Error::<T>::InvalidDutchAuctionPrice
 			);
 
-			MetaverseCollection::<T>::insert(metaverse_id.clone(), class_id.clone(), ());
+			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
+			let remaining_time: T::BlockNumber = end_time.checked_sub(&start_time).ok_or(Error::<T>::Overflow)?;
 
-			Self::deposit_event(Event::<T>::CollectionAuthorizedInMetaverse(class_id, metaverse_id));
+			ensure!(
+				remaining_time >= T::MinimumAuctionDuration::get(),
+				Error::<T>::AuctionEndIsLessThanMinimumDuration
+			);
+
+			ensure!(
+				!price_step.is_zero() && price_step <= remaining_time,
+				Error::<T>::InvalidDutchAuctionPriceStep
+			);
+
+			let auction_id = Self::create_auction(
+				AuctionType::Dutch,
+				item_id,
+				Some(end_time),
+				from.clone(),
+				start_price.clone(),
+				start_time,
+				listing_level.clone(),
+				FungibleTokenId::NativeToken(0),
+			)?;
+
+			DutchAuctionConfigs::<T>::insert(
+				auction_id,
+				DutchAuctionConfig {
+					floor_price,
+					price_step,
+				},
+			);
 
 			Ok(().into())
 		}
 
+		/// Create a sealed bid auction. Bids are committed until `end_time`, then revealed during
+		/// the reveal phase. The highest revealed bid wins and pays the second highest price.
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn remove_authorise_metaverse_collection(
+		pub fn create_new_sealed_bid_auction(
 			origin: OriginFor<T>,
-			class_id: ClassId,
-			metaverse_id: MetaverseId,
+			item_id: ItemId,
+			reserve_price: BalanceOf<T>,
+			end_time: T::BlockNumber,
+			listing_level: ListingLevel<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
+
 			ensure!(
-				T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
-				Error::<T>::NoPermissionToAuthoriseCollection
+				matches!(
+					item_id,
+					ItemId::NFT(_, _)
+						| ItemId::Estate(_)
+						| ItemId::LandUnit(_, _)
+						| ItemId::Spot(_, _)
+						| ItemId::Bundle(_)
+				),
+				Error::<T>::NoPermissionToCreateAuction
 			);
 
+			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
+			let remaining_time: T::BlockNumber = end_time.checked_sub(&start_time).ok_or(Error::<T>::Overflow)?;
+
 			ensure!(
-				MetaverseCollection::<T>::contains_key(metaverse_id, class_id),
-				Error::<T>::CollectionIsNotAuthorised
+				remaining_time >= T::MinimumAuctionDuration::get(),
+				Error::<T>::AuctionEndIsLessThanMinimumDuration
 			);
 
-			MetaverseCollection::<T>::remove(metaverse_id.clone(), class_id.clone());
-			Self::deposit_event(Event::<T>::CollectionAuthorizationRemoveInMetaverse(
-				class_id,
-				metaverse_id,
-			));
-			Ok(().into())
-		}
-	}
-
-	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
-		fn on_finalize(now: T::BlockNumber) {
-			let max_finality = T::MaxFinality::get();
-			let mut proceeded_item: u32 = 0;
-			for (auction_id, _) in <AuctionEndTime<T>>::drain_prefix(&now) {
-				if proceeded_item == max_finality {
-					break;
-				};
-				if let Some(auction) = <Auctions<T>>::get(&auction_id) {
-					if let Some(auction_item) = <AuctionItems<T>>::get(&auction_id) {
-						proceeded_item.checked_add(One::one()).ok_or("Overflow");
-						Self::remove_auction(auction_id.clone(), auction_item.item_id);
-						// Transfer balance from high bidder to asset owner
-						if let Some(current_bid) = auction.bid {
-							let (high_bidder, high_bid_price): (T::AccountId, BalanceOf<T>) = current_bid;
-							// Handle listing
-							<T as Config>::Currency::unreserve(&high_bidder, high_bid_price);
-
-							// Handle balance transfer
-							let currency_transfer = <T as Config>::Currency::transfer(
-								&high_bidder,
-								&auction_item.recipient,
-								high_bid_price,
-								ExistenceRequirement::KeepAlive,
-							);
+			// Auction is finalized at the end of the reveal phase
+			let reveal_end = end_time
+				.checked_add(&T::SealedBidRevealDuration::get())
+				.ok_or(Error::<T>::Overflow)?;
 
-							match currency_transfer {
-								Err(_e) => continue,
-								Ok(_v) => {
-									// Transfer asset from asset owner to high bidder
-									// Check asset type and handle internal logic
-
-									match auction_item.item_id {
-										ItemId::NFT(class_id, token_id) => {
-											Self::collect_royalty_fee(
-												&high_bid_price,
-												&auction_item.recipient,
-												&(class_id, token_id),
-												FungibleTokenId::NativeToken(0),
-											);
-											let asset_transfer = T::NFTHandler::transfer_nft(
-												&auction_item.recipient,
-												&high_bidder,
-												&(class_id, token_id),
-											);
-
-											match asset_transfer {
-												Err(_) => continue,
-												Ok(_) => {
-													Self::deposit_event(Event::AuctionFinalized(
-														auction_id,
-														high_bidder,
-														high_bid_price,
-													));
-												}
-											}
-										}
-										ItemId::Spot(spot_id, metaverse_id) => {
-											let continuum_spot = T::ContinuumHandler::transfer_spot(
-												spot_id,
-												&auction_item.recipient,
-												&(high_bidder.clone(), metaverse_id),
-											);
-											match continuum_spot {
-												Err(_) => continue,
-												Ok(_) => {
-													Self::deposit_event(Event::AuctionFinalized(
-														auction_id,
-														high_bidder,
-														high_bid_price,
-													));
-												}
-											}
-										}
-										ItemId::Estate(estate_id) => {
-											let estate = T::EstateHandler::transfer_estate(
-												estate_id,
-												&auction_item.recipient,
-												&high_bidder.clone(),
-											);
-											match estate {
-												Err(_) => (),
-												Ok(_) => {
-													Self::deposit_event(Event::AuctionFinalized(
-														auction_id,
-														high_bidder,
-														high_bid_price,
-													));
-												}
-											}
-										}
-										ItemId::LandUnit(coordinate, metaverse_id) => {
-											let land_unit = T::EstateHandler::transfer_landunit(
-												coordinate,
-												&auction_item.recipient,
-												&(high_bidder.clone(), metaverse_id),
-											);
-											match land_unit {
-												Err(_) => (),
-												Ok(_) => {
-													Self::deposit_event(Event::AuctionFinalized(
-														auction_id,
-														high_bidder,
-														high_bid_price,
-													));
-												}
-											}
-										}
-										_ => {} // Future implementation for Spot, Metaverse
-									}
-									<ItemsInAuction<T>>::remove(auction_item.item_id);
-								}
-							}
-						} else {
-							Self::deposit_event(Event::AuctionFinalizedNoBid(auction_id));
-						}
-					}
-				};
-			}
-		}
-	}
+			let auction_id = Self::create_auction(
+				AuctionType::SealedBid,
+				item_id,
+				Some(reveal_end),
+				from.clone(),
+				reserve_price.clone(),
+				start_time,
+				listing_level.clone(),
+				FungibleTokenId::NativeToken(0),
+			)?;
 
-	impl<T: Config> Auction<T::AccountId, T::BlockNumber> for Pallet<T> {
-		type Balance = BalanceOf<T>;
+			SealedBidCommitEnd::<T>::insert(auction_id, end_time);
 
-		fn update_auction(
-			id: AuctionId,
-			info: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber>,
-		) -> DispatchResult {
-			let auction = <Auctions<T>>::get(id).ok_or(Error::<T>::AuctionNotExist)?;
-			if let Some(old_end) = auction.end {
-				<AuctionEndTime<T>>::remove(&old_end, id);
-			}
-			if let Some(new_end) = info.end {
-				<AuctionEndTime<T>>::insert(&new_end, id, ());
-			}
-			<Auctions<T>>::insert(id, info);
-			Ok(())
+			Ok(().into())
 		}
 
-		fn new_auction(
-			_recipient: T::AccountId,
-			_initial_amount: Self::Balance,
-			start: T::BlockNumber,
-			end: Option<T::BlockNumber>,
-		) -> Result<AuctionId, DispatchError> {
-			let auction: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber> =
-				AuctionInfo { bid: None, start, end };
+		/// Commit a sealed bid during the bidding phase. `commitment` is the hash of the bidder
+		/// account, bid amount and salt.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn commit_sealed_bid(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			commitment: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
 
-			let auction_id: AuctionId = AuctionsIndex::<T>::try_mutate(|n| -> Result<AuctionId, DispatchError> {
-				let id = *n;
-				ensure!(id != AuctionId::max_value(), Error::<T>::NoAvailableAuctionId);
-				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuctionId)?;
-				Ok(id)
-			})?;
+			let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
 
-			<Auctions<T>>::insert(auction_id, auction);
+			ensure!(
+				auction_item.auction_type == AuctionType::SealedBid,
+				Error::<T>::InvalidAuctionType
+			);
+			ensure!(auction_item.recipient != from, Error::<T>::CannotBidOnOwnAuction);
 
-			if let Some(end_block) = end {
-				<AuctionEndTime<T>>::insert(&end_block, auction_id, ());
+			if let ListingLevel::NetworkSpot(allowed_bidders) = &auction_item.listing_level {
+				ensure!(allowed_bidders.contains(&from), Error::<T>::BidNotAccepted);
 			}
 
-			Ok(auction_id)
-		}
+			let block_number = <system::Pallet<T>>::block_number();
+			ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
+
+			let commit_end = Self::sealed_bid_commit_end(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(block_number < commit_end, Error::<T>::NotInBiddingPhase);
 
-		fn create_auction(
-			auction_type: AuctionType,
-			item_id: ItemId,
-			_end: Option<T::BlockNumber>,
-			recipient: T::AccountId,
-			initial_amount: Self::Balance,
-			_start: T::BlockNumber,
-			listing_level: ListingLevel<T::AccountId>,
-		) -> Result<AuctionId, DispatchError> {
 			ensure!(
-				Self::items_in_auction(item_id) == None,
-				Error::<T>::ItemAlreadyInAuction
+				!SealedBids::<T>::contains_key(auction_id, &from),
+				Error::<T>::SealedBidAlreadyCommitted
+			);
+			ensure!(
+				(SealedBids::<T>::iter_prefix(auction_id).count() as u32) < T::MaxSealedBids::get(),
+				Error::<T>::TooManySealedBids
 			);
 
-			match item_id {
-				ItemId::NFT(class_id, token_id) => {
-					// Check ownership
-					let is_owner = T::NFTHandler::check_ownership(&recipient, &(class_id, token_id))?;
+			let deposit = T::SealedBidDeposit::get();
+			<T as Config>::Currency::reserve(&from, deposit)?;
 
-					ensure!(is_owner == true, Error::<T>::NoPermissionToCreateAuction);
+			SealedBids::<T>::insert(
+				auction_id,
+				&from,
+				SealedBid {
+					commitment,
+					deposit,
+					revealed: None,
+				},
+			);
 
-					let is_transferable = T::NFTHandler::is_transferable(&(class_id, token_id))?;
+			Self::deposit_event(Event::SealedBidCommitted(auction_id, from));
 
-					ensure!(is_transferable == true, Error::<T>::NoPermissionToCreateAuction);
+			Ok(().into())
+		}
 
-					// Ensure NFT authorised to sell
-					match listing_level {
-						ListingLevel::Local(metaverse_id) => {
-							ensure!(
-								MetaverseCollection::<T>::contains_key(metaverse_id, class_id)
-									|| T::MetaverseInfoSource::check_ownership(&recipient, &metaverse_id),
-								Error::<T>::NoPermissionToCreateAuction
-							);
-						}
-						_ => {}
-					}
+		/// Reveal a sealed bid during the reveal phase. The bid amount is reserved until the
+		/// auction is finalized.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn reveal_sealed_bid(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			value: BalanceOf<T>,
+			salt: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
 
-					let start_time = <system::Pallet<T>>::block_number();
+			let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
 
-					let mut end_time = start_time + T::AuctionTimeToClose::get();
-					if let Some(_end_block) = _end {
-						end_time = _end_block
+			ensure!(
+				auction_item.auction_type == AuctionType::SealedBid,
+				Error::<T>::InvalidAuctionType
+			);
+
+			let block_number = <system::Pallet<T>>::block_number();
+			let commit_end = Self::sealed_bid_commit_end(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(
+				block_number >= commit_end && auction.end.map_or(false, |end| block_number < end),
+				Error::<T>::NotInRevealPhase
+			);
+
+			SealedBids::<T>::try_mutate(auction_id, &from, |maybe_sealed_bid| -> DispatchResult {
+				let sealed_bid = maybe_sealed_bid.as_mut().ok_or(Error::<T>::SealedBidNotFound)?;
+
+				ensure!(sealed_bid.revealed.is_none(), Error::<T>::SealedBidAlreadyRevealed);
+				ensure!(
+					T::Hashing::hash_of(&(&from, value, &salt)) == sealed_bid.commitment,
+					Error::<T>::InvalidSealedBidReveal
+				);
+				ensure!(
+					!value.is_zero() && value >= auction_item.initial_amount,
+					Error::<T>::InvalidBidPrice
+				);
+
+				Self::reserve_in_currency(auction_item.currency_id, &from, value)?;
+				sealed_bid.revealed = Some(value);
+
+				Ok(())
+			})?;
+
+			// Track the highest revealed bid, earlier reveals win ties
+			if auction
+				.bid
+				.as_ref()
+				.map_or(true, |(_, highest_bid)| value > *highest_bid)
+			{
+				<Auctions<T>>::mutate(auction_id, |maybe_auction| {
+					if let Some(auction) = maybe_auction {
+						auction.bid = Some((from.clone(), value));
 					}
-					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
-					let mut currency_id: FungibleTokenId = FungibleTokenId::NativeToken(0);
-
-					let new_auction_item = AuctionItem {
-						item_id,
-						recipient: recipient.clone(),
-						initial_amount: initial_amount,
-						amount: initial_amount,
-						start_time,
-						end_time,
-						auction_type,
-						listing_level: listing_level.clone(),
-						currency_id,
-					};
-
-					<AuctionItems<T>>::insert(auction_id, new_auction_item);
-
-					Self::deposit_event(Event::NewAuctionItem(
-						auction_id,
-						recipient,
-						listing_level,
-						initial_amount,
-						initial_amount,
-						end_time,
-					));
-					<ItemsInAuction<T>>::insert(item_id, true);
-					Ok(auction_id)
-				}
-				ItemId::Spot(_spot_id, _metaverse_id) => {
-					let start_time = <system::Pallet<T>>::block_number();
-					let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get();
-					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
-
-					let new_auction_item = AuctionItem {
-						item_id,
-						recipient: recipient.clone(),
-						initial_amount,
-						amount: initial_amount,
-						start_time,
-						end_time,
-						auction_type,
-						listing_level: listing_level.clone(),
-						currency_id: FungibleTokenId::NativeToken(0),
-					};
-
-					<AuctionItems<T>>::insert(auction_id, new_auction_item);
-
-					Self::deposit_event(Event::NewAuctionItem(
-						auction_id,
-						recipient,
-						listing_level,
-						initial_amount,
-						initial_amount,
-						end_time,
-					));
-					<ItemsInAuction<T>>::insert(item_id, true);
-					Ok(auction_id)
-				}
-				ItemId::Estate(_estate_id_) => {
-					// Ensure the _estate_id_ exist/minted
+				});
+			}
+
+			Self::deposit_event(Event::SealedBidRevealed(auction_id, from, value));
+
+			Ok(().into())
+		}
+
+		/// Make an offer on an item that is not listed. The offered amount is reserved until the
+		/// offer is accepted, withdrawn or expires at `end_block`.
+		#[pallet::weight(T::WeightInfo::make_offer())]
+		#[transactional]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			item_id: ItemId,
+			amount: BalanceOf<T>,
+			currency_id: FungibleTokenId,
+			end_block: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			ensure!(
+				Self::items_in_auction(item_id) == None,
+				Error::<T>::ItemAlreadyInAuction
+			);
+			match item_id {
+				ItemId::Estate(estate_id) => {
 					ensure!(
-						T::EstateHandler::check_estate(_estate_id_)?,
+						T::EstateHandler::check_estate(estate_id)?,
 						Error::<T>::EstateDoesNotExist
 					);
-
-					let start_time = <system::Pallet<T>>::block_number();
-					let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get(); // add 7 days block for default auction
-					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
-
-					let new_auction_item = AuctionItem {
-						item_id,
-						recipient: recipient.clone(),
-						initial_amount,
-						amount: initial_amount,
-						start_time,
-						end_time,
-						auction_type,
-						listing_level: ListingLevel::Global,
-						currency_id: FungibleTokenId::NativeToken(0),
-					};
-
-					<AuctionItems<T>>::insert(auction_id, new_auction_item);
-
-					Self::deposit_event(Event::NewAuctionItem(
-						auction_id,
-						recipient,
-						listing_level,
-						initial_amount,
-						initial_amount,
-						end_time,
-					));
-					<ItemsInAuction<T>>::insert(item_id, true);
-					Ok(auction_id)
 				}
-				ItemId::LandUnit(_coordinate_, _metaverse_id_) => {
-					// Ensure the _coordinate_ exist/minted
+				ItemId::LandUnit(coordinate, metaverse_id) => {
 					ensure!(
-						T::EstateHandler::check_landunit(_metaverse_id_, _coordinate_)?,
+						T::EstateHandler::check_landunit(metaverse_id, coordinate)?,
 						Error::<T>::LandUnitDoesNotExist
 					);
+				}
+				ItemId::NFT(class_id, token_id) => {
+					ensure!(
+						T::NFTHandler::is_transferable(&(class_id, token_id))?,
+						Error::<T>::ItemIsNotTransferable
+					);
+				}
+				_ => {}
+			}
+			ensure!(
+				!Self::check_item_ownership(item_id, &from)?,
+				Error::<T>::NoPermissionToMakeOffer
+			);
+			ensure!(
+				matches!(
+					currency_id,
+					FungibleTokenId::NativeToken(0)
+						| FungibleTokenId::FungibleToken(_)
+						| FungibleTokenId::MiningResource(_)
+				),
+				Error::<T>::FungibleTokenCurrencyNotFound
+			);
+			ensure!(!amount.is_zero(), Error::<T>::InvalidBidPrice);
+			ensure!(
+				end_block > <system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidOfferEndBlock
+			);
+			ensure!(
+				!Offers::<T>::contains_key(item_id, &from),
+				Error::<T>::OfferAlreadyExists
+			);
+			ensure!(
+				(OfferEndTime::<T>::iter_prefix(end_block).count() as u32) < T::MaxOffersPerBlock::get(),
+				Error::<T>::TooManyOffersInBlock
+			);
+
+			Self::reserve_in_currency(currency_id, &from, amount)?;
+
+			Offers::<T>::insert(
+				item_id,
+				&from,
+				Offer {
+					amount,
+					currency_id,
+					end_block,
+				},
+			);
+			OfferEndTime::<T>::insert(end_block, (item_id, from.clone()), ());
+
+			Self::deposit_event(Event::OfferMade(item_id, from, amount, currency_id));
+
+			Ok(().into())
+		}
+
+		/// Offeror can withdraw an offer and release the reserved funds
+		#[pallet::weight(T::WeightInfo::withdraw_offer())]
+		pub fn withdraw_offer(origin: OriginFor<T>, item_id: ItemId) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let offer = Offers::<T>::take(item_id, &from).ok_or(Error::<T>::OfferNotFound)?;
+			OfferEndTime::<T>::remove(offer.end_block, (item_id, from.clone()));
+			Self::unreserve_in_currency(offer.currency_id, &from, offer.amount);
+
+			Self::deposit_event(Event::OfferWithdrawn(item_id, from));
+
+			Ok(().into())
+		}
+
+		/// Item owner can accept an offer, the item is transferred to the offeror and the owner
+		/// is paid from the reserved funds after royalty fee
+		#[pallet::weight(T::WeightInfo::accept_offer())]
+		#[transactional]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			item_id: ItemId,
+			offeror: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			ensure!(
+				Self::items_in_auction(item_id) == None,
+				Error::<T>::ItemAlreadyInAuction
+			);
+			ensure!(
+				Self::check_item_ownership(item_id, &from)?,
+				Error::<T>::NoPermissionToAcceptOffer
+			);
+
+			let offer = Offers::<T>::take(item_id, &offeror).ok_or(Error::<T>::OfferNotFound)?;
+			ensure!(
+				<system::Pallet<T>>::block_number() < offer.end_block,
+				Error::<T>::OfferIsExpired
+			);
+			OfferEndTime::<T>::remove(offer.end_block, (item_id, offeror.clone()));
+
+			// Transfer the offered amount from offeror to item owner
+			Self::unreserve_in_currency(offer.currency_id, &offeror, offer.amount);
+			Self::transfer_in_currency(offer.currency_id, &offeror, &from, offer.amount)?;
+
+			Self::collect_listing_royalty_fee(offer.amount, &from, item_id, offer.currency_id)?;
 
-					let start_time = <system::Pallet<T>>::block_number();
-					let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get(); // add 7 days block for default auction
-					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
-
-					let new_auction_item = AuctionItem {
-						item_id,
-						recipient: recipient.clone(),
-						initial_amount,
-						amount: initial_amount,
-						start_time,
-						end_time,
-						auction_type,
-						listing_level: ListingLevel::Global,
-						currency_id: FungibleTokenId::NativeToken(0),
-					};
-
-					<AuctionItems<T>>::insert(auction_id, new_auction_item);
-
-					Self::deposit_event(Event::NewAuctionItem(
-						auction_id,
-						recipient,
-						listing_level,
-						initial_amount,
-						initial_amount,
-						end_time,
-					));
-					<ItemsInAuction<T>>::insert(item_id, true);
-					Ok(auction_id)
+			Self::transfer_offer_item(item_id, &from, &offeror)?;
+
+			Self::deposit_event(Event::OfferAccepted(item_id, from, offeror, offer.amount));
+
+			Ok(().into())
+		}
+
+		/// Owner can group NFTs, estates, land units and spots into a bundle which is listed and
+		/// sold as one lot. Ownership is checked again when the bundle is listed.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn create_bundle(origin: OriginFor<T>, items: Vec<ItemId>) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			ensure!(
+				items.len() > 1 && items.len() as u32 <= T::MaxBundleItems::get(),
+				Error::<T>::InvalidBundle
+			);
+
+			for (index, item_id) in items.iter().enumerate() {
+				// Bundles can not be nested or contain the same item twice
+				ensure!(
+					!matches!(item_id, ItemId::Bundle(_)) && !items[..index].contains(item_id),
+					Error::<T>::InvalidBundle
+				);
+				ensure!(
+					Self::check_item_ownership(*item_id, &from)?,
+					Error::<T>::NoPermissionToCreateBundle
+				);
+			}
+
+			let bundle_id = BundlesIndex::<T>::try_mutate(|n| -> Result<BundleId, DispatchError> {
+				let id = *n;
+				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableBundleId)?;
+				Ok(id)
+			})?;
+			Bundles::<T>::insert(bundle_id, items.clone());
+
+			Self::deposit_event(Event::BundleCreated(bundle_id, from, items));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn authorise_metaverse_collection(
+			origin: OriginFor<T>,
+			class_id: ClassId,
+			metaverse_id: MetaverseId,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
+				Error::<T>::NoPermissionToAuthoriseCollection
+			);
+
+			ensure!(
+				!MetaverseCollection::<T>::contains_key(metaverse_id, class_id),
+				Error::<T>::CollectionAlreadyAuthorised
+			);
+
+			MetaverseCollection::<T>::insert(metaverse_id.clone(), class_id.clone(), ());
+
+			Self::deposit_event(Event::<T>::CollectionAuthorizedInMetaverse(class_id, metaverse_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn remove_authorise_metaverse_collection(
+			origin: OriginFor<T>,
+			class_id: ClassId,
+			metaverse_id: MetaverseId,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
+				Error::<T>::NoPermissionToAuthoriseCollection
+			);
+
+			ensure!(
+				MetaverseCollection::<T>::contains_key(metaverse_id, class_id),
+				Error::<T>::CollectionIsNotAuthorised
+			);
+
+			MetaverseCollection::<T>::remove(metaverse_id.clone(), class_id.clone());
+			Self::deposit_event(Event::<T>::CollectionAuthorizationRemoveInMetaverse(
+				class_id,
+				metaverse_id,
+			));
+			Ok(().into())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_finalize(now: T::BlockNumber) {
+			let max_finality = T::MaxFinality::get();
+			let mut proceeded_item: u32 = 0;
+			for (auction_id, _) in <AuctionEndTime<T>>::drain_prefix(&now) {
+				if proceeded_item >= max_finality {
+					// Settle the remaining auctions in the next block
+					<AuctionEndTime<T>>::insert(now.saturating_add(One::one()), auction_id, ());
+					continue;
+				};
+				if let Some(auction) = <Auctions<T>>::get(&auction_id) {
+					if let Some(auction_item) = <AuctionItems<T>>::get(&auction_id) {
+						proceeded_item = proceeded_item.saturating_add(One::one());
+						// Sealed bids are settled first, the winner pays the second highest price
+						let highest_bid = if auction_item.auction_type == AuctionType::SealedBid {
+							Self::settle_sealed_bids(
+								auction_id,
+								auction.bid,
+								auction_item.initial_amount,
+								auction_item.currency_id,
+							)
+						} else {
+							auction.bid
+						};
+
+						if let Some(current_bid) = highest_bid {
+							let (high_bidder, high_bid_price): (T::AccountId, BalanceOf<T>) = current_bid;
+
+							let reserve_met = Self::reserve_price(auction_id)
+								.map_or(true, |reserve_price| high_bid_price >= reserve_price);
+
+							if !reserve_met {
+								// Item stays with the seller, release the high bid
+								Self::unreserve_in_currency(auction_item.currency_id, &high_bidder, high_bid_price);
+								Self::deposit_event(Event::AuctionFinalizedReserveNotMet(
+									auction_id,
+									high_bidder,
+									high_bid_price,
+								));
+							} else {
+								match Self::settle_auction(&auction_item, &high_bidder, high_bid_price) {
+									Ok(()) => {
+										Self::deposit_event(Event::AuctionFinalized(
+											auction_id,
+											high_bidder,
+											high_bid_price,
+										));
+									}
+									Err(e) => {
+										// Keep the bid reserved and the item listed until the settlement is retried
+										// or abandoned
+										let abandon_at = now.saturating_add(T::SettlementTimeout::get());
+										PendingSettlements::<T>::insert(
+											auction_id,
+											(high_bidder, high_bid_price, abandon_at),
+										);
+										Self::deposit_event(Event::SettlementFailed(auction_id, e));
+										continue;
+									}
+								}
+							}
+						} else {
+							Self::deposit_event(Event::AuctionFinalizedNoBid(auction_id));
+						}
+
+						// Unlock the item in auction
+						Self::remove_auction(auction_id.clone(), auction_item.item_id);
+					}
+				};
+			}
+		}
+
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			// Release offers that expire in this block, bounded by MaxOffersPerBlock in make_offer
+			let mut expired_offers: u32 = 0;
+			for ((item_id, offeror), _) in <OfferEndTime<T>>::drain_prefix(&now) {
+				expired_offers = expired_offers.saturating_add(One::one());
+				if let Some(offer) = <Offers<T>>::take(item_id, &offeror) {
+					Self::unreserve_in_currency(offer.currency_id, &offeror, offer.amount);
+					Self::deposit_event(Event::OfferExpired(item_id, offeror));
+				}
+			}
+			T::WeightInfo::expire_offers(expired_offers)
+		}
+	}
+
+	impl<T: Config> Auction<T::AccountId, T::BlockNumber> for Pallet<T> {
+		type Balance = BalanceOf<T>;
+
+		fn update_auction(
+			id: AuctionId,
+			info: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber>,
+		) -> DispatchResult {
+			let auction = <Auctions<T>>::get(id).ok_or(Error::<T>::AuctionNotExist)?;
+			if let Some(old_end) = auction.end {
+				<AuctionEndTime<T>>::remove(&old_end, id);
+			}
+			if let Some(new_end) = info.end {
+				<AuctionEndTime<T>>::insert(&new_end, id, ());
+			}
+			<Auctions<T>>::insert(id, info);
+			Ok(())
+		}
+
+		fn new_auction(
+			_recipient: T::AccountId,
+			_initial_amount: Self::Balance,
+			start: T::BlockNumber,
+			end: Option<T::BlockNumber>,
+		) -> Result<AuctionId, DispatchError> {
+			let auction: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber> =
+				AuctionInfo { bid: None, start, end };
+
+			let auction_id: AuctionId = AuctionsIndex::<T>::try_mutate(|n| -> Result<AuctionId, DispatchError> {
+				let id = *n;
+				ensure!(id != AuctionId::max_value(), Error::<T>::NoAvailableAuctionId);
+				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuctionId)?;
+				Ok(id)
+			})?;
+
+			<Auctions<T>>::insert(auction_id, auction);
+
+			if let Some(end_block) = end {
+				<AuctionEndTime<T>>::insert(&end_block, auction_id, ());
+			}
+
+			Ok(auction_id)
+		}
+
+		fn create_auction(
+			auction_type: AuctionType,
+			item_id: ItemId,
+			_end: Option<T::BlockNumber>,
+			recipient: T::AccountId,
+			initial_amount: Self::Balance,
+			_start: T::BlockNumber,
+			listing_level: ListingLevel<T::AccountId>,
+			currency_id: FungibleTokenId,
+		) -> Result<AuctionId, DispatchError> {
+			ensure!(
+				Self::items_in_auction(item_id) == None,
+				Error::<T>::ItemAlreadyInAuction
+			);
+
+			// Listings are priced in native token, social tokens or mining resources
+			ensure!(
+				matches!(
+					currency_id,
+					FungibleTokenId::NativeToken(0)
+						| FungibleTokenId::FungibleToken(_)
+						| FungibleTokenId::MiningResource(_)
+				),
+				Error::<T>::FungibleTokenCurrencyNotFound
+			);
+
+			match item_id {
+				ItemId::Bundle(bundle_id) => {
+					// Every item of the bundle is checked and locked as if it was listed on its own
+					let bundle_items = Self::bundles(bundle_id).ok_or(Error::<T>::BundleNotExist)?;
+					for bundle_item in bundle_items {
+						ensure!(
+							Self::items_in_auction(bundle_item) == None,
+							Error::<T>::ItemAlreadyInAuction
+						);
+						Self::ensure_item_can_be_listed(bundle_item, &recipient, &listing_level)?;
+					}
+				}
+				_ => Self::ensure_item_can_be_listed(item_id, &recipient, &listing_level)?,
+			}
+
+			let start_time = <system::Pallet<T>>::block_number();
+
+			// Default auction duration if no end block was given
+			let mut end_time = start_time + T::AuctionTimeToClose::get();
+			if let Some(_end_block) = _end {
+				end_time = _end_block
+			}
+			let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+
+			let new_auction_item = AuctionItem {
+				item_id,
+				recipient: recipient.clone(),
+				initial_amount,
+				amount: initial_amount,
+				start_time,
+				end_time,
+				auction_type,
+				listing_level: listing_level.clone(),
+				currency_id,
+			};
+
+			<AuctionItems<T>>::insert(auction_id, new_auction_item);
+
+			Self::deposit_event(Event::NewAuctionItem(
+				auction_id,
+				recipient,
+				listing_level,
+				initial_amount,
+				initial_amount,
+				end_time,
+			));
+			<ItemsInAuction<T>>::insert(item_id, true);
+			if let ItemId::Bundle(bundle_id) = item_id {
+				for bundle_item in Self::bundles(bundle_id).unwrap_or_default() {
+					<ItemsInAuction<T>>::insert(bundle_item, true);
 				}
-				_ => Err(Error::<T>::AuctionTypeIsNotSupported.into()),
 			}
+			Ok(auction_id)
 		}
 
 		fn remove_auction(id: AuctionId, item_id: ItemId) {
@@ -873,6 +1398,14 @@ pub mod pallet {
 					<AuctionEndTime<T>>::remove(end_block, id);
 					<Auctions<T>>::remove(&id);
 					<ItemsInAuction<T>>::remove(item_id);
+					if let ItemId::Bundle(bundle_id) = item_id {
+						for bundle_item in Self::bundles(bundle_id).unwrap_or_default() {
+							<ItemsInAuction<T>>::remove(bundle_item);
+						}
+					}
+					<DutchAuctionConfigs<T>>::remove(&id);
+					<SealedBidCommitEnd<T>>::remove(&id);
+					<ReservePrices<T>>::remove(&id);
 				}
 			}
 		}
@@ -903,13 +1436,13 @@ pub mod pallet {
 					//unlock reserve amount
 					if !last_bid_price.is_zero() {
 						//Unreserve balance of last bidder
-						<T as Config>::Currency::unreserve(&last_bidder, last_bid_price);
+						Self::unreserve_in_currency(auction_item.currency_id, &last_bidder, last_bid_price);
 					}
 				}
 
 				// Lock fund of new bidder
 				// Reserve balance
-				<T as Config>::Currency::reserve(&new_bidder, new_bid_price)?;
+				Self::reserve_in_currency(auction_item.currency_id, &new_bidder, new_bid_price)?;
 				auction_item.amount = new_bid_price.clone();
 
 				Ok(())
@@ -929,6 +1462,13 @@ pub mod pallet {
 			<AuctionItems<T>>::try_mutate_exists(id, |auction_item| -> DispatchResult {
 				let mut auction_item = auction_item.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
 
+				match auction_item.clone().listing_level {
+					ListingLevel::NetworkSpot(allowed_bidders) => {
+						ensure!(allowed_bidders.contains(&new_bidder), Error::<T>::BidNotAccepted);
+					}
+					_ => {}
+				}
+
 				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
 				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
 
@@ -963,38 +1503,16 @@ pub mod pallet {
 			asset_id: &(ClassId, TokenId),
 			social_currency_id: FungibleTokenId,
 		) -> DispatchResult {
-			let fee_scale = T::RoyaltyFee::get();
-			// Calculate loyalty fee and deposit to pot fund
-			let royalty_fee = high_bid_price
-				.saturating_mul(fee_scale.into())
-				.checked_div(&10000u128.saturated_into())
-				.ok_or("Overflow")?;
-
-			// Collect loyalty fee
-			// and deposit to class fund
-
-			// Get royalty fee
-			//			let fee = T::NFTHandler::get_nft_detail((asset_id.0,
-			// asset_id.1)).ok_or(Error::<T>::AssetIsNotExist)?;
-
-			let class_fund = T::NFTHandler::get_class_fund(&asset_id.0);
-			// Transfer loyalty fee from winner to class fund pot
-			if social_currency_id == FungibleTokenId::NativeToken(0) {
-				<T as Config>::Currency::transfer(
-					&high_bidder,
-					&class_fund,
-					royalty_fee,
-					ExistenceRequirement::KeepAlive,
-				)?;
-			} else {
-				T::FungibleTokenCurrency::transfer(
-					social_currency_id.clone(),
-					&high_bidder,
-					&class_fund,
-					royalty_fee.saturated_into(),
-				)?;
+			// Royalty fee and recipient are set per class
+			let (fee_scale, royalty_recipient) = T::NFTHandler::get_royalty_info(&asset_id.0)?;
+			let royalty_fee = fee_scale * *high_bid_price;
+
+			if royalty_fee.is_zero() || royalty_recipient == *high_bidder {
+				return Ok(());
 			}
-			Ok(())
+
+			// Transfer royalty fee to the royalty recipient of the class
+			Self::transfer_in_currency(social_currency_id, high_bidder, &royalty_recipient, royalty_fee)
 		}
 	}
 
@@ -1006,14 +1524,27 @@ pub mod pallet {
 
 	impl<T: Config> AuctionHandler<T::AccountId, BalanceOf<T>, T::BlockNumber, AuctionId> for Pallet<T> {
 		fn on_new_bid(
-			_now: T::BlockNumber,
-			_id: AuctionId,
+			now: T::BlockNumber,
+			id: AuctionId,
 			_new_bid: (T::AccountId, BalanceOf<T>),
 			_last_bid: Option<(T::AccountId, BalanceOf<T>)>,
 		) -> OnNewBidResult<T::BlockNumber> {
+			let anti_snipe_duration = T::AntiSnipeDuration::get();
+			let mut auction_end_change = Change::NoChange;
+
+			// Extend the auction if the bid is placed within the soft close window
+			if !anti_snipe_duration.is_zero() {
+				if let Some(current_end) = Self::auctions(id).and_then(|auction| auction.end) {
+					let new_end = now.saturating_add(anti_snipe_duration);
+					if new_end > current_end {
+						auction_end_change = Change::NewValue(Some(new_end));
+					}
+				}
+			}
+
 			OnNewBidResult {
 				accept_bid: true,
-				auction_end_change: Change::NoChange,
+				auction_end_change,
 			}
 		}
 
@@ -1021,6 +1552,328 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Active auctions with their item, only the ones at `listing_level` if given. Used by
+		/// the auction runtime API.
+		pub fn active_auctions(
+			listing_level: Option<ListingLevel<T::AccountId>>,
+		) -> Vec<(AuctionId, AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>)> {
+			// Auction items are kept after the auction ends, only auctions still in storage and
+			// not waiting on a failed settlement are active
+			AuctionItems::<T>::iter()
+				.filter(|(auction_id, _)| {
+					Auctions::<T>::contains_key(auction_id) && !PendingSettlements::<T>::contains_key(auction_id)
+				})
+				.filter(|(_, auction_item)| {
+					listing_level
+						.as_ref()
+						.map_or(true, |level| *level == auction_item.listing_level)
+				})
+				.collect()
+		}
+
+		/// Current highest bidder and bid price of an auction
+		pub fn highest_bid(auction_id: AuctionId) -> Option<(T::AccountId, BalanceOf<T>)> {
+			Self::auctions(auction_id).and_then(|auction| auction.bid)
+		}
+
+		/// Active auctions `who` holds the highest bid or a sealed bid on
+		pub fn auctions_bid_by(who: T::AccountId) -> Vec<AuctionId> {
+			Auctions::<T>::iter()
+				.filter(|(auction_id, auction)| {
+					auction.bid.as_ref().map_or(false, |(bidder, _)| *bidder == who)
+						|| SealedBids::<T>::contains_key(auction_id, &who)
+				})
+				.map(|(auction_id, _)| auction_id)
+				.collect()
+		}
+
+		/// Current price of a Dutch auction at block `now`. The price falls from the initial
+		/// amount at the auction start to the floor price at the auction end, in steps of
+		/// `price_step` blocks.
+		pub fn dutch_auction_price(auction_id: AuctionId, now: T::BlockNumber) -> Option<BalanceOf<T>> {
+			let auction_item = Self::get_auction_item(auction_id)?;
+			let start_price = auction_item.initial_amount;
+			let config = match Self::dutch_auction_config(auction_id) {
+				Some(config) => config,
+				None => return Some(start_price),
+			};
+
+			let duration = auction_item.end_time.saturating_sub(auction_item.start_time);
+			if duration.is_zero() || now >= auction_item.end_time {
+				return Some(config.floor_price);
+			}
+
+			let elapsed = now.saturating_sub(auction_item.start_time);
+			// Only count full price steps
+			let elapsed = elapsed / config.price_step * config.price_step;
+
+			let price_range = start_price.saturating_sub(config.floor_price);
+			let price_drop = Perbill::from_rational(elapsed, duration) * price_range;
+
+			Some(start_price.saturating_sub(price_drop).max(config.floor_price))
+		}
+
+		/// Check whether `who` owns an item that can be listed or offered on
+		fn check_item_ownership(item_id: ItemId, who: &T::AccountId) -> Result<bool, DispatchError> {
+			match item_id {
+				ItemId::NFT(class_id, token_id) => T::NFTHandler::check_ownership(who, &(class_id, token_id)),
+				ItemId::Spot(spot_id, _metaverse_id) => T::ContinuumHandler::check_spot_owner(spot_id, who),
+				ItemId::Estate(estate_id) => T::EstateHandler::check_estate_ownership(who, estate_id),
+				ItemId::LandUnit(coordinate, metaverse_id) => {
+					T::EstateHandler::check_landunit_ownership(who, metaverse_id, coordinate)
+				}
+				_ => Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+			}
+		}
+
+		/// Check that `recipient` owns an item and is allowed to list it at the listing level
+		fn ensure_item_can_be_listed(
+			item_id: ItemId,
+			recipient: &T::AccountId,
+			listing_level: &ListingLevel<T::AccountId>,
+		) -> DispatchResult {
+			match item_id {
+				ItemId::NFT(class_id, token_id) => {
+					// Check ownership
+					let is_owner = T::NFTHandler::check_ownership(recipient, &(class_id, token_id))?;
+
+					ensure!(is_owner == true, Error::<T>::NoPermissionToCreateAuction);
+
+					let is_transferable = T::NFTHandler::is_transferable(&(class_id, token_id))?;
+
+					ensure!(is_transferable == true, Error::<T>::ItemIsNotTransferable);
+
+					// Ensure NFT authorised to sell
+					match listing_level {
+						ListingLevel::Local(metaverse_id) => {
+							ensure!(
+								MetaverseCollection::<T>::contains_key(metaverse_id, class_id)
+									|| T::MetaverseInfoSource::check_ownership(recipient, metaverse_id),
+								Error::<T>::NoPermissionToCreateAuction
+							);
+						}
+						_ => {}
+					}
+				}
+				ItemId::Spot(spot_id, _metaverse_id) => {
+					// Check ownership
+					let is_owner = T::ContinuumHandler::check_spot_owner(spot_id, recipient)?;
+
+					ensure!(is_owner == true, Error::<T>::NoPermissionToCreateAuction);
+				}
+				ItemId::Estate(estate_id) => {
+					// Ensure the estate exist/minted
+					ensure!(
+						T::EstateHandler::check_estate(estate_id)?,
+						Error::<T>::EstateDoesNotExist
+					);
+
+					// Check ownership
+					let is_owner = T::EstateHandler::check_estate_ownership(recipient, estate_id)?;
+
+					ensure!(is_owner == true, Error::<T>::NoPermissionToCreateAuction);
+
+					// Leased estates can not change owner until the lease ends
+					ensure!(!T::EstateHandler::is_estate_leased(estate_id), Error::<T>::ItemIsLeased);
+				}
+				ItemId::LandUnit(coordinate, metaverse_id) => {
+					// Ensure the land unit exist/minted
+					ensure!(
+						T::EstateHandler::check_landunit(metaverse_id, coordinate)?,
+						Error::<T>::LandUnitDoesNotExist
+					);
+
+					// Check ownership
+					let is_owner = T::EstateHandler::check_landunit_ownership(recipient, metaverse_id, coordinate)?;
+
+					ensure!(is_owner == true, Error::<T>::NoPermissionToCreateAuction);
+
+					// Leased land units can not change owner until the lease ends
+					ensure!(
+						!T::EstateHandler::is_landunit_leased(metaverse_id, coordinate),
+						Error::<T>::ItemIsLeased
+					);
+				}
+				_ => return Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+			}
+
+			Ok(())
+		}
+
+		/// Reserve an amount in the listing currency
+		fn reserve_in_currency(
+			currency_id: FungibleTokenId,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::FungibleTokenCurrency::reserve(currency_id, who, amount.saturated_into())
+		}
+
+		/// Free balance of `who` in the listing currency
+		fn free_balance_in_currency(currency_id: FungibleTokenId, who: &T::AccountId) -> BalanceOf<T> {
+			T::FungibleTokenCurrency::free_balance(currency_id, who).saturated_into()
+		}
+
+		/// Release a bid reserved in the listing currency
+		fn unreserve_in_currency(currency_id: FungibleTokenId, who: &T::AccountId, amount: BalanceOf<T>) {
+			T::FungibleTokenCurrency::unreserve(currency_id, who, amount.saturated_into());
+		}
+
+		/// Transfer an amount in the listing currency
+		fn transfer_in_currency(
+			currency_id: FungibleTokenId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::FungibleTokenCurrency::transfer(currency_id, from, to, amount.saturated_into())
+		}
+
+		/// Pay the seller from the reserved high bid and transfer the item to the high bidder. Runs
+		/// in a storage transaction so a failure leaves the bid reserved and the item listed.
+		#[transactional]
+		fn settle_auction(
+			auction_item: &AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+			high_bidder: &T::AccountId,
+			high_bid_price: BalanceOf<T>,
+		) -> DispatchResult {
+			Self::unreserve_in_currency(auction_item.currency_id, high_bidder, high_bid_price);
+
+			// Transfer balance from high bidder to asset owner
+			Self::transfer_in_currency(
+				auction_item.currency_id,
+				high_bidder,
+				&auction_item.recipient,
+				high_bid_price,
+			)?;
+
+			Self::collect_listing_royalty_fee(
+				high_bid_price,
+				&auction_item.recipient,
+				auction_item.item_id,
+				auction_item.currency_id,
+			)?;
+
+			// Transfer asset from asset owner to high bidder while it is still locked
+			Self::transfer_auction_item(auction_item.item_id, &auction_item.recipient, high_bidder)
+		}
+
+		/// Pay royalty fee on a sale to the royalty recipient of the class. A bundle price is split
+		/// equally across its items and royalty is paid on the share of each NFT to the recipient of
+		/// its class.
+		fn collect_listing_royalty_fee(
+			price: BalanceOf<T>,
+			seller: &T::AccountId,
+			item_id: ItemId,
+			currency_id: FungibleTokenId,
+		) -> DispatchResult {
+			match item_id {
+				ItemId::NFT(class_id, token_id) => {
+					Self::collect_royalty_fee(&price, seller, &(class_id, token_id), currency_id)
+				}
+				ItemId::Bundle(bundle_id) => {
+					let bundle_items = Self::bundles(bundle_id).ok_or(Error::<T>::BundleNotExist)?;
+					let item_price = price / (bundle_items.len() as u32).into();
+					for bundle_item in bundle_items {
+						if let ItemId::NFT(class_id, token_id) = bundle_item {
+							Self::collect_royalty_fee(&item_price, seller, &(class_id, token_id), currency_id)?;
+						}
+					}
+					Ok(())
+				}
+				_ => Ok(()),
+			}
+		}
+
+		/// Settle the sealed bids of an auction at the end of its reveal phase. Revealed losing
+		/// bids and deposits are released, deposits of bids that were never revealed are slashed.
+		/// Returns the winner with the second highest price, which stays reserved on the winner.
+		fn settle_sealed_bids(
+			auction_id: AuctionId,
+			highest_bid: Option<(T::AccountId, BalanceOf<T>)>,
+			reserve_price: BalanceOf<T>,
+			currency_id: FungibleTokenId,
+		) -> Option<(T::AccountId, BalanceOf<T>)> {
+			let mut second_price = reserve_price;
+
+			for (bidder, sealed_bid) in SealedBids::<T>::drain_prefix(auction_id) {
+				match sealed_bid.revealed {
+					Some(value) => {
+						<T as Config>::Currency::unreserve(&bidder, sealed_bid.deposit);
+
+						let is_winner = highest_bid.as_ref().map_or(false, |(winner, _)| *winner == bidder);
+						if !is_winner {
+							Self::unreserve_in_currency(currency_id, &bidder, value);
+							second_price = second_price.max(value);
+						}
+					}
+					None => {
+						let (_, not_slashed) = <T as Config>::Currency::slash_reserved(&bidder, sealed_bid.deposit);
+						Self::deposit_event(Event::SealedBidDepositSlashed(
+							auction_id,
+							bidder,
+							sealed_bid.deposit.saturating_sub(not_slashed),
+						));
+					}
+				}
+			}
+
+			highest_bid.map(|(winner, value)| {
+				let price = second_price.min(value);
+				Self::unreserve_in_currency(currency_id, &winner, value.saturating_sub(price));
+				(winner, price)
+			})
+		}
+
+		/// Transfer the item of a listing from the seller to the buyer. Estate and land unit
+		/// handlers only accept items that are still in auction, so this has to run before the
+		/// listing is removed.
+		fn transfer_auction_item(item_id: ItemId, from: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+			match item_id {
+				ItemId::NFT(class_id, token_id) => T::NFTHandler::transfer_nft(from, to, &(class_id, token_id)),
+				ItemId::Spot(spot_id, metaverse_id) => {
+					T::ContinuumHandler::transfer_spot(spot_id, from, &(to.clone(), metaverse_id))?;
+					Ok(())
+				}
+				ItemId::Estate(estate_id) => {
+					T::EstateHandler::transfer_estate(estate_id, from, to)?;
+					Ok(())
+				}
+				ItemId::LandUnit(coordinate, metaverse_id) => {
+					T::EstateHandler::transfer_landunit(coordinate, from, &(to.clone(), metaverse_id))?;
+					Ok(())
+				}
+				ItemId::Bundle(bundle_id) => {
+					let bundle_items = Self::bundles(bundle_id).ok_or(Error::<T>::BundleNotExist)?;
+					for bundle_item in bundle_items {
+						Self::transfer_auction_item(bundle_item, from, to)?;
+					}
+					Ok(())
+				}
+				_ => Err(Error::<T>::AuctionTypeIsNotSupported.into()), // Future implementation for Metaverse
+			}
+		}
+
+		/// Transfer an item sold through an accepted offer, the item is never in auction
+		fn transfer_offer_item(item_id: ItemId, from: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+			match item_id {
+				ItemId::NFT(class_id, token_id) => T::NFTHandler::transfer_nft(from, to, &(class_id, token_id)),
+				ItemId::Spot(spot_id, metaverse_id) => {
+					T::ContinuumHandler::transfer_spot(spot_id, from, &(to.clone(), metaverse_id))?;
+					Ok(())
+				}
+				ItemId::Estate(estate_id) => {
+					T::EstateHandler::transfer_estate_by_offer(estate_id, from, to)?;
+					Ok(())
+				}
+				ItemId::LandUnit(coordinate, metaverse_id) => {
+					T::EstateHandler::transfer_landunit_by_offer(coordinate, from, &(to.clone(), metaverse_id))?;
+					Ok(())
+				}
+				_ => Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+			}
+		}
+
 		//		pub fn upgrade_asset_auction_data_v2() -> Weight {
 		//			log::info!("Start upgrading nft class data v2");
 		//			let mut num_auction_item = 0;
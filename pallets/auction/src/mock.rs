@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use frame_support::traits::{EqualPrivilegeOnly, Nothing};
-use frame_support::{construct_runtime, pallet_prelude::Hooks, parameter_types, PalletId};
+use frame_support::{construct_runtime, pallet_prelude::Hooks, parameter_types, weights::Weight, PalletId};
 use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
@@ -10,7 +10,7 @@ use sp_runtime::{testing::Header, traits::IdentityLookup};
 
 use auction_manager::{CheckAuctionItemHandler, ListingLevel};
 use core_primitives::{MetaverseInfo, MetaverseTrait, NftAssetData, NftClassData};
-use primitives::{continuum::Continuum, estate::Estate, Amount, AuctionId, EstateId, FungibleTokenId};
+use primitives::{continuum::Continuum, estate::Estate, Amount, AuctionId, EstateId, FungibleTokenId, SpotId};
 
 use crate as auction;
 
@@ -27,6 +27,9 @@ pub type MetaverseId = u64;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+pub const ROYALTY_RECIPIENT: AccountId = 4;
+pub const SOCIAL_TOKEN: FungibleTokenId = FungibleTokenId::FungibleToken(1);
 pub const CLASS_ID: u32 = 0;
 pub const COLLECTION_ID: u64 = 0;
 pub const ALICE_METAVERSE_ID: MetaverseId = 1;
@@ -38,6 +41,7 @@ pub const ESTATE_ID_NOT_EXIST: EstateId = 99;
 pub const LAND_UNIT_EXIST: (i32, i32) = (0, 0);
 pub const LAND_UNIT_EXIST_1: (i32, i32) = (1, 1);
 pub const LAND_UNIT_NOT_EXIST: (i32, i32) = (99, 99);
+pub const SPOT_ID_EXIST: SpotId = 0;
 
 impl frame_system::Config for Runtime {
 	type Origin = Origin;
@@ -88,20 +92,71 @@ impl Continuum<u128> for Continuumm {
 	fn transfer_spot(_spot_id: u64, _from: &AccountId, _to: &(AccountId, u64)) -> Result<u64, DispatchError> {
 		Ok(1)
 	}
+
+	fn check_spot_owner(spot_id: SpotId, who: &AccountId) -> Result<bool, DispatchError> {
+		match spot_id {
+			SPOT_ID_EXIST => Ok(*who == ALICE || *who == BOB),
+			_ => Ok(false),
+		}
+	}
 }
 
 pub struct EstateHandler;
 
 impl Estate<u128> for EstateHandler {
-	fn transfer_estate(_estate_id: EstateId, _from: &AccountId, _to: &AccountId) -> Result<EstateId, DispatchError> {
+	fn transfer_estate(estate_id: EstateId, _from: &AccountId, _to: &AccountId) -> Result<EstateId, DispatchError> {
+		// Estate can only be transferred while it is still in auction
+		ensure!(
+			AuctionModule::check_item_in_auction(ItemId::Estate(estate_id)),
+			DispatchError::Other("EstateNotInAuction")
+		);
+		ensure!(
+			!Self::is_estate_leased(estate_id),
+			DispatchError::Other("AssetIsLeased")
+		);
 		Ok(1)
 	}
 
 	fn transfer_landunit(
-		_coordinate: (i32, i32),
+		coordinate: (i32, i32),
 		_from: &AccountId,
-		_to: &(AccountId, MetaverseId),
+		to: &(AccountId, MetaverseId),
 	) -> Result<(i32, i32), DispatchError> {
+		// Land unit can only be transferred while it is still in auction
+		ensure!(
+			AuctionModule::check_item_in_auction(ItemId::LandUnit(coordinate, to.1)),
+			DispatchError::Other("LandUnitNotInAuction")
+		);
+		ensure!(
+			!Self::is_landunit_leased(to.1, coordinate),
+			DispatchError::Other("AssetIsLeased")
+		);
+		Ok((0, 0))
+	}
+
+	fn transfer_estate_by_offer(
+		estate_id: EstateId,
+		_from: &AccountId,
+		_to: &AccountId,
+	) -> Result<EstateId, DispatchError> {
+		// Estate sold by offer must not be in auction
+		ensure!(
+			!AuctionModule::check_item_in_auction(ItemId::Estate(estate_id)),
+			DispatchError::Other("EstateAlreadyInAuction")
+		);
+		Ok(1)
+	}
+
+	fn transfer_landunit_by_offer(
+		coordinate: (i32, i32),
+		_from: &AccountId,
+		to: &(AccountId, MetaverseId),
+	) -> Result<(i32, i32), DispatchError> {
+		// Land unit sold by offer must not be in auction
+		ensure!(
+			!AuctionModule::check_item_in_auction(ItemId::LandUnit(coordinate, to.1)),
+			DispatchError::Other("LandUnitAlreadyInAuction")
+		);
 		Ok((0, 0))
 	}
 
@@ -121,6 +176,24 @@ impl Estate<u128> for EstateHandler {
 		}
 	}
 
+	fn check_estate_ownership(owner: &AccountId, estate_id: EstateId) -> Result<bool, DispatchError> {
+		match estate_id {
+			ESTATE_ID_EXIST | ESTATE_ID_EXIST_1 => Ok(*owner == ALICE || *owner == BOB),
+			_ => Ok(false),
+		}
+	}
+
+	fn check_landunit_ownership(
+		owner: &AccountId,
+		_metaverse_id: MetaverseId,
+		coordinate: (i32, i32),
+	) -> Result<bool, DispatchError> {
+		match coordinate {
+			LAND_UNIT_EXIST | LAND_UNIT_EXIST_1 => Ok(*owner == ALICE || *owner == BOB),
+			_ => Ok(false),
+		}
+	}
+
 	fn get_total_land_units() -> u64 {
 		100
 	}
@@ -128,23 +201,30 @@ impl Estate<u128> for EstateHandler {
 	fn get_total_undeploy_land_units() -> u64 {
 		100
 	}
+
+	fn is_estate_leased(estate_id: EstateId) -> bool {
+		LeasedItems::get().contains(&ItemId::Estate(estate_id))
+	}
+
+	fn is_landunit_leased(metaverse_id: MetaverseId, coordinate: (i32, i32)) -> bool {
+		LeasedItems::get().contains(&ItemId::LandUnit(coordinate, metaverse_id))
+	}
 }
 
 pub struct Handler;
 
 impl AuctionHandler<AccountId, Balance, BlockNumber, AuctionId> for Handler {
 	fn on_new_bid(
-		_now: BlockNumber,
-		_id: AuctionId,
+		now: BlockNumber,
+		id: AuctionId,
 		new_bid: (AccountId, Balance),
-		_last_bid: Option<(AccountId, Balance)>,
+		last_bid: Option<(AccountId, Balance)>,
 	) -> OnNewBidResult<BlockNumber> {
 		// Test with Alice bid
 		if new_bid.0 == ALICE {
-			OnNewBidResult {
-				accept_bid: true,
-				auction_end_change: Change::NoChange,
-			}
+			<AuctionModule as AuctionHandler<AccountId, Balance, BlockNumber, AuctionId>>::on_new_bid(
+				now, id, new_bid, last_bid,
+			)
 		} else {
 			OnNewBidResult {
 				accept_bid: false,
@@ -185,9 +265,16 @@ parameter_types! {
 	pub const AuctionTimeToClose: u64 = 100;
 	// Test auction end within 100 blocks
 	pub const MinimumAuctionDuration: u64 = 10;
-	// Test 1% royalty fee
-	pub const RoyaltyFee: u16 = 100;
-	pub const MaxFinality: u32 = 100;
+	pub static MaxFinality: u32 = 100;
+	pub static LeasedItems: Vec<ItemId> = vec![];
+	// Test auction end extends when bid within 10 blocks before the end
+	pub const AntiSnipeDuration: u64 = 10;
+	pub const SealedBidDeposit: Balance = 10;
+	pub const SealedBidRevealDuration: u64 = 20;
+	pub const MaxSealedBids: u32 = 2;
+	pub const MaxBundleItems: u32 = 3;
+	pub const SettlementTimeout: u64 = 50;
+	pub const MaxOffersPerBlock: u32 = 2;
 }
 
 pub struct MetaverseInfoSource {}
@@ -212,6 +299,10 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 	fn update_metaverse_token(_metaverse_id: u64, _currency_id: FungibleTokenId) -> Result<(), DispatchError> {
 		Ok(())
 	}
+
+	fn get_land_connectivity(_metaverse_id: u64) -> primitives::estate::LandConnectivity {
+		primitives::estate::LandConnectivity::FourConnected
+	}
 }
 
 impl Config for Runtime {
@@ -220,13 +311,20 @@ impl Config for Runtime {
 	type Handler = Handler;
 	type Currency = Balances;
 	type ContinuumHandler = Continuumm;
-	type FungibleTokenCurrency = Tokens;
+	type FungibleTokenCurrency = Currencies;
 	type MetaverseInfoSource = MetaverseInfoSource;
 	type MinimumAuctionDuration = MinimumAuctionDuration;
 	type EstateHandler = EstateHandler;
-	type RoyaltyFee = RoyaltyFee;
 	type MaxFinality = MaxFinality;
 	type NFTHandler = NFTModule;
+	type AntiSnipeDuration = AntiSnipeDuration;
+	type SealedBidDeposit = SealedBidDeposit;
+	type SealedBidRevealDuration = SealedBidRevealDuration;
+	type MaxSealedBids = MaxSealedBids;
+	type MaxBundleItems = MaxBundleItems;
+	type SettlementTimeout = SettlementTimeout;
+	type MaxOffersPerBlock = MaxOffersPerBlock;
+	type WeightInfo = ();
 }
 
 pub type AdaptedBasicCurrency = currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
@@ -267,6 +365,7 @@ parameter_types! {
 	pub MaxBatchTransfer: u32 = 3;
 	pub MaxBatchMinting: u32 = 2000;
 	pub MaxMetadata: u32 = 10;
+	pub MaxExecutableCallWeight: Weight = 1_000_000_000;
 }
 
 impl pallet_nft::Config for Runtime {
@@ -281,6 +380,9 @@ impl pallet_nft::Config for Runtime {
 	type MaxMetadata = MaxMetadata;
 	type MultiCurrency = Currencies;
 	type MiningResourceId = MiningCurrencyId;
+	type Call = Call;
+	type ExecutableCallFilter = Nothing;
+	type MaxExecutableCallWeight = MaxExecutableCallWeight;
 }
 
 parameter_types! {
@@ -291,7 +393,7 @@ parameter_types! {
 impl orml_nft::Config for Runtime {
 	type ClassId = u32;
 	type TokenId = u64;
-	type ClassData = NftClassData<Balance>;
+	type ClassData = NftClassData<Balance, AccountId>;
 	type TokenData = NftAssetData<Balance>;
 	type MaxClassMetadata = MaxClassMetadata;
 	type MaxTokenMetadata = MaxTokenMetadata;
@@ -335,7 +437,13 @@ impl ExtBuilder {
 			.unwrap();
 
 		pallet_balances::GenesisConfig::<Runtime> {
-			balances: vec![(ALICE, 100000), (BOB, 500)],
+			balances: vec![(ALICE, 100000), (BOB, 500), (CHARLIE, 1000)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: vec![(ALICE, SOCIAL_TOKEN, 10000), (CHARLIE, SOCIAL_TOKEN, 1000)],
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
@@ -393,6 +501,7 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		_initial_amount: Self::Balance,
 		_start: u64,
 		_listing_level: ListingLevel<AccountId>,
+		_currency_id: FungibleTokenId,
 	) -> Result<u64, DispatchError> {
 		Ok(1)
 	}
@@ -6,7 +6,11 @@ use sp_std::collections::btree_map::BTreeMap;
 use auction_manager::ListingLevel;
 use core_primitives::{Attributes, CollectionType, NFTTrait, TokenType};
 use mock::{Event, *};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use primitives::FungibleTokenId;
 use primitives::ItemId::NFT;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 
 use super::*;
 
@@ -23,6 +27,11 @@ fn init_test_nft(owner: Origin) {
 		CollectionType::Collectable,
 		Perbill::from_percent(1u32)
 	));
+	assert_ok!(NFTModule::<Runtime>::set_royalty_recipient(
+		owner.clone(),
+		CLASS_ID,
+		ROYALTY_RECIPIENT
+	));
 
 	assert_ok!(NFTModule::<Runtime>::mint(
 		owner.clone(),
@@ -33,6 +42,10 @@ fn init_test_nft(owner: Origin) {
 	));
 }
 
+fn sealed_bid_commitment(who: AccountId, value: Balance, salt: Vec<u8>) -> H256 {
+	BlakeTwo256::hash_of(&(who, value, salt))
+}
+
 fn test_attributes(x: u8) -> Attributes {
 	let mut attr: Attributes = BTreeMap::new();
 	attr.insert(vec![x, x + 5], vec![x, x + 10]);
@@ -53,7 +66,8 @@ fn create_new_auction_work() {
 			ALICE,
 			100,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		assert_eq!(
@@ -80,7 +94,8 @@ fn create_new_auction_should_work_for_valid_estate() {
 			ALICE,
 			100,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 		assert_eq!(
 			AuctionModule::auctions(0),
@@ -100,12 +115,55 @@ fn create_new_auction_should_fail_for_non_exist_estate() {
 	ExtBuilder::default().build().execute_with(|| {
 		let item_id: ItemId = ItemId::Estate(ESTATE_ID_NOT_EXIST);
 		assert_noop!(
-			AuctionModule::create_auction(AuctionType::Auction, item_id, None, ALICE, 100, 0, ListingLevel::Global),
+			AuctionModule::create_auction(
+				AuctionType::Auction,
+				item_id,
+				None,
+				ALICE,
+				100,
+				0,
+				ListingLevel::Global,
+				FungibleTokenId::NativeToken(0)
+			),
 			Error::<Runtime>::EstateDoesNotExist
 		);
 	});
 }
 
+#[test]
+fn create_new_auction_should_fail_for_leased_estate_or_land_unit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let estate_item: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		let land_unit_item: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+		LeasedItems::set(vec![estate_item, land_unit_item]);
+
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				Origin::signed(BOB),
+				estate_item,
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::ItemIsLeased
+		);
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				Origin::signed(BOB),
+				land_unit_item,
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::ItemIsLeased
+		);
+	});
+}
+
 #[test]
 // Creating auction should work
 fn create_new_auction_should_work_for_valid_landunit() {
@@ -118,7 +176,8 @@ fn create_new_auction_should_work_for_valid_landunit() {
 			ALICE,
 			100,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 		assert_eq!(
 			AuctionModule::auctions(0),
@@ -138,12 +197,149 @@ fn create_new_auction_should_work_for_non_exist_landunit() {
 	ExtBuilder::default().build().execute_with(|| {
 		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_NOT_EXIST, ALICE_METAVERSE_ID);
 		assert_noop!(
-			AuctionModule::create_auction(AuctionType::Auction, item_id, None, ALICE, 100, 0, ListingLevel::Global),
+			AuctionModule::create_auction(
+				AuctionType::Auction,
+				item_id,
+				None,
+				ALICE,
+				100,
+				0,
+				ListingLevel::Global,
+				FungibleTokenId::NativeToken(0)
+			),
 			Error::<Runtime>::LandUnitDoesNotExist
 		);
 	});
 }
 
+#[test]
+// Listing estate, land unit and spot through create_new_auction should work
+fn create_new_auction_should_work_for_land_items() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(BOB);
+		let estate_item: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		let land_unit_item: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, BOB_METAVERSE_ID);
+		let spot_item: ItemId = ItemId::Spot(SPOT_ID_EXIST, BOB_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_new_auction(
+			origin.clone(),
+			estate_item,
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_ok!(AuctionModule::create_new_auction(
+			origin.clone(),
+			land_unit_item,
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_ok!(AuctionModule::create_new_auction(
+			origin.clone(),
+			spot_item,
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+
+		assert_eq!(
+			AuctionModule::auctions(2),
+			Some(AuctionInfo {
+				bid: None,
+				start: 1,
+				end: Some(50),
+			})
+		);
+		assert_eq!(AuctionModule::items_in_auction(estate_item), Some(true));
+		assert_eq!(AuctionModule::items_in_auction(land_unit_item), Some(true));
+		assert_eq!(AuctionModule::items_in_auction(spot_item), Some(true));
+
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				origin,
+				estate_item,
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::ItemAlreadyInAuction
+		);
+	});
+}
+
+#[test]
+// Listing estate, land unit and spot should fail if not owner
+fn create_new_auction_should_fail_for_land_items_not_owned() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(CHARLIE);
+
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				origin.clone(),
+				ItemId::Estate(ESTATE_ID_EXIST),
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::NoPermissionToCreateAuction
+		);
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				origin.clone(),
+				ItemId::LandUnit(LAND_UNIT_EXIST, BOB_METAVERSE_ID),
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::NoPermissionToCreateAuction
+		);
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				origin,
+				ItemId::Spot(SPOT_ID_EXIST, BOB_METAVERSE_ID),
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::NoPermissionToCreateAuction
+		);
+	});
+}
+
+#[test]
+// Listing unsupported items through create_new_auction should fail
+fn create_new_auction_should_fail_for_unsupported_items() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				Origin::signed(BOB),
+				ItemId::Country(BOB_METAVERSE_ID),
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::NoPermissionToCreateAuction
+		);
+	});
+}
+
 #[test]
 // Private create_auction should work
 fn create_auction_fail() {
@@ -178,7 +374,8 @@ fn create_auction_fail() {
 				BOB,
 				100,
 				0,
-				ListingLevel::Global
+				ListingLevel::Global,
+				FungibleTokenId::NativeToken(0)
 			),
 			Error::<Runtime>::NoPermissionToCreateAuction
 		);
@@ -210,7 +407,8 @@ fn create_auction_fail() {
 				ALICE,
 				100,
 				0,
-				ListingLevel::Global
+				ListingLevel::Global,
+				FungibleTokenId::NativeToken(0)
 			),
 			Error::<Runtime>::NoPermissionToCreateAuction
 		);
@@ -223,7 +421,8 @@ fn create_auction_fail() {
 			ALICE,
 			100,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 		assert_noop!(
 			AuctionModule::create_auction(
@@ -233,7 +432,8 @@ fn create_auction_fail() {
 				ALICE,
 				100,
 				0,
-				ListingLevel::Global
+				ListingLevel::Global,
+				FungibleTokenId::NativeToken(0)
 			),
 			Error::<Runtime>::ItemAlreadyInAuction
 		);
@@ -253,7 +453,8 @@ fn remove_auction_work() {
 			ALICE,
 			100,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 		AuctionModule::remove_auction(0, ItemId::NFT(0, 0));
 		assert_eq!(AuctionModule::auctions(0), None);
@@ -276,7 +477,8 @@ fn bid_works() {
 			BOB,
 			100,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		assert_ok!(AuctionModule::bid(bidder, 0, 200));
@@ -299,7 +501,8 @@ fn bid_works_for_valid_estate() {
 			BOB,
 			100,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		assert_ok!(AuctionModule::bid(bidder, 0, 200));
@@ -322,7 +525,8 @@ fn bid_works_for_valid_land_unit() {
 			BOB,
 			100,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		assert_ok!(AuctionModule::bid(bidder, 0, 200));
@@ -357,7 +561,8 @@ fn cannot_bid_with_insufficient_funds() {
 			BOB,
 			600,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		assert_noop!(
@@ -382,7 +587,8 @@ fn cannot_bid_on_own_auction() {
 			ALICE,
 			100,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		assert_noop!(AuctionModule::bid(owner, 0, 50), Error::<Runtime>::SelfBidNotAccepted);
@@ -410,7 +616,8 @@ fn asset_transfers_after_auction() {
 			BOB,
 			100,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		assert_ok!(AuctionModule::bid(bidder, 0, 200));
@@ -448,7 +655,8 @@ fn cannot_bid_on_ended_auction() {
 			BOB,
 			150,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		System::set_block_number(101);
@@ -459,6 +667,77 @@ fn cannot_bid_on_ended_auction() {
 	});
 }
 
+#[test]
+fn bid_within_anti_snipe_window_should_extend_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		let bidder = Origin::signed(ALICE);
+
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+
+		// Auction ends at block 101, bid 5 blocks before the end
+		System::set_block_number(96);
+
+		assert_ok!(AuctionModule::bid(bidder, 0, 200));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::AuctionExtended(0, 106))
+		);
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(106));
+		assert_eq!(AuctionModule::get_auction_item(0).unwrap().end_time, 106);
+		assert_eq!(AuctionModule::auction_end_time(101, 0), None);
+		assert_eq!(AuctionModule::auction_end_time(106, 0), Some(()));
+
+		// Auction should not be finalized at the original end block
+		run_to_block(102);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), Some(true));
+
+		run_to_block(107);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 200))
+		);
+	});
+}
+
+#[test]
+fn bid_outside_anti_snipe_window_should_not_extend_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		let bidder = Origin::signed(ALICE);
+
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+
+		System::set_block_number(50);
+
+		assert_ok!(AuctionModule::bid(bidder, 0, 200));
+		assert_eq!(last_event(), Event::AuctionModule(crate::Event::Bid(0, ALICE, 200)));
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(101));
+		assert_eq!(AuctionModule::auction_end_time(101, 0), Some(()));
+	});
+}
+
 #[test]
 // Private bid_auction should work
 fn buy_now_work() {
@@ -476,7 +755,8 @@ fn buy_now_work() {
 			BOB,
 			200,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		// buy now successful
@@ -497,7 +777,8 @@ fn buy_now_work() {
 			BOB,
 			200,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		assert_ok!(AuctionModule::buy_now(buyer.clone(), 1, 200));
@@ -542,7 +823,8 @@ fn buy_now_works_for_valid_estate() {
 			BOB,
 			150,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		// buy now successful
@@ -558,7 +840,8 @@ fn buy_now_works_for_valid_estate() {
 			BOB,
 			150,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		assert_ok!(AuctionModule::buy_now(buyer.clone(), 1, 150));
@@ -597,7 +880,8 @@ fn buy_now_works_for_valid_landunit() {
 			BOB,
 			150,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		// buy now successful
@@ -613,7 +897,8 @@ fn buy_now_works_for_valid_landunit() {
 			BOB,
 			150,
 			0,
-			ListingLevel::Global
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
 		));
 
 		assert_ok!(AuctionModule::buy_now(buyer.clone(), 1, 150));
@@ -637,149 +922,1407 @@ fn buy_now_works_for_valid_landunit() {
 }
 
 #[test]
-// Private bid_auction should work
-fn buy_now_should_fail() {
+fn create_new_dutch_auction_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		let owner = Origin::signed(BOB);
-		let buyer = Origin::signed(ALICE);
-		// we need this to test auction not started scenario
-		System::set_block_number(1);
-		init_test_nft(owner.clone());
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
 
-		// call create_auction
-		assert_ok!(AuctionModule::create_auction(
-			AuctionType::BuyNow,
-			ItemId::NFT(0, 0),
-			None,
-			BOB,
-			150,
-			0,
+		assert_ok!(AuctionModule::create_new_dutch_auction(
+			Origin::signed(BOB),
+			item_id,
+			1000,
+			100,
+			101,
+			1,
 			ListingLevel::Global
 		));
 
-		// no auction id
-		assert_noop!(
-			AuctionModule::buy_now(buyer.clone(), 1, 150),
-			Error::<Runtime>::AuctionNotExist
-		);
-		// user is seller
-		assert_noop!(
-			AuctionModule::buy_now(owner.clone(), 0, 150),
-			Error::<Runtime>::CannotBidOnOwnAuction
-		);
-		// buy it now value is less than buy_now_amount
-		assert_noop!(
-			AuctionModule::buy_now(buyer.clone(), 0, 100),
-			Error::<Runtime>::InvalidBuyItNowPrice
-		);
-		// buy it now value is more than buy_now_amount
-		assert_noop!(
-			AuctionModule::buy_now(buyer.clone(), 0, 200),
-			Error::<Runtime>::InvalidBuyItNowPrice
-		);
-		// user does not have enough balance in wallet
-		assert_ok!(Balances::reserve(&ALICE, 100000));
-		assert_noop!(
-			AuctionModule::buy_now(buyer.clone(), 0, 150),
-			Error::<Runtime>::InsufficientFunds
+		assert_eq!(
+			AuctionModule::get_auction_item(0).unwrap().auction_type,
+			AuctionType::Dutch
 		);
-		assert_eq!(Balances::unreserve(&ALICE, 100000), 0);
-		// auction has not started or is over
-		System::set_block_number(0);
-		assert_noop!(
-			AuctionModule::buy_now(buyer.clone(), 0, 150),
-			Error::<Runtime>::AuctionNotStarted
+		assert_eq!(
+			AuctionModule::dutch_auction_config(0),
+			Some(DutchAuctionConfig {
+				floor_price: 100,
+				price_step: 1,
+			})
 		);
-		System::set_block_number(101);
+		assert_eq!(AuctionModule::items_in_auction(item_id), Some(true));
+	});
+}
+
+#[test]
+fn create_new_dutch_auction_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
 		assert_noop!(
-			AuctionModule::buy_now(buyer.clone(), 0, 150),
-			Error::<Runtime>::AuctionIsExpired
+			AuctionModule::create_new_dutch_auction(
+				Origin::signed(BOB),
+				item_id,
+				100,
+				1000,
+				101,
+				1,
+				ListingLevel::Global
+			),
+			Error::<Runtime>::InvalidDutchAuctionPrice
 		);
-		System::set_block_number(1);
-		assert_ok!(AuctionModule::buy_now(buyer.clone(), 0, 150));
+
 		assert_noop!(
-			AuctionModule::create_auction(
-				AuctionType::BuyNow,
-				ItemId::NFT(0, 0),
-				None,
-				BOB,
-				150,
+			AuctionModule::create_new_dutch_auction(
+				Origin::signed(BOB),
+				item_id,
+				1000,
+				100,
+				101,
 				0,
 				ListingLevel::Global
 			),
-			Error::<Runtime>::NoPermissionToCreateAuction
+			Error::<Runtime>::InvalidDutchAuctionPriceStep
 		);
-	});
-}
 
-#[test]
+		assert_noop!(
+			AuctionModule::create_new_dutch_auction(
+				Origin::signed(BOB),
+				item_id,
+				1000,
+				100,
+				101,
+				101,
+				ListingLevel::Global
+			),
+			Error::<Runtime>::InvalidDutchAuctionPriceStep
+		);
+	});
+}
+
+#[test]
+fn dutch_auction_price_should_fall_to_floor() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+		let item_id_1: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST_1, ALICE_METAVERSE_ID);
+
+		// Linear price drop
+		assert_ok!(AuctionModule::create_new_dutch_auction(
+			Origin::signed(BOB),
+			item_id,
+			1000,
+			100,
+			101,
+			1,
+			ListingLevel::Global
+		));
+
+		// Price drops every 10 blocks
+		assert_ok!(AuctionModule::create_new_dutch_auction(
+			Origin::signed(BOB),
+			item_id_1,
+			1000,
+			100,
+			101,
+			10,
+			ListingLevel::Global
+		));
+
+		assert_eq!(AuctionModule::dutch_auction_price(0, 1), Some(1000));
+		assert_eq!(AuctionModule::dutch_auction_price(0, 15), Some(874));
+		assert_eq!(AuctionModule::dutch_auction_price(0, 51), Some(550));
+		assert_eq!(AuctionModule::dutch_auction_price(0, 101), Some(100));
+
+		assert_eq!(AuctionModule::dutch_auction_price(1, 1), Some(1000));
+		assert_eq!(AuctionModule::dutch_auction_price(1, 15), Some(910));
+		assert_eq!(AuctionModule::dutch_auction_price(1, 51), Some(550));
+		assert_eq!(AuctionModule::dutch_auction_price(1, 100), Some(190));
+	});
+}
+
+#[test]
+fn buy_now_should_work_for_dutch_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		let buyer = Origin::signed(ALICE);
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_new_dutch_auction(
+			Origin::signed(BOB),
+			item_id,
+			1000,
+			100,
+			101,
+			1,
+			ListingLevel::Global
+		));
+
+		System::set_block_number(51);
+
+		assert_noop!(
+			AuctionModule::buy_now(buyer.clone(), 0, 500),
+			Error::<Runtime>::InvalidBuyItNowPrice
+		);
+
+		// Buyer accepts up to 600 but only pays the current price
+		assert_ok!(AuctionModule::buy_now(buyer.clone(), 0, 600));
+
+		assert_eq!(Balances::free_balance(ALICE), 99450);
+		assert_eq!(Balances::free_balance(BOB), 1050);
+
+		let event = mock::Event::AuctionModule(crate::Event::BuyNowFinalised(0, ALICE, 550));
+		assert_eq!(last_event(), event);
+
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::items_in_auction(item_id), None);
+		assert_eq!(AuctionModule::dutch_auction_config(0), None);
+	});
+}
+
+#[test]
+fn dutch_auction_should_respect_listing_level() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_new_dutch_auction(
+			Origin::signed(BOB),
+			item_id,
+			1000,
+			100,
+			101,
+			1,
+			ListingLevel::NetworkSpot(vec![CHARLIE])
+		));
+
+		assert_noop!(
+			AuctionModule::buy_now(Origin::signed(ALICE), 0, 1000),
+			Error::<Runtime>::BidNotAccepted
+		);
+	});
+}
+
+#[test]
+fn cannot_bid_on_dutch_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_new_dutch_auction(
+			Origin::signed(BOB),
+			item_id,
+			1000,
+			100,
+			101,
+			1,
+			ListingLevel::Global
+		));
+
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 1000),
+			Error::<Runtime>::InvalidAuctionType
+		);
+	});
+}
+
+#[test]
+fn sealed_bid_auction_should_settle_at_second_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_new_sealed_bid_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			51,
+			ListingLevel::Global
+		));
+		// Auction finalizes at the end of the reveal phase
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(71));
+		assert_eq!(AuctionModule::sealed_bid_commit_end(0), Some(51));
+
+		assert_ok!(AuctionModule::commit_sealed_bid(
+			Origin::signed(ALICE),
+			0,
+			sealed_bid_commitment(ALICE, 500, vec![1])
+		));
+		assert_ok!(AuctionModule::commit_sealed_bid(
+			Origin::signed(CHARLIE),
+			0,
+			sealed_bid_commitment(CHARLIE, 300, vec![2])
+		));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::SealedBidCommitted(0, CHARLIE))
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 10);
+
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 500, vec![1]),
+			Error::<Runtime>::NotInRevealPhase
+		);
+
+		System::set_block_number(51);
+
+		assert_noop!(
+			AuctionModule::commit_sealed_bid(Origin::signed(ALICE), 0, sealed_bid_commitment(ALICE, 600, vec![1])),
+			Error::<Runtime>::NotInBiddingPhase
+		);
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 500, vec![2]),
+			Error::<Runtime>::InvalidSealedBidReveal
+		);
+
+		assert_ok!(AuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 500, vec![1]));
+		assert_ok!(AuctionModule::reveal_sealed_bid(
+			Origin::signed(CHARLIE),
+			0,
+			300,
+			vec![2]
+		));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::SealedBidRevealed(0, CHARLIE, 300))
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 510);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 310);
+		assert_eq!(AuctionModule::auctions(0).unwrap().bid, Some((ALICE, 500)));
+
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 500, vec![1]),
+			Error::<Runtime>::SealedBidAlreadyRevealed
+		);
+
+		run_to_block(72);
+
+		// Highest bidder wins and pays the second highest price
+		assert_eq!(Balances::free_balance(ALICE), 99700);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(CHARLIE), 1000);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(Balances::free_balance(BOB), 800);
+
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 300))
+		);
+		assert_eq!(AuctionModule::items_in_auction(item_id), None);
+		assert_eq!(AuctionModule::sealed_bids(0, ALICE), None);
+		assert_eq!(AuctionModule::sealed_bid_commit_end(0), None);
+	});
+}
+
+#[test]
+fn sealed_bid_auction_should_slash_unrevealed_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_new_sealed_bid_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			51,
+			ListingLevel::Global
+		));
+
+		assert_ok!(AuctionModule::commit_sealed_bid(
+			Origin::signed(ALICE),
+			0,
+			sealed_bid_commitment(ALICE, 500, vec![1])
+		));
+		assert_ok!(AuctionModule::commit_sealed_bid(
+			Origin::signed(CHARLIE),
+			0,
+			sealed_bid_commitment(CHARLIE, 800, vec![2])
+		));
+
+		System::set_block_number(51);
+		assert_ok!(AuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 500, vec![1]));
+
+		run_to_block(72);
+
+		let slashed_event = Event::AuctionModule(crate::Event::SealedBidDepositSlashed(0, CHARLIE, 10));
+		assert!(System::events().iter().any(|record| record.event == slashed_event));
+		assert_eq!(Balances::free_balance(CHARLIE), 990);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+
+		// Single revealed bid pays the reserve price
+		assert_eq!(Balances::free_balance(ALICE), 99900);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(BOB), 600);
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 100))
+		);
+	});
+}
+
+#[test]
+fn commit_sealed_bid_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_new_sealed_bid_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			51,
+			ListingLevel::Global
+		));
+
+		assert_noop!(
+			AuctionModule::commit_sealed_bid(Origin::signed(BOB), 0, sealed_bid_commitment(BOB, 500, vec![1])),
+			Error::<Runtime>::CannotBidOnOwnAuction
+		);
+
+		assert_ok!(AuctionModule::commit_sealed_bid(
+			Origin::signed(ALICE),
+			0,
+			sealed_bid_commitment(ALICE, 500, vec![1])
+		));
+		assert_noop!(
+			AuctionModule::commit_sealed_bid(Origin::signed(ALICE), 0, sealed_bid_commitment(ALICE, 600, vec![1])),
+			Error::<Runtime>::SealedBidAlreadyCommitted
+		);
+
+		assert_ok!(AuctionModule::commit_sealed_bid(
+			Origin::signed(CHARLIE),
+			0,
+			sealed_bid_commitment(CHARLIE, 300, vec![2])
+		));
+		assert_noop!(
+			AuctionModule::commit_sealed_bid(Origin::signed(4), 0, sealed_bid_commitment(4, 300, vec![3])),
+			Error::<Runtime>::TooManySealedBids
+		);
+
+		// Sealed bid auction does not accept public bids
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 200),
+			Error::<Runtime>::InvalidAuctionType
+		);
+	});
+}
+
+#[test]
+fn reveal_sealed_bid_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_new_sealed_bid_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			51,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::commit_sealed_bid(
+			Origin::signed(ALICE),
+			0,
+			sealed_bid_commitment(ALICE, 500, vec![1])
+		));
+		assert_ok!(AuctionModule::commit_sealed_bid(
+			Origin::signed(CHARLIE),
+			0,
+			sealed_bid_commitment(CHARLIE, 50, vec![2])
+		));
+
+		// Reveal before the reveal phase
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 500, vec![1]),
+			Error::<Runtime>::NotInRevealPhase
+		);
+
+		System::set_block_number(51);
+
+		// Wrong salt
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 500, vec![2]),
+			Error::<Runtime>::InvalidSealedBidReveal
+		);
+		// Wrong amount
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 400, vec![1]),
+			Error::<Runtime>::InvalidSealedBidReveal
+		);
+		// Commitment of another bidder
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(CHARLIE), 0, 500, vec![1]),
+			Error::<Runtime>::InvalidSealedBidReveal
+		);
+		// No commitment
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(BOB), 0, 500, vec![1]),
+			Error::<Runtime>::SealedBidNotFound
+		);
+		// Below the reserve price
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(CHARLIE), 0, 50, vec![2]),
+			Error::<Runtime>::InvalidBidPrice
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert_eq!(AuctionModule::auctions(0).unwrap().bid, None);
+
+		// Double reveal
+		assert_ok!(AuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 500, vec![1]));
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(ALICE), 0, 500, vec![1]),
+			Error::<Runtime>::SealedBidAlreadyRevealed
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 510);
+
+		// Reveal after the reveal phase
+		System::set_block_number(71);
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(CHARLIE), 0, 50, vec![2]),
+			Error::<Runtime>::NotInRevealPhase
+		);
+	});
+}
+
+#[test]
 // Private bid_auction should work
-fn invalid_auction_type() {
+fn buy_now_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		let buyer = Origin::signed(ALICE);
+		// we need this to test auction not started scenario
+		System::set_block_number(1);
+		init_test_nft(owner.clone());
+
+		// call create_auction
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			150,
+			0,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+
+		// no auction id
+		assert_noop!(
+			AuctionModule::buy_now(buyer.clone(), 1, 150),
+			Error::<Runtime>::AuctionNotExist
+		);
+		// user is seller
+		assert_noop!(
+			AuctionModule::buy_now(owner.clone(), 0, 150),
+			Error::<Runtime>::CannotBidOnOwnAuction
+		);
+		// buy it now value is less than buy_now_amount
+		assert_noop!(
+			AuctionModule::buy_now(buyer.clone(), 0, 100),
+			Error::<Runtime>::InvalidBuyItNowPrice
+		);
+		// buy it now value is more than buy_now_amount
+		assert_noop!(
+			AuctionModule::buy_now(buyer.clone(), 0, 200),
+			Error::<Runtime>::InvalidBuyItNowPrice
+		);
+		// user does not have enough balance in wallet
+		assert_ok!(Balances::reserve(&ALICE, 100000));
+		assert_noop!(
+			AuctionModule::buy_now(buyer.clone(), 0, 150),
+			Error::<Runtime>::InsufficientFunds
+		);
+		assert_eq!(Balances::unreserve(&ALICE, 100000), 0);
+		// auction has not started or is over
+		System::set_block_number(0);
+		assert_noop!(
+			AuctionModule::buy_now(buyer.clone(), 0, 150),
+			Error::<Runtime>::AuctionNotStarted
+		);
+		System::set_block_number(101);
+		assert_noop!(
+			AuctionModule::buy_now(buyer.clone(), 0, 150),
+			Error::<Runtime>::AuctionIsExpired
+		);
+		System::set_block_number(1);
+		assert_ok!(AuctionModule::buy_now(buyer.clone(), 0, 150));
+		assert_noop!(
+			AuctionModule::create_auction(
+				AuctionType::BuyNow,
+				ItemId::NFT(0, 0),
+				None,
+				BOB,
+				150,
+				0,
+				ListingLevel::Global,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::NoPermissionToCreateAuction
+		);
+	});
+}
+
+#[test]
+// Private bid_auction should work
+fn invalid_auction_type() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+		let participant = Origin::signed(ALICE);
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			150,
+			0,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_noop!(
+			AuctionModule::bid(participant.clone(), 0, 200),
+			Error::<Runtime>::InvalidAuctionType
+		);
+		AuctionModule::remove_auction(0, ItemId::NFT(0, 0));
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			150,
+			0,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_noop!(
+			AuctionModule::buy_now(participant.clone(), 1, 150),
+			Error::<Runtime>::InvalidAuctionType
+		);
+	});
+}
+
+#[test]
+// Private auction_bid_handler should not work
+fn on_finalize_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		let bidder = Origin::signed(ALICE);
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), Some(true));
+		assert_ok!(AuctionModule::bid(bidder, 0, 100));
+		run_to_block(102);
+		assert_eq!(AuctionModule::auctions(0), None);
+		// check account received asset
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		// check balances were transferred
+		assert_eq!(Balances::free_balance(ALICE), 99900);
+		// BOB only receive 596 - 1 (1% of 100 as loyalty fee) + 6 minting fee = 589
+		assert_eq!(Balances::free_balance(BOB), 589);
+		// asset is not longer in auction
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
+		// event was triggered
+		let event = mock::Event::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 100));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn on_finalize_should_defer_auctions_above_max_finality() {
+	ExtBuilder::default().build().execute_with(|| {
+		MaxFinality::set(1);
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(NFTModule::<Runtime>::mint(
+			owner.clone(),
+			CLASS_ID,
+			vec![1],
+			test_attributes(1),
+			1
+		));
+		for token_id in 0..2 {
+			assert_ok!(AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::NFT(CLASS_ID, token_id),
+				None,
+				BOB,
+				100,
+				0,
+				ListingLevel::Global,
+				FungibleTokenId::NativeToken(0)
+			));
+		}
+
+		// Only one auction is finalized in the end block, the other one in the next block
+		run_to_block(102);
+		assert_eq!(
+			[AuctionModule::auctions(0), AuctionModule::auctions(1)]
+				.iter()
+				.filter(|auction| auction.is_some())
+				.count(),
+			1
+		);
+		run_to_block(103);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::auctions(1), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(CLASS_ID, 1)), None);
+	});
+}
+
+fn assert_land_item_auction_finalized(item_id: ItemId) {
+	let owner = Origin::signed(BOB);
+	let bidder = Origin::signed(ALICE);
+
+	assert_ok!(AuctionModule::create_new_auction(
+		owner,
+		item_id,
+		100,
+		50,
+		ListingLevel::Global,
+		None,
+		FungibleTokenId::NativeToken(0)
+	));
+	assert_ok!(AuctionModule::bid(bidder, 0, 200));
+	assert_eq!(Balances::reserved_balance(ALICE), 200);
+
+	run_to_block(51);
+
+	assert_eq!(AuctionModule::auctions(0), None);
+	// item is no longer in auction
+	assert_eq!(AuctionModule::items_in_auction(item_id), None);
+	// check balances were transferred
+	assert_eq!(Balances::reserved_balance(ALICE), 0);
+	assert_eq!(Balances::free_balance(ALICE), 99800);
+	assert_eq!(Balances::free_balance(BOB), 700);
+	// event was triggered
+	let event = mock::Event::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 200));
+	assert_eq!(last_event(), event);
+}
+
+#[test]
+fn on_finalize_should_work_for_estate() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_land_item_auction_finalized(ItemId::Estate(ESTATE_ID_EXIST));
+	});
+}
+
+#[test]
+fn on_finalize_should_work_for_land_unit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_land_item_auction_finalized(ItemId::LandUnit(LAND_UNIT_EXIST, BOB_METAVERSE_ID));
+	});
+}
+
+#[test]
+fn on_finalize_should_not_settle_leased_estate() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			item_id,
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(CHARLIE), 0, 200));
+
+		// Estate is leased before the auction ends
+		LeasedItems::set(vec![item_id]);
+
+		run_to_block(102);
+
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::SettlementFailed(0, DispatchError::Other("AssetIsLeased")))
+		);
+		// Bid stays reserved and the estate stays listed
+		assert_eq!(Balances::reserved_balance(CHARLIE), 200);
+		assert_eq!(AuctionModule::pending_settlement(0), Some((CHARLIE, 200, 151)));
+		assert_eq!(AuctionModule::items_in_auction(item_id), Some(true));
+	});
+}
+
+#[test]
+fn on_finalize_should_work_for_spot() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_land_item_auction_finalized(ItemId::Spot(SPOT_ID_EXIST, BOB_METAVERSE_ID));
+	});
+}
+
+#[test]
+fn on_finalize_without_bid_should_unlock_land_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+
+		run_to_block(51);
+
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::items_in_auction(item_id), None);
+		assert_eq!(Balances::free_balance(BOB), 500);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::AuctionFinalizedNoBid(0))
+		);
+	});
+}
+
+#[test]
+fn buy_now_should_revert_when_item_transfer_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		let buyer = Origin::signed(ALICE);
+
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			150,
+			0,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+
+		// Seller no longer owns the listed item
+		assert_ok!(NFTModule::<Runtime>::transfer(owner.clone(), CHARLIE, (0, 0)));
+
+		assert_noop!(
+			AuctionModule::buy_now(buyer.clone(), 0, 150),
+			pallet_nft::Error::<Runtime>::NoPermission
+		);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), Some(true));
+	});
+}
+
+#[test]
+fn on_finalize_should_keep_failed_settlement_for_retry() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+
+		// Seller no longer owns the listed item
+		assert_ok!(NFTModule::<Runtime>::transfer(owner.clone(), CHARLIE, (0, 0)));
+		let bob_balance = Balances::free_balance(BOB);
+
+		run_to_block(102);
+
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::SettlementFailed(
+				0,
+				pallet_nft::Error::<Runtime>::NoPermission.into()
+			))
+		);
+		// Bid stays reserved and the item stays listed
+		assert_eq!(Balances::reserved_balance(ALICE), 200);
+		assert_eq!(Balances::free_balance(BOB), bob_balance);
+		assert_eq!(AuctionModule::pending_settlement(0), Some((ALICE, 200, 151)));
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), Some(true));
+
+		assert_noop!(
+			AuctionModule::retry_settlement(Origin::signed(CHARLIE), 0),
+			pallet_nft::Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			AuctionModule::retry_settlement(Origin::signed(CHARLIE), 1),
+			Error::<Runtime>::SettlementNotPending
+		);
+
+		assert_ok!(NFTModule::<Runtime>::transfer(Origin::signed(CHARLIE), BOB, (0, 0)));
+		assert_ok!(AuctionModule::retry_settlement(Origin::signed(CHARLIE), 0));
+
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 200))
+		);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 99800);
+		assert_eq!(AuctionModule::pending_settlement(0), None);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
+	});
+}
+
+#[test]
+fn abandon_settlement_should_release_bid_and_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+
+		// Seller no longer owns the listed item
+		assert_ok!(NFTModule::<Runtime>::transfer(owner.clone(), CHARLIE, (0, 0)));
+
+		run_to_block(102);
+
+		assert_eq!(AuctionModule::pending_settlement(0), Some((ALICE, 200, 151)));
+		// Failed settlement is not an active auction
+		assert_eq!(AuctionModule::active_auctions(None), vec![]);
+
+		assert_noop!(
+			AuctionModule::abandon_settlement(Origin::signed(ALICE), 1),
+			Error::<Runtime>::SettlementNotPending
+		);
+		assert_noop!(
+			AuctionModule::abandon_settlement(Origin::signed(ALICE), 0),
+			Error::<Runtime>::SettlementNotExpired
+		);
+
+		System::set_block_number(151);
+		assert_ok!(AuctionModule::abandon_settlement(Origin::signed(ALICE), 0));
+
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::SettlementAbandoned(0, ALICE, 200))
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(AuctionModule::pending_settlement(0), None);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
+		assert_noop!(
+			AuctionModule::retry_settlement(Origin::signed(ALICE), 0),
+			Error::<Runtime>::SettlementNotPending
+		);
+	});
+}
+
+#[test]
+fn buy_now_should_work_in_social_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_new_buy_now(
+			owner.clone(),
+			ItemId::NFT(0, 0),
+			150,
+			101,
+			ListingLevel::Global,
+			SOCIAL_TOKEN
+		));
+		assert_eq!(AuctionModule::get_auction_item(0).unwrap().currency_id, SOCIAL_TOKEN);
+
+		let bob_native_balance = Balances::free_balance(BOB);
+
+		assert_ok!(AuctionModule::buy_now(Origin::signed(ALICE), 0, 150));
+
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		// Payment and 1% royalty fee are paid in the social token
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN, &ALICE), 9850);
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN, &BOB), 149);
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN, &ROYALTY_RECIPIENT), 1);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(Balances::free_balance(BOB), bob_native_balance);
+	});
+}
+
+#[test]
+fn on_finalize_should_work_in_social_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			SOCIAL_TOKEN
+		));
+
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		assert_eq!(Tokens::reserved_balance(SOCIAL_TOKEN, &ALICE), 200);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+
+		run_to_block(51);
+
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN, &ALICE), 9800);
+		assert_eq!(Tokens::reserved_balance(SOCIAL_TOKEN, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN, &BOB), 200);
+		assert_eq!(Balances::free_balance(BOB), 500);
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 200))
+		);
+	});
+}
+
+#[test]
+fn bid_in_social_token_should_respect_listing_level() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			50,
+			ListingLevel::NetworkSpot(vec![CHARLIE]),
+			None,
+			SOCIAL_TOKEN
+		));
+
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 200),
+			Error::<Runtime>::BidNotAccepted
+		);
+
+		assert_ok!(AuctionModule::bid(Origin::signed(CHARLIE), 0, 200));
+		assert_eq!(Tokens::reserved_balance(SOCIAL_TOKEN, &CHARLIE), 200);
+	});
+}
+
+#[test]
+fn create_new_auction_should_fail_for_unsupported_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				Origin::signed(BOB),
+				ItemId::Estate(ESTATE_ID_EXIST),
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::DEXShare(0, 1)
+			),
+			Error::<Runtime>::FungibleTokenCurrencyNotFound
+		);
+	});
+}
+
+#[test]
+fn on_finalize_should_not_sell_below_reserve_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			50,
+			ListingLevel::Global,
+			Some(1000),
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_eq!(AuctionModule::reserve_price(0), Some(1000));
+
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+
+		run_to_block(51);
+
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::items_in_auction(item_id), None);
+		assert_eq!(AuctionModule::reserve_price(0), None);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(BOB), 500);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::AuctionFinalizedReserveNotMet(0, ALICE, 200))
+		);
+	});
+}
+
+#[test]
+fn on_finalize_should_sell_when_reserve_price_met() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			50,
+			ListingLevel::Global,
+			Some(150),
+			FungibleTokenId::NativeToken(0)
+		));
+
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+
+		run_to_block(51);
+
+		assert_eq!(Balances::free_balance(ALICE), 99800);
+		assert_eq!(Balances::free_balance(BOB), 700);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 200))
+		);
+	});
+}
+
+#[test]
+fn create_new_auction_should_fail_with_reserve_below_start_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				Origin::signed(BOB),
+				ItemId::Estate(ESTATE_ID_EXIST),
+				100,
+				50,
+				ListingLevel::Global,
+				Some(50),
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::InvalidReservePrice
+		);
+	});
+}
+
+#[test]
+fn cancel_listing_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			50,
+			ListingLevel::Global,
+			Some(1000),
+			FungibleTokenId::NativeToken(0)
+		));
+
+		assert_noop!(
+			AuctionModule::cancel_listing(Origin::signed(ALICE), 0),
+			Error::<Runtime>::NoPermissionToCancelAuction
+		);
+
+		assert_ok!(AuctionModule::cancel_listing(Origin::signed(BOB), 0));
+
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::AuctionCancelled(0, BOB))
+		);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::items_in_auction(item_id), None);
+		assert_eq!(AuctionModule::auction_end_time(50, 0), None);
+		assert_eq!(AuctionModule::reserve_price(0), None);
+
+		// Item can be listed again
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+	});
+}
+
+#[test]
+fn cancel_listing_should_fail_after_first_bid() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(BOB),
+			item_id,
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+
+		assert_noop!(
+			AuctionModule::cancel_listing(Origin::signed(BOB), 0),
+			Error::<Runtime>::AuctionHasBids
+		);
+		assert_noop!(
+			AuctionModule::cancel_listing(Origin::signed(BOB), 1),
+			Error::<Runtime>::AuctionNotExist
+		);
+	});
+}
+
+#[test]
+fn accept_offer_should_work_for_nft() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		let item_id = ItemId::NFT(0, 0);
+
+		init_test_nft(owner.clone());
+		let bob_balance = Balances::free_balance(BOB);
+		let royalty_recipient_balance = Balances::free_balance(ROYALTY_RECIPIENT);
+
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(ALICE),
+			item_id,
+			200,
+			FungibleTokenId::NativeToken(0),
+			50
+		));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::OfferMade(
+				item_id,
+				ALICE,
+				200,
+				FungibleTokenId::NativeToken(0)
+			))
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 200);
+
+		assert_noop!(
+			AuctionModule::accept_offer(Origin::signed(CHARLIE), item_id, ALICE),
+			Error::<Runtime>::NoPermissionToAcceptOffer
+		);
+
+		assert_ok!(AuctionModule::accept_offer(owner.clone(), item_id, ALICE));
+
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::OfferAccepted(item_id, BOB, ALICE, 200))
+		);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 99800);
+		// 1% royalty fee is paid to the royalty recipient
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 198);
+		assert_eq!(Balances::free_balance(ROYALTY_RECIPIENT), royalty_recipient_balance + 2);
+		assert_eq!(AuctionModule::offers(item_id, ALICE), None);
+		assert_eq!(AuctionModule::offer_end_time(50, (item_id, ALICE)), None);
+		assert_eq!(AuctionModule::items_in_auction(item_id), None);
+	});
+}
+
+#[test]
+fn accept_offer_should_work_for_estate_in_social_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(CHARLIE),
+			item_id,
+			300,
+			SOCIAL_TOKEN,
+			50
+		));
+		assert_eq!(Tokens::reserved_balance(SOCIAL_TOKEN, &CHARLIE), 300);
+
+		assert_ok!(AuctionModule::accept_offer(Origin::signed(BOB), item_id, CHARLIE));
+
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN, &CHARLIE), 700);
+		assert_eq!(Tokens::reserved_balance(SOCIAL_TOKEN, &CHARLIE), 0);
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN, &BOB), 300);
+		assert_eq!(AuctionModule::items_in_auction(item_id), None);
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::OfferAccepted(item_id, BOB, CHARLIE, 300))
+		);
+	});
+}
+
+#[test]
+fn make_offer_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+
+		// Owner can not make an offer on its own item
+		assert_noop!(
+			AuctionModule::make_offer(Origin::signed(BOB), item_id, 300, FungibleTokenId::NativeToken(0), 50),
+			Error::<Runtime>::NoPermissionToMakeOffer
+		);
+		assert_noop!(
+			AuctionModule::make_offer(
+				Origin::signed(CHARLIE),
+				ItemId::Estate(ESTATE_ID_NOT_EXIST),
+				300,
+				FungibleTokenId::NativeToken(0),
+				50
+			),
+			Error::<Runtime>::EstateDoesNotExist
+		);
+		assert_noop!(
+			AuctionModule::make_offer(
+				Origin::signed(CHARLIE),
+				item_id,
+				300,
+				FungibleTokenId::NativeToken(0),
+				1
+			),
+			Error::<Runtime>::InvalidOfferEndBlock
+		);
+
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(CHARLIE),
+			item_id,
+			300,
+			FungibleTokenId::NativeToken(0),
+			50
+		));
+		assert_noop!(
+			AuctionModule::make_offer(
+				Origin::signed(CHARLIE),
+				item_id,
+				400,
+				FungibleTokenId::NativeToken(0),
+				50
+			),
+			Error::<Runtime>::OfferAlreadyExists
+		);
+
+		// Listed items only accept bids
+		let item_id_1: ItemId = ItemId::Estate(ESTATE_ID_EXIST_1);
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(BOB),
+			item_id_1,
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_noop!(
+			AuctionModule::make_offer(
+				Origin::signed(CHARLIE),
+				item_id_1,
+				300,
+				FungibleTokenId::NativeToken(0),
+				50
+			),
+			Error::<Runtime>::ItemAlreadyInAuction
+		);
+	});
+}
+
+#[test]
+fn make_offer_should_fail_when_end_block_is_full() {
 	ExtBuilder::default().build().execute_with(|| {
-		let owner = Origin::signed(BOB);
-		init_test_nft(owner.clone());
-		let participant = Origin::signed(ALICE);
-		assert_ok!(AuctionModule::create_auction(
-			AuctionType::BuyNow,
-			ItemId::NFT(0, 0),
-			None,
-			BOB,
-			150,
-			0,
-			ListingLevel::Global
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+		let item_id_1: ItemId = ItemId::Estate(ESTATE_ID_EXIST_1);
+		let item_id_2: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(CHARLIE),
+			item_id,
+			100,
+			FungibleTokenId::NativeToken(0),
+			20
+		));
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(CHARLIE),
+			item_id_1,
+			100,
+			FungibleTokenId::NativeToken(0),
+			20
 		));
 		assert_noop!(
-			AuctionModule::bid(participant.clone(), 0, 200),
-			Error::<Runtime>::InvalidAuctionType
+			AuctionModule::make_offer(
+				Origin::signed(CHARLIE),
+				item_id_2,
+				100,
+				FungibleTokenId::NativeToken(0),
+				20
+			),
+			Error::<Runtime>::TooManyOffersInBlock
 		);
-		AuctionModule::remove_auction(0, ItemId::NFT(0, 0));
-		assert_ok!(AuctionModule::create_auction(
-			AuctionType::Auction,
-			ItemId::NFT(0, 0),
-			None,
-			BOB,
-			150,
-			0,
-			ListingLevel::Global
+
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(CHARLIE),
+			item_id_2,
+			100,
+			FungibleTokenId::NativeToken(0),
+			21
+		));
+	});
+}
+
+#[test]
+fn withdraw_offer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(CHARLIE),
+			item_id,
+			300,
+			FungibleTokenId::NativeToken(0),
+			50
 		));
+		assert_ok!(AuctionModule::withdraw_offer(Origin::signed(CHARLIE), item_id));
+
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::OfferWithdrawn(item_id, CHARLIE))
+		);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(AuctionModule::offers(item_id, CHARLIE), None);
+		assert_eq!(AuctionModule::offer_end_time(50, (item_id, CHARLIE)), None);
+
 		assert_noop!(
-			AuctionModule::buy_now(participant.clone(), 1, 150),
-			Error::<Runtime>::InvalidAuctionType
+			AuctionModule::withdraw_offer(Origin::signed(CHARLIE), item_id),
+			Error::<Runtime>::OfferNotFound
 		);
 	});
 }
 
 #[test]
-// Private auction_bid_handler should not work
-fn on_finalize_should_work() {
+fn expired_offer_should_be_unreserved() {
 	ExtBuilder::default().build().execute_with(|| {
-		let owner = Origin::signed(BOB);
-		let bidder = Origin::signed(ALICE);
-		init_test_nft(owner.clone());
-		assert_ok!(AuctionModule::create_auction(
-			AuctionType::Auction,
-			ItemId::NFT(0, 0),
-			None,
-			BOB,
-			100,
-			0,
-			ListingLevel::Global
+		let item_id: ItemId = ItemId::Estate(ESTATE_ID_EXIST);
+
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(CHARLIE),
+			item_id,
+			300,
+			FungibleTokenId::NativeToken(0),
+			10
 		));
-		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), Some(true));
-		assert_ok!(AuctionModule::bid(bidder, 0, 100));
-		run_to_block(102);
-		assert_eq!(AuctionModule::auctions(0), None);
-		// check account received asset
-		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
-		// check balances were transferred
-		assert_eq!(Balances::free_balance(ALICE), 99900);
-		// BOB only receive 596 - 1 (1% of 100 as loyalty fee) + 6 minting fee = 589
-		assert_eq!(Balances::free_balance(BOB), 589);
-		// asset is not longer in auction
-		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
-		// event was triggered
-		let event = mock::Event::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 100));
-		assert_eq!(last_event(), event);
+
+		run_to_block(11);
+
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::OfferExpired(item_id, CHARLIE))
+		);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(Balances::free_balance(CHARLIE), 1000);
+		assert_eq!(AuctionModule::offers(item_id, CHARLIE), None);
+
+		assert_noop!(
+			AuctionModule::accept_offer(Origin::signed(BOB), item_id, CHARLIE),
+			Error::<Runtime>::OfferNotFound
+		);
 	});
 }
 
@@ -795,6 +2338,8 @@ fn list_item_on_auction_local_marketplace_should_work() {
 			100,
 			102,
 			ListingLevel::Local(ALICE_METAVERSE_ID),
+			None,
+			FungibleTokenId::NativeToken(0)
 		));
 		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), Some(true))
 	});
@@ -812,7 +2357,338 @@ fn list_item_on_buy_now_local_marketplace_should_work() {
 			100,
 			102,
 			ListingLevel::Local(ALICE_METAVERSE_ID),
+			None,
+			FungibleTokenId::NativeToken(0)
 		));
 		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), Some(true))
 	});
 }
+
+fn bundle_test_items() -> Vec<ItemId> {
+	vec![
+		ItemId::NFT(0, 0),
+		ItemId::Estate(ESTATE_ID_EXIST),
+		ItemId::LandUnit(LAND_UNIT_EXIST, BOB_METAVERSE_ID),
+	]
+}
+
+#[test]
+fn create_bundle_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+
+		assert_ok!(AuctionModule::create_bundle(owner, bundle_test_items()));
+
+		assert_eq!(AuctionModule::bundles(0), Some(bundle_test_items()));
+		assert_eq!(AuctionModule::bundles_index(), 1);
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::BundleCreated(0, BOB, bundle_test_items()))
+		);
+	});
+}
+
+#[test]
+fn create_bundle_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+
+		// single item
+		assert_noop!(
+			AuctionModule::create_bundle(owner.clone(), vec![ItemId::NFT(0, 0)]),
+			Error::<Runtime>::InvalidBundle
+		);
+		// more than MaxBundleItems
+		let mut items = bundle_test_items();
+		items.push(ItemId::Estate(ESTATE_ID_EXIST_1));
+		assert_noop!(
+			AuctionModule::create_bundle(owner.clone(), items),
+			Error::<Runtime>::InvalidBundle
+		);
+		// duplicate item
+		assert_noop!(
+			AuctionModule::create_bundle(owner.clone(), vec![ItemId::NFT(0, 0), ItemId::NFT(0, 0)]),
+			Error::<Runtime>::InvalidBundle
+		);
+		// nested bundle
+		assert_noop!(
+			AuctionModule::create_bundle(owner.clone(), vec![ItemId::NFT(0, 0), ItemId::Bundle(0)]),
+			Error::<Runtime>::InvalidBundle
+		);
+		// item not owned
+		assert_noop!(
+			AuctionModule::create_bundle(Origin::signed(CHARLIE), bundle_test_items()),
+			Error::<Runtime>::NoPermissionToCreateBundle
+		);
+	});
+}
+
+#[test]
+fn buy_now_should_transfer_every_bundle_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+		let bob_balance = Balances::free_balance(BOB);
+		let royalty_recipient_balance = Balances::free_balance(ROYALTY_RECIPIENT);
+
+		assert_ok!(AuctionModule::create_bundle(owner.clone(), bundle_test_items()));
+		assert_ok!(AuctionModule::create_new_buy_now(
+			owner,
+			ItemId::Bundle(0),
+			300,
+			50,
+			ListingLevel::Global,
+			FungibleTokenId::NativeToken(0)
+		));
+
+		// every item of the bundle is locked
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Bundle(0)), Some(true));
+		for item_id in bundle_test_items() {
+			assert_eq!(AuctionModule::items_in_auction(item_id), Some(true));
+		}
+
+		assert_ok!(AuctionModule::buy_now(Origin::signed(ALICE), 0, 300));
+
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(ALICE), 99700);
+		// 1% royalty fee is paid on the NFT share of the bundle price
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 299);
+		assert_eq!(Balances::free_balance(ROYALTY_RECIPIENT), royalty_recipient_balance + 1);
+		// every item of the bundle is unlocked
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Bundle(0)), None);
+		for item_id in bundle_test_items() {
+			assert_eq!(AuctionModule::items_in_auction(item_id), None);
+		}
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::BuyNowFinalised(0, ALICE, 300))
+		);
+	});
+}
+
+#[test]
+fn bundle_listing_should_fail_when_item_is_listed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+
+		assert_ok!(AuctionModule::create_bundle(owner.clone(), bundle_test_items()));
+		assert_ok!(AuctionModule::create_new_auction(
+			owner.clone(),
+			ItemId::Estate(ESTATE_ID_EXIST),
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				owner.clone(),
+				ItemId::Bundle(0),
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::ItemAlreadyInAuction
+		);
+
+		assert_ok!(AuctionModule::create_bundle(
+			owner.clone(),
+			vec![ItemId::NFT(0, 0), ItemId::Estate(ESTATE_ID_EXIST_1)]
+		));
+		assert_ok!(AuctionModule::create_new_auction(
+			owner.clone(),
+			ItemId::Bundle(1),
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+
+		// items of a listed bundle can not be listed on their own
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				owner,
+				ItemId::NFT(0, 0),
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::ItemAlreadyInAuction
+		);
+	});
+}
+
+#[test]
+fn bundle_listing_should_fail_for_non_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+
+		assert_ok!(AuctionModule::create_bundle(owner, bundle_test_items()));
+
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				Origin::signed(ALICE),
+				ItemId::Bundle(0),
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::NoPermissionToCreateAuction
+		);
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				Origin::signed(ALICE),
+				ItemId::Bundle(1),
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::BundleNotExist
+		);
+	});
+}
+
+#[test]
+fn auction_queries_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(ALICE));
+
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(BOB),
+			ItemId::Estate(ESTATE_ID_EXIST),
+			100,
+			50,
+			ListingLevel::Global,
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_ok!(AuctionModule::create_new_auction(
+			Origin::signed(ALICE),
+			ItemId::NFT(0, 0),
+			100,
+			60,
+			ListingLevel::Local(ALICE_METAVERSE_ID),
+			None,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+
+		let mut active_auctions: Vec<AuctionId> = AuctionModule::active_auctions(None)
+			.into_iter()
+			.map(|(auction_id, _)| auction_id)
+			.collect();
+		active_auctions.sort();
+		assert_eq!(active_auctions, vec![0, 1]);
+		let local_auctions = AuctionModule::active_auctions(Some(ListingLevel::Local(ALICE_METAVERSE_ID)));
+		assert_eq!(local_auctions.len(), 1);
+		assert_eq!(local_auctions[0].0, 1);
+		assert_eq!(local_auctions[0].1.item_id, ItemId::NFT(0, 0));
+
+		assert_eq!(AuctionModule::highest_bid(0), Some((ALICE, 200)));
+		assert_eq!(AuctionModule::highest_bid(1), None);
+		assert_eq!(AuctionModule::auctions_bid_by(ALICE), vec![0]);
+		assert_eq!(AuctionModule::auctions_bid_by(BOB), Vec::<AuctionId>::new());
+
+		run_to_block(51);
+
+		// ended auctions are no longer active
+		let active_auctions: Vec<AuctionId> = AuctionModule::active_auctions(None)
+			.into_iter()
+			.map(|(auction_id, _)| auction_id)
+			.collect();
+		assert_eq!(active_auctions, vec![1]);
+		assert_eq!(AuctionModule::auctions_bid_by(ALICE), Vec::<AuctionId>::new());
+	});
+}
+
+fn init_bound_to_address_nft(owner: Origin) {
+	assert_ok!(NFTModule::<Runtime>::create_group(Origin::root(), vec![1], vec![1]));
+	assert_ok!(NFTModule::<Runtime>::create_class(
+		owner.clone(),
+		vec![1],
+		test_attributes(1),
+		COLLECTION_ID,
+		TokenType::BoundToAddress,
+		CollectionType::Collectable,
+		Perbill::from_percent(0u32)
+	));
+	assert_ok!(NFTModule::<Runtime>::mint(
+		owner.clone(),
+		CLASS_ID,
+		vec![1],
+		test_attributes(1),
+		1
+	));
+}
+
+#[test]
+fn bound_to_address_nft_should_not_be_listed_or_offered() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_bound_to_address_nft(owner.clone());
+
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				owner.clone(),
+				ItemId::NFT(0, 0),
+				100,
+				50,
+				ListingLevel::Global,
+				None,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::ItemIsNotTransferable
+		);
+		assert_noop!(
+			AuctionModule::create_new_buy_now(
+				owner.clone(),
+				ItemId::NFT(0, 0),
+				100,
+				50,
+				ListingLevel::Global,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::ItemIsNotTransferable
+		);
+		assert_noop!(
+			AuctionModule::make_offer(
+				Origin::signed(ALICE),
+				ItemId::NFT(0, 0),
+				100,
+				FungibleTokenId::NativeToken(0),
+				50
+			),
+			Error::<Runtime>::ItemIsNotTransferable
+		);
+
+		// a bundle with a soulbound NFT can not be listed either
+		assert_ok!(AuctionModule::create_bundle(owner.clone(), bundle_test_items()));
+		assert_noop!(
+			AuctionModule::create_new_buy_now(
+				owner,
+				ItemId::Bundle(0),
+				300,
+				50,
+				ListingLevel::Global,
+				FungibleTokenId::NativeToken(0)
+			),
+			Error::<Runtime>::ItemIsNotTransferable
+		);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
+	});
+}
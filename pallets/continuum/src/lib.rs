@@ -62,7 +62,7 @@ use sp_std::vec::Vec;
 use auction_manager::{Auction, AuctionType, CheckAuctionItemHandler, ListingLevel};
 use core_primitives::MetaverseTrait;
 pub use pallet::*;
-use primitives::{continuum::Continuum, ItemId, MetaverseId, SpotId};
+use primitives::{continuum::Continuum, FungibleTokenId, ItemId, MetaverseId, SpotId};
 pub use types::*;
 pub use vote::*;
 
@@ -565,6 +565,7 @@ impl<T: Config> Pallet<T> {
 					Default::default(),
 					now,
 					ListingLevel::NetworkSpot(recent_slot.participants),
+					FungibleTokenId::NativeToken(0),
 				)?;
 				Self::deposit_event(Event::FinalizedVote(referendum_info.spot_id))
 			}
@@ -720,6 +721,10 @@ impl<T: Config> Pallet<T> {
 		from: &T::AccountId,
 		to: &(T::AccountId, MetaverseId),
 	) -> Result<SpotId, DispatchError> {
+		ensure!(
+			!T::AuctionHandler::check_item_in_auction(ItemId::Spot(spot_id, to.1.clone())),
+			Error::<T>::SpotIsInAuction
+		);
 		Self::transfer_spot(spot_id, from, to)
 	}
 
@@ -772,10 +777,6 @@ impl<T: Config> Continuum<T::AccountId> for Pallet<T> {
 		from: &T::AccountId,
 		to: &(T::AccountId, MetaverseId),
 	) -> Result<SpotId, DispatchError> {
-		ensure!(
-			!T::AuctionHandler::check_item_in_auction(ItemId::Spot(spot_id, to.1.clone())),
-			Error::<T>::SpotIsInAuction
-		);
 		ContinuumSpots::<T>::try_mutate(spot_id, |maybe_spot| -> Result<SpotId, DispatchError> {
 			let treasury = Self::account_id();
 			if *from != treasury {
@@ -789,4 +790,16 @@ impl<T: Config> Continuum<T::AccountId> for Pallet<T> {
 			Ok(spot_id)
 		})
 	}
+
+	fn check_spot_owner(spot_id: SpotId, who: &T::AccountId) -> Result<bool, DispatchError> {
+		ensure!(ContinuumSpots::<T>::contains_key(spot_id), Error::<T>::SpotNotFound);
+		let spot = ContinuumSpots::<T>::get(spot_id);
+
+		// Unallocated spots are held by the continuum treasury
+		if *who == Self::account_id() {
+			return Ok(spot.metaverse_id == 0);
+		}
+
+		Ok(T::MetaverseInfoSource::check_ownership(who, &spot.metaverse_id))
+	}
 }
@@ -128,6 +128,7 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		_initial_amount: Self::Balance,
 		_start: u64,
 		_listing_level: ListingLevel<AccountId>,
+		_currency_id: FungibleTokenId,
 	) -> Result<u64, DispatchError> {
 		Ok(1)
 	}
@@ -202,6 +203,10 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 	fn update_metaverse_token(_metaverse_id: u64, _currency_id: FungibleTokenId) -> Result<(), DispatchError> {
 		Ok(())
 	}
+
+	fn get_land_connectivity(_metaverse_id: u64) -> primitives::estate::LandConnectivity {
+		primitives::estate::LandConnectivity::FourConnected
+	}
 }
 
 impl Config for Runtime {
@@ -462,9 +462,9 @@ pub mod pallet {
 				Error::<T>::NoAuthorization
 			);
 
-			// Check if executor is the owner of the Distributor NFT
+			// Check if executor is the user (owner or active renter) of the Distributor NFT
 			ensure!(
-				T::NFTHandler::check_nft_ownership(&who, &distributor_nft_id)?,
+				T::NFTHandler::get_user_of(&distributor_nft_id)? == who,
 				Error::<T>::NoPermissionToExecuteBuyPowerOrder
 			);
 
@@ -536,9 +536,9 @@ pub mod pallet {
 				Error::<T>::NoPermissionToBuyPower
 			);
 
-			// Check if origin is the owner of the Distributor NFT
+			// Check if origin is the user (owner or active renter) of the Distributor NFT
 			ensure!(
-				T::NFTHandler::check_nft_ownership(&who, &distributor_nft_id)?,
+				T::NFTHandler::get_user_of(&distributor_nft_id)? == who,
 				Error::<T>::NoPermissionToBuyPower
 			);
 
@@ -612,9 +612,9 @@ pub mod pallet {
 				Error::<T>::PowerGenerationIsNotAuthorized
 			);
 
-			// Check if origin is the owner of the Distributor NFT
+			// Check if origin is the user (owner or active renter) of the Generator NFT
 			ensure!(
-				T::NFTHandler::check_nft_ownership(&who, &generator_nft_id)?,
+				T::NFTHandler::get_user_of(&generator_nft_id)? == who,
 				Error::<T>::NoPermissionToExecuteGeneratingPowerOrder
 			);
 
@@ -811,9 +811,9 @@ pub mod pallet {
 				Error::<T>::PowerGenerationIsNotAuthorized
 			);
 
-			// Check if origin is the owner of the Distributor NFT
+			// Check if origin is the user (owner or active renter) of the Generator NFT
 			ensure!(
-				T::NFTHandler::check_nft_ownership(&who, &generator_nft_id)?,
+				T::NFTHandler::get_user_of(&generator_nft_id)? == who,
 				Error::<T>::NoPermissionToBuyPower
 			);
 
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use frame_support::traits::Nothing;
-use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types, weights::Weight, PalletId};
 use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
@@ -127,6 +127,18 @@ impl Estate<u128> for EstateHandler {
 		Ok(coordinate)
 	}
 
+	fn transfer_estate_by_offer(estate_id: EstateId, _from: &u128, _to: &u128) -> Result<EstateId, DispatchError> {
+		Ok(estate_id)
+	}
+
+	fn transfer_landunit_by_offer(
+		coordinate: (i32, i32),
+		_from: &u128,
+		_to: &(u128, primitives::MetaverseId),
+	) -> Result<(i32, i32), DispatchError> {
+		Ok(coordinate)
+	}
+
 	fn check_estate(_estate_id: EstateId) -> Result<bool, DispatchError> {
 		Ok(true)
 	}
@@ -135,6 +147,18 @@ impl Estate<u128> for EstateHandler {
 		Ok(true)
 	}
 
+	fn check_estate_ownership(_owner: &u128, _estate_id: EstateId) -> Result<bool, DispatchError> {
+		Ok(true)
+	}
+
+	fn check_landunit_ownership(
+		_owner: &u128,
+		_metaverse_id: primitives::MetaverseId,
+		_coordinate: (i32, i32),
+	) -> Result<bool, DispatchError> {
+		Ok(true)
+	}
+
 	fn get_total_land_units() -> u64 {
 		10
 	}
@@ -142,6 +166,14 @@ impl Estate<u128> for EstateHandler {
 	fn get_total_undeploy_land_units() -> u64 {
 		10
 	}
+
+	fn is_estate_leased(_estate_id: EstateId) -> bool {
+		false
+	}
+
+	fn is_landunit_leased(_metaverse_id: primitives::MetaverseId, _coordinate: (i32, i32)) -> bool {
+		false
+	}
 }
 
 pub struct MetaverseStakingHandler;
@@ -245,6 +277,7 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		_initial_amount: Self::Balance,
 		_start: u64,
 		_listing_level: ListingLevel<AccountId>,
+		_currency_id: FungibleTokenId,
 	) -> Result<u64, DispatchError> {
 		Ok(1)
 	}
@@ -293,6 +326,7 @@ parameter_types! {
 	pub MaxBatchTransfer: u32 = 3;
 	pub MaxBatchMinting: u32 = 2000;
 	pub MaxMetadata: u32 = 10;
+	pub MaxExecutableCallWeight: Weight = 1_000_000_000;
 }
 
 impl pallet_nft::Config for Runtime {
@@ -307,6 +341,9 @@ impl pallet_nft::Config for Runtime {
 	type MaxMetadata = MaxMetadata;
 	type MultiCurrency = Currencies;
 	type MiningResourceId = MiningCurrencyId;
+	type Call = Call;
+	type ExecutableCallFilter = Nothing;
+	type MaxExecutableCallWeight = MaxExecutableCallWeight;
 }
 
 parameter_types! {
@@ -316,7 +353,7 @@ parameter_types! {
 impl orml_nft::Config for Runtime {
 	type ClassId = u32;
 	type TokenId = u64;
-	type ClassData = NftClassData<Balance>;
+	type ClassData = NftClassData<Balance, AccountId>;
 	type TokenData = NftAssetData<Balance>;
 	type MaxClassMetadata = MaxClassMetadata;
 	type MaxTokenMetadata = MaxTokenMetadata;
@@ -294,6 +294,56 @@ fn buy_power_by_user_should_work() {
 		});
 }
 
+#[test]
+fn buy_power_by_user_should_work_with_bound_to_address_distributor() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, get_mining_currency(), ALICE_MINING_BALANCE.into())])
+		.build()
+		.execute_with(|| {
+			let origin = Origin::signed(ALICE);
+			assert_ok!(NFTModule::create_group(Origin::root(), vec![1], vec![1]));
+			assert_ok!(NFTModule::create_class(
+				origin.clone(),
+				vec![1],
+				test_attributes(1),
+				DISTRIBUTOR_COLLECTION_ID,
+				TokenType::BoundToAddress,
+				CollectionType::Collectable,
+				Perbill::from_percent(0u32)
+			));
+			assert_ok!(NFTModule::mint(
+				origin.clone(),
+				DISTRIBUTOR_CLASS_ID,
+				vec![1],
+				test_attributes(1),
+				1
+			));
+
+			assert_ok!(EconomyModule::authorize_power_distributor_collection(
+				Origin::root(),
+				DISTRIBUTOR_COLLECTION_ID,
+				DISTRIBUTOR_CLASS_ID,
+			));
+			assert_ok!(EconomyModule::set_bit_power_exchange_rate(
+				Origin::root(),
+				EXCHANGE_RATE
+			));
+
+			// Soulbound distributor is still usable by its holder
+			assert_ok!(EconomyModule::buy_power_by_user(
+				origin.clone(),
+				USER_BUY_POWER_AMOUNT,
+				DISTRIBUTOR_NFT_ASSET_ID,
+			));
+
+			// but can not be moved to another account
+			assert_noop!(
+				NFTModule::transfer(origin, BOB, DISTRIBUTOR_NFT_ASSET_ID),
+				pallet_nft::Error::<Runtime>::NonTransferable
+			);
+		});
+}
+
 #[test]
 fn buy_power_by_user_with_block_target_should_work() {
 	ExtBuilder::default()
@@ -697,6 +747,60 @@ fn execute_buy_power_order_should_work() {
 		});
 }
 
+#[test]
+fn execute_buy_power_order_should_work_with_rented_distributor() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, get_mining_currency(), ALICE_MINING_BALANCE.into())])
+		.build()
+		.execute_with(|| {
+			let origin = Origin::signed(ALICE);
+
+			init_test_nft(origin.clone(), DISTRIBUTOR_COLLECTION_ID, DISTRIBUTOR_CLASS_ID);
+
+			assert_ok!(EconomyModule::authorize_power_distributor_collection(
+				Origin::root(),
+				DISTRIBUTOR_COLLECTION_ID,
+				DISTRIBUTOR_CLASS_ID,
+			));
+
+			assert_ok!(EconomyModule::buy_power_by_user(
+				origin.clone(),
+				USER_BUY_POWER_AMOUNT,
+				DISTRIBUTOR_NFT_ASSET_ID,
+			));
+
+			let distributor_account_id = sub_account(DISTRIBUTOR_NFT_ASSET_ID);
+			PowerBalance::<Runtime>::insert(distributor_account_id, DISTRIBUTOR_POWER_BALANCE);
+
+			// BOB rents the distributor NFT and becomes its user
+			assert_ok!(NFTModule::list_for_rent(
+				origin.clone(),
+				DISTRIBUTOR_NFT_ASSET_ID,
+				1,
+				200
+			));
+			assert_ok!(NFTModule::rent(Origin::signed(BOB), DISTRIBUTOR_NFT_ASSET_ID, 200, 200));
+
+			run_to_block(101);
+
+			assert_noop!(
+				EconomyModule::execute_buy_power_order(origin, DISTRIBUTOR_NFT_ASSET_ID, ALICE),
+				Error::<Runtime>::NoPermissionToExecuteBuyPowerOrder
+			);
+			assert_ok!(EconomyModule::execute_buy_power_order(
+				Origin::signed(BOB),
+				DISTRIBUTOR_NFT_ASSET_ID,
+				ALICE
+			));
+
+			assert_eq!(
+				EconomyModule::get_buy_power_by_user_request_queue(DISTRIBUTOR_NFT_ASSET_ID, ALICE),
+				None
+			);
+			assert_eq!(EconomyModule::get_power_balance(ALICE), USER_BUY_POWER_AMOUNT);
+		});
+}
+
 #[test]
 fn execute_generate_power_order_should_fail_nft_does_not_exist() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -28,7 +28,7 @@ use sp_std::vec;
 #[allow(unused)]
 pub use crate::Pallet as EstateModule;
 use crate::{
-	pallet::{MintingRateConfig, Round},
+	pallet::{MintingRateConfig, Round, StakingRewards},
 	Call, Config, MintingRateInfo, Range,
 };
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
@@ -49,9 +49,13 @@ const METAVERSE_ID: u64 = 1;
 const ALICE: AccountId = 1;
 const BENEFICIARY_ID: AccountId = 99;
 
-const MAX_BOUND: (i32, i32) = (-100, 100);
+const MAX_BOUND: LandBounds = LandBounds {
+	x: (-100, 100),
+	y: (-100, 100),
+	exclusion_zones: Vec::new(),
+};
 const COORDINATE_IN_1: (i32, i32) = (-10, 10);
-const COORDINATE_IN_2: (i32, i32) = (-5, 5);
+const COORDINATE_IN_2: (i32, i32) = (-10, 11);
 const COORDINATE_OUT: (i32, i32) = (0, 101);
 const COORDINATE_IN_AUCTION: (i32, i32) = (99, 99);
 const ESTATE_IN_AUCTION: EstateId = 99;
@@ -449,6 +453,103 @@ benchmarks! {
 	verify {
 		assert_eq!(crate::Pallet::<T>::exit_queue(caller.clone(), 0), Some(()))
 	}
+
+	// merge_estates
+	merge_estates {
+		let caller = funded_account::<T>("caller", 10000);
+
+		crate::Pallet::<T>::set_max_bounds(RawOrigin::Root.into(), METAVERSE_ID, MAX_BOUND);
+		crate::Pallet::<T>::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1], false);
+		crate::Pallet::<T>::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_2], false);
+		let min_stake = T::MinimumStake::get();
+		crate::Pallet::<T>::bond_more(RawOrigin::Signed(caller.clone()).into(), 1, min_stake);
+	}: _(RawOrigin::Signed(caller.clone()), 0, 1)
+	verify {
+		assert_eq!(crate::Pallet::<T>::get_estates(1), None);
+		assert_eq!(crate::Pallet::<T>::estate_stake(0, caller.clone()), min_stake);
+	}
+
+	// split_estate
+	split_estate {
+		let caller = funded_account::<T>("caller", 10000);
+
+		crate::Pallet::<T>::set_max_bounds(RawOrigin::Root.into(), METAVERSE_ID, MAX_BOUND);
+		crate::Pallet::<T>::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1, COORDINATE_IN_2], false);
+	}: _(RawOrigin::Signed(caller.clone()), 0, vec![COORDINATE_IN_2])
+	verify {
+		assert_eq!(crate::Pallet::<T>::get_estate_owner(1), Some(OwnerId::Account(caller.clone())));
+	}
+
+	// offer_lease
+	offer_lease {
+		let caller = funded_account::<T>("caller", 10000);
+
+		crate::Pallet::<T>::set_max_bounds(RawOrigin::Root.into(), METAVERSE_ID, MAX_BOUND);
+		crate::Pallet::<T>::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1], false);
+	}: _(RawOrigin::Signed(caller.clone()), LeaseAsset::Estate(0), 100u32.into(), false, 10)
+	verify {
+		assert_eq!(crate::Pallet::<T>::get_lease_offer(LeaseAsset::Estate(0)).is_some(), true);
+	}
+
+	// cancel_lease_offer
+	cancel_lease_offer {
+		let caller = funded_account::<T>("caller", 10000);
+
+		crate::Pallet::<T>::set_max_bounds(RawOrigin::Root.into(), METAVERSE_ID, MAX_BOUND);
+		crate::Pallet::<T>::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1], false);
+		crate::Pallet::<T>::offer_lease(RawOrigin::Signed(caller.clone()).into(), LeaseAsset::Estate(0), 100u32.into(), false, 10);
+	}: _(RawOrigin::Signed(caller.clone()), LeaseAsset::Estate(0))
+	verify {
+		assert_eq!(crate::Pallet::<T>::get_lease_offer(LeaseAsset::Estate(0)), None);
+	}
+
+	// accept_lease
+	accept_lease {
+		let caller = funded_account::<T>("caller", 10000);
+		let tenant = funded_account::<T>("tenant", 10000);
+
+		crate::Pallet::<T>::set_max_bounds(RawOrigin::Root.into(), METAVERSE_ID, MAX_BOUND);
+		crate::Pallet::<T>::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1], false);
+		crate::Pallet::<T>::offer_lease(RawOrigin::Signed(caller.clone()).into(), LeaseAsset::Estate(0), 100u32.into(), false, 10);
+	}: _(RawOrigin::Signed(tenant.clone()), LeaseAsset::Estate(0))
+	verify {
+		assert_eq!(crate::Pallet::<T>::get_land_unit_tenant(&METAVERSE_ID, &COORDINATE_IN_1), Some(tenant.clone()));
+	}
+
+	// end_lease
+	end_lease {
+		let caller = funded_account::<T>("caller", 10000);
+		let tenant = funded_account::<T>("tenant", 10000);
+
+		crate::Pallet::<T>::set_max_bounds(RawOrigin::Root.into(), METAVERSE_ID, MAX_BOUND);
+		crate::Pallet::<T>::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1], false);
+		crate::Pallet::<T>::offer_lease(RawOrigin::Signed(caller.clone()).into(), LeaseAsset::Estate(0), 100u32.into(), false, 10);
+		crate::Pallet::<T>::accept_lease(RawOrigin::Signed(tenant.clone()).into(), LeaseAsset::Estate(0));
+	}: _(RawOrigin::Signed(tenant.clone()), LeaseAsset::Estate(0))
+	verify {
+		assert_eq!(crate::Pallet::<T>::get_lease(LeaseAsset::Estate(0)), None);
+	}
+
+	// set_estate_commission
+	set_estate_commission {
+		let caller = funded_account::<T>("caller", 10000);
+
+		crate::Pallet::<T>::set_max_bounds(RawOrigin::Root.into(), METAVERSE_ID, MAX_BOUND);
+		crate::Pallet::<T>::mint_estate(RawOrigin::Root.into(), caller.clone(), METAVERSE_ID, vec![COORDINATE_IN_1], false);
+	}: _(RawOrigin::Signed(caller.clone()), 0, Perbill::from_percent(10))
+	verify {
+		assert_eq!(crate::Pallet::<T>::estate_commission(0), Perbill::from_percent(10));
+	}
+
+	// claim_rewards
+	claim_rewards {
+		let caller = funded_account::<T>("caller", 10000);
+
+		StakingRewards::<T>::insert(&caller, 1, dollar(1).unique_saturated_into());
+	}: _(RawOrigin::Signed(caller.clone()), 1)
+	verify {
+		assert_eq!(crate::Pallet::<T>::staking_rewards(&caller, 1), 0u32.into());
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::benchmarking::tests::new_test_ext(), crate::mock::Test);
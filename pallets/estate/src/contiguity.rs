@@ -0,0 +1,213 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Helper methods to validate the shape of an estate.
+use primitives::estate::LandConnectivity;
+use sp_std::{collections::btree_set::BTreeSet, vec, vec::Vec};
+
+const EDGE_NEIGHBOURS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const CORNER_NEIGHBOURS: [(i32, i32); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+fn neighbours(coordinate: (i32, i32), connectivity: LandConnectivity) -> Vec<(i32, i32)> {
+	let offsets = match connectivity {
+		LandConnectivity::FourConnected => EDGE_NEIGHBOURS.to_vec(),
+		LandConnectivity::EightConnected => [EDGE_NEIGHBOURS, CORNER_NEIGHBOURS].concat(),
+	};
+
+	offsets
+		.into_iter()
+		.filter_map(|(dx, dy)| Some((coordinate.0.checked_add(dx)?, coordinate.1.checked_add(dy)?)))
+		.collect()
+}
+
+/// Check whether the land units form a single connected shape. An empty estate is contiguous.
+pub fn is_contiguous(land_units: &[(i32, i32)], connectivity: LandConnectivity) -> bool {
+	let remaining: BTreeSet<(i32, i32)> = land_units.iter().cloned().collect();
+	let start = match remaining.iter().next() {
+		Some(coordinate) => *coordinate,
+		None => return true,
+	};
+
+	let mut visited = BTreeSet::new();
+	visited.insert(start);
+	let mut to_visit = vec![start];
+
+	while let Some(coordinate) = to_visit.pop() {
+		for neighbour in neighbours(coordinate, connectivity) {
+			if remaining.contains(&neighbour) && visited.insert(neighbour) {
+				to_visit.push(neighbour);
+			}
+		}
+	}
+
+	visited.len() == remaining.len()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Deterministic xorshift generator so the property tests are reproducible without extra
+	/// dependencies
+	struct Rng(u64);
+
+	impl Rng {
+		fn next(&mut self) -> u64 {
+			self.0 ^= self.0 << 13;
+			self.0 ^= self.0 >> 7;
+			self.0 ^= self.0 << 17;
+			self.0
+		}
+
+		fn range(&mut self, min: i32, max: i32) -> i32 {
+			min + (self.next() % (max - min + 1) as u64) as i32
+		}
+	}
+
+	const CASES: u64 = 500;
+
+	fn random_walk(rng: &mut Rng, size: usize, connectivity: LandConnectivity) -> Vec<(i32, i32)> {
+		let mut land_units = vec![(rng.range(-50, 50), rng.range(-50, 50))];
+		while land_units.len() < size {
+			let from = land_units[rng.next() as usize % land_units.len()];
+			let options = neighbours(from, connectivity);
+			let next = options[rng.next() as usize % options.len()];
+			if !land_units.contains(&next) {
+				land_units.push(next);
+			}
+		}
+		land_units
+	}
+
+	fn random_set(rng: &mut Rng, size: usize) -> Vec<(i32, i32)> {
+		(0..size).map(|_| (rng.range(-5, 5), rng.range(-5, 5))).collect()
+	}
+
+	/// Reference implementation: repeatedly merge groups of land units that touch each other
+	fn count_components(land_units: &[(i32, i32)], connectivity: LandConnectivity) -> usize {
+		let mut groups: Vec<BTreeSet<(i32, i32)>> = Vec::new();
+		for land_unit in land_units.iter().cloned().collect::<BTreeSet<_>>() {
+			let adjacent = neighbours(land_unit, connectivity);
+			let (touching, mut others): (Vec<_>, Vec<_>) = groups
+				.into_iter()
+				.partition(|group| adjacent.iter().any(|n| group.contains(n)));
+			let mut merged: BTreeSet<(i32, i32)> = touching.into_iter().flatten().collect();
+			merged.insert(land_unit);
+			others.push(merged);
+			groups = others;
+		}
+		groups.len()
+	}
+
+	#[test]
+	fn empty_and_single_land_unit_are_contiguous() {
+		assert!(is_contiguous(&[], LandConnectivity::FourConnected));
+		assert!(is_contiguous(&[(3, -7)], LandConnectivity::FourConnected));
+		assert!(is_contiguous(&[(3, -7), (3, -7)], LandConnectivity::EightConnected));
+	}
+
+	#[test]
+	fn diagonal_land_units_are_only_eight_connected() {
+		let land_units = [(0, 0), (1, 1), (2, 2)];
+		assert!(!is_contiguous(&land_units, LandConnectivity::FourConnected));
+		assert!(is_contiguous(&land_units, LandConnectivity::EightConnected));
+	}
+
+	#[test]
+	fn land_units_at_coordinate_limits_do_not_overflow() {
+		let land_units = [(i32::MAX, i32::MAX), (i32::MAX, i32::MIN)];
+		assert!(!is_contiguous(&land_units, LandConnectivity::EightConnected));
+		assert!(is_contiguous(
+			&[(i32::MIN, 0), (i32::MIN, 1)],
+			LandConnectivity::FourConnected
+		));
+	}
+
+	#[test]
+	fn random_walks_are_contiguous() {
+		let mut rng = Rng(0x5eed);
+		for case in 0..CASES {
+			for connectivity in [LandConnectivity::FourConnected, LandConnectivity::EightConnected] {
+				let land_units = random_walk(&mut rng, 1 + (case % 30) as usize, connectivity);
+				assert!(is_contiguous(&land_units, connectivity), "{:?}", land_units);
+			}
+		}
+	}
+
+	#[test]
+	fn four_connected_implies_eight_connected() {
+		let mut rng = Rng(0xfeed);
+		for case in 0..CASES {
+			let land_units = random_set(&mut rng, 1 + (case % 40) as usize);
+			if is_contiguous(&land_units, LandConnectivity::FourConnected) {
+				assert!(
+					is_contiguous(&land_units, LandConnectivity::EightConnected),
+					"{:?}",
+					land_units
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn order_does_not_matter() {
+		let mut rng = Rng(0xbeef);
+		for case in 0..CASES {
+			let land_units = random_set(&mut rng, 1 + (case % 40) as usize);
+			let mut reversed = land_units.clone();
+			reversed.reverse();
+			for connectivity in [LandConnectivity::FourConnected, LandConnectivity::EightConnected] {
+				assert_eq!(
+					is_contiguous(&land_units, connectivity),
+					is_contiguous(&reversed, connectivity),
+					"{:?}",
+					land_units
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn matches_reference_component_count() {
+		let mut rng = Rng(0xcafe);
+		for case in 0..CASES {
+			let land_units = random_set(&mut rng, 1 + (case % 40) as usize);
+			for connectivity in [LandConnectivity::FourConnected, LandConnectivity::EightConnected] {
+				assert_eq!(
+					is_contiguous(&land_units, connectivity),
+					count_components(&land_units, connectivity) == 1,
+					"{:?}",
+					land_units
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn detached_land_unit_breaks_contiguity() {
+		let mut rng = Rng(0xd00d);
+		for case in 0..CASES {
+			for connectivity in [LandConnectivity::FourConnected, LandConnectivity::EightConnected] {
+				let mut land_units = random_walk(&mut rng, 1 + (case % 30) as usize, connectivity);
+				// Random walks stay within 30 units of their start, so this is never adjacent
+				let (x, y) = land_units[0];
+				land_units.push((x + 100, y));
+				assert!(!is_contiguous(&land_units, connectivity), "{:?}", land_units);
+			}
+		}
+	}
+}
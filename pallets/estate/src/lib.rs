@@ -18,24 +18,25 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::pallet_prelude::*;
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get, PalletId};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional, PalletId};
 use frame_system::pallet_prelude::*;
 use frame_system::{ensure_root, ensure_signed};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Saturating},
-	DispatchError,
+	traits::{AccountIdConversion, One, SaturatedConversion, Saturating},
+	DispatchError, Perbill,
 };
-use sp_std::vec::Vec;
+use sp_std::{vec, vec::Vec};
 
 use auction_manager::{Auction, CheckAuctionItemHandler};
 use core_primitives::*;
 pub use pallet::*;
 use primitives::estate::EstateInfo;
 use primitives::{
-	estate::Estate, estate::LandUnitStatus, estate::OwnerId, Attributes, ClassId, EstateId, ItemId, MetaverseId,
-	NftMetadata, TokenId, UndeployedLandBlock, UndeployedLandBlockId, UndeployedLandBlockType, ESTATE_CLASS_ID,
-	LAND_CLASS_ID,
+	estate::Estate, estate::LandBounds, estate::LandConnectivity, estate::LandUnitStatus, estate::LeaseAsset,
+	estate::LeaseInfo, estate::LeaseOffer, estate::OwnerId, Attributes, ClassId, EstateId, FungibleTokenId, ItemId,
+	MetaverseId, NftMetadata, TokenId, UndeployedLandBlock, UndeployedLandBlockId, UndeployedLandBlockType,
+	ESTATE_CLASS_ID, LAND_CLASS_ID,
 };
 pub use rate::{MintingRateInfo, Range};
 pub use weights::WeightInfo;
@@ -43,6 +44,7 @@ pub use weights::WeightInfo;
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 
+mod contiguity;
 #[cfg(test)]
 mod mock;
 mod rate;
@@ -55,19 +57,23 @@ pub mod weights;
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::traits::{Currency, Imbalance, ReservableCurrency};
+	use orml_traits::MultiCurrency;
 	use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero};
 
 	use primitives::estate::EstateInfo;
 	use primitives::staking::{Bond, RoundInfo, StakeSnapshot};
 	use primitives::{RoundIndex, UndeployedLandBlockId};
 
-	use crate::rate::{round_issuance_range, MintingRateInfo};
+	use crate::rate::{round_issuance_range, round_staking_issuance, MintingRateInfo, Range};
 
 	use super::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(trait Store)]
 	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::config]
@@ -95,14 +101,31 @@ pub mod pallet {
 		type RewardPaymentDelay: Get<u32>;
 		/// NFT Trait required for land and estate tokenization
 		type NFTTokenizationSource: NFTTrait<Self::AccountId, BalanceOf<Self>, ClassId = ClassId, TokenId = TokenId>;
+		/// Maximum number of exclusion zones per metaverse
+		#[pallet::constant]
+		type MaxExclusionZones: Get<u32>;
+		/// Multi currencies used to pay rent in native or metaverse social token
+		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = FungibleTokenId, Balance = BalanceOf<Self>>;
+		/// Number of consecutive missed rent payments after which the tenant is evicted
+		#[pallet::constant]
+		type MaxMissedRentPayments: Get<u32>;
+		/// Maximum number of active leases
+		#[pallet::constant]
+		type MaxLeases: Get<u32>;
+		/// Maximum number of leases the rent is collected for per block
+		#[pallet::constant]
+		type MaxRentCollectionsPerBlock: Get<u32>;
+		/// Maximum share of the estate staking rewards the estate owner can take as commission
+		#[pallet::constant]
+		type MaxEstateCommission: Get<Perbill>;
 	}
 
 	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-	/// Get max bound
+	/// Get land bounds of a metaverse
 	#[pallet::storage]
 	#[pallet::getter(fn get_max_bounds)]
-	pub type MaxBounds<T: Config> = StorageMap<_, Blake2_128Concat, MetaverseId, (i32, i32), ValueQuery>;
+	pub type MaxBounds<T: Config> = StorageMap<_, Blake2_128Concat, MetaverseId, LandBounds, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn all_land_units_count)]
@@ -165,6 +188,11 @@ pub mod pallet {
 	/// Minting rate configuration
 	pub type MintingRateConfig<T: Config> = StorageValue<_, MintingRateInfo, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn staking_expectation)]
+	/// Expected total estate stake, bounds the staking rewards issued per round
+	pub type StakingExpectation<T: Config> = StorageValue<_, Range<BalanceOf<T>>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn total_stake)]
 	/// Total NEER locked by estate
@@ -199,24 +227,79 @@ pub mod pallet {
 	pub type EstateStake<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, EstateId, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn estate_commission)]
+	/// Share of the estate staking rewards paid to the estate owner
+	pub type EstateCommission<T: Config> = StorageMap<_, Twox64Concat, EstateId, Perbill, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_land_connectivity)]
+	/// Land connectivity the estate was created with, later changes of the metaverse land
+	/// connectivity only apply to new estates
+	pub type EstateLandConnectivity<T: Config> = StorageMap<_, Twox64Concat, EstateId, LandConnectivity, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn staking_rewards)]
+	/// Staking rewards claimable by account per round
+	pub type StakingRewards<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, RoundIndex, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_lease_offer)]
+	/// Estates and land units offered for lease
+	pub type LeaseOffers<T: Config> =
+		StorageMap<_, Twox64Concat, LeaseAsset, LeaseOffer<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_lease)]
+	/// Active leases of estates and land units
+	pub type Leases<T: Config> =
+		StorageMap<_, Twox64Concat, LeaseAsset, LeaseInfo<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn all_leases_count)]
+	/// Number of active leases
+	pub type AllLeasesCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_tenant_leases)]
+	/// Leases of a tenant by metaverse
+	pub type TenantLeases<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, (T::AccountId, MetaverseId), Twox64Concat, LeaseAsset, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_leased_land_unit_tenant)]
+	/// Tenant of each leased land unit, including the land units of leased estates
+	pub type LeasedLandUnits<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, MetaverseId, Twox64Concat, (i32, i32), T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn rent_collection_cursor)]
+	/// Raw key of the last lease the rent was collected for while the rent of the round is
+	/// collected, empty when the collection has not started yet
+	pub type RentCollectionCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	#[pallet::genesis_config]
-	pub struct GenesisConfig {
+	pub struct GenesisConfig<T: Config> {
 		pub minting_rate_config: MintingRateInfo,
+		pub staking_expectation: Range<BalanceOf<T>>,
 	}
 
 	#[cfg(feature = "std")]
-	impl Default for GenesisConfig {
+	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
 			GenesisConfig {
 				minting_rate_config: Default::default(),
+				staking_expectation: Default::default(),
 			}
 		}
 	}
 
 	#[pallet::genesis_build]
-	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
 		fn build(&self) {
 			<MintingRateConfig<T>>::put(self.minting_rate_config.clone());
+			<StakingExpectation<T>>::put(self.staking_expectation);
 
 			// Start Round 1 at Block 0
 			let round: RoundInfo<T::BlockNumber> = RoundInfo::new(1u32, 0u32.into(), T::MinBlocksPerRound::get());
@@ -245,8 +328,8 @@ pub mod pallet {
 		NewLandUnitMinted(OwnerId<T::AccountId, TokenId>, MetaverseId, (i32, i32)),
 		/// Estate Id, OwnerId, Metaverse Id, Coordinates
 		NewEstateMinted(EstateId, OwnerId<T::AccountId, TokenId>, MetaverseId, Vec<(i32, i32)>),
-		/// Metaverse Id, Min and Max Coordinate
-		MaxBoundSet(MetaverseId, (i32, i32)),
+		/// Metaverse Id, Land Bounds
+		MaxBoundSet(MetaverseId, LandBounds),
 		/// From Account Id, Metaverse Id, Undeployed Land Block Id, Coordinates
 		LandBlockDeployed(T::AccountId, MetaverseId, UndeployedLandBlockId, Vec<(i32, i32)>),
 		/// Beneficial Account Id, Undeployed Land Block Id
@@ -284,6 +367,28 @@ pub mod pallet {
 		EstateStakeLeft(OwnerId<T::AccountId, TokenId>, EstateId),
 		/// Account Id, Balance
 		StakingRewarded(T::AccountId, BalanceOf<T>),
+		/// Estate Id, Merged Estate Id, Owner Id
+		EstatesMerged(EstateId, EstateId, OwnerId<T::AccountId, TokenId>),
+		/// Estate Id, New Estate Id, Owner Id, Coordinates
+		EstateSplit(EstateId, EstateId, OwnerId<T::AccountId, TokenId>, Vec<(i32, i32)>),
+		/// Lease Asset, Lease Offer
+		LeaseOffered(LeaseAsset, LeaseOffer<T::AccountId, BalanceOf<T>>),
+		/// Lease Asset
+		LeaseOfferCancelled(LeaseAsset),
+		/// Lease Asset, Tenant Account Id, End Round
+		LeaseStarted(LeaseAsset, T::AccountId, RoundIndex),
+		/// Lease Asset, Tenant Account Id
+		LeaseEnded(LeaseAsset, T::AccountId),
+		/// Lease Asset, Tenant Account Id, Rent
+		RentCollected(LeaseAsset, T::AccountId, BalanceOf<T>),
+		/// Lease Asset, Tenant Account Id, Missed Payments
+		RentPaymentMissed(LeaseAsset, T::AccountId, u32),
+		/// Lease Asset, Tenant Account Id
+		TenantEvicted(LeaseAsset, T::AccountId),
+		/// Estate Id, Commission
+		EstateCommissionSet(EstateId, Perbill),
+		/// Account Id, Round, Balance
+		StakingRewardAccrued(T::AccountId, RoundIndex, BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -302,6 +407,14 @@ pub mod pallet {
 		LandUnitIsOutOfBound,
 		// No max bound set
 		NoMaxBoundSet,
+		// Land unit is inside an exclusion zone
+		LandUnitInExclusionZone,
+		// Estate land units are not connected to each other
+		EstateIsNotContiguous,
+		// Min of a range or exclusion zone is greater than its max
+		InvalidBounds,
+		// Exceed maximum number of exclusion zones
+		ExceedMaxExclusionZones,
 		UndeployedLandBlockNotFound,
 		UndeployedLandBlockIsNotTransferable,
 		UndeployedLandBlockDoesNotHaveEnoughLandUnits,
@@ -322,6 +435,30 @@ pub mod pallet {
 		Overflow,
 		EstateStakeAlreadyLeft,
 		AccountHasNoStake,
+		// Cannot merge an estate with itself
+		CannotMergeSameEstate,
+		// Estates belong to different metaverses
+		EstatesNotInSameMetaverse,
+		// Split must carve out some but not all land units of the estate
+		InvalidEstateSplit,
+		// Estate or land unit is offered for lease or leased
+		AssetIsLeased,
+		// Lease offer not found
+		LeaseOfferNotFound,
+		// Lease not found
+		LeaseNotFound,
+		// Lease duration must be at least one round
+		InvalidLeaseDuration,
+		// Owner cannot lease own estate or land unit
+		CannotLeaseOwnAsset,
+		// Exceed maximum number of active leases
+		ExceedMaxLeases,
+		// Metaverse has no social token to pay rent in
+		NoMetaverseSocialToken,
+		// Estate commission exceeds the maximum commission
+		ExceedMaxEstateCommission,
+		// No staking rewards to claim for the round
+		NoStakingRewards,
 	}
 
 	#[pallet::call]
@@ -330,11 +467,17 @@ pub mod pallet {
 		pub fn set_max_bounds(
 			origin: OriginFor<T>,
 			metaverse_id: MetaverseId,
-			new_bound: (i32, i32),
+			new_bound: LandBounds,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
-			MaxBounds::<T>::insert(metaverse_id, new_bound);
+			ensure!(new_bound.is_valid(), Error::<T>::InvalidBounds);
+			ensure!(
+				new_bound.exclusion_zones.len() as u32 <= T::MaxExclusionZones::get(),
+				Error::<T>::ExceedMaxExclusionZones
+			);
+
+			MaxBounds::<T>::insert(metaverse_id, new_bound.clone());
 
 			Self::deposit_event(Event::<T>::MaxBoundSet(metaverse_id, new_bound));
 
@@ -414,6 +557,7 @@ pub mod pallet {
 				!T::AuctionHandler::check_item_in_auction(ItemId::LandUnit(coordinate, metaverse_id)),
 				Error::<T>::LandUnitAlreadyInAuction
 			);
+			Self::ensure_not_leased(&LeaseAsset::LandUnit(metaverse_id, coordinate))?;
 
 			Self::do_transfer_landunit(coordinate, &who, &to, metaverse_id)?;
 			Ok(().into())
@@ -430,6 +574,9 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
+			let connectivity = T::MetaverseInfoSource::get_land_connectivity(metaverse_id);
+			Self::ensure_contiguous(connectivity, &coordinates)?;
+
 			// Generate new estate id
 			let new_estate_id = Self::get_new_estate_id()?;
 
@@ -460,7 +607,20 @@ pub mod pallet {
 			Self::set_total_land_unit(coordinates.len() as u64, false)?;
 
 			// Update estate information
-			Self::update_estate_information(new_estate_id, metaverse_id, &beneficiary, coordinates, is_tokenized)?;
+			let owner = Self::update_estate_information(
+				new_estate_id,
+				metaverse_id,
+				&beneficiary,
+				coordinates.clone(),
+				is_tokenized,
+				connectivity,
+			)?;
+			Self::deposit_event(Event::<T>::NewEstateMinted(
+				new_estate_id,
+				owner,
+				metaverse_id,
+				coordinates,
+			));
 			Ok(().into())
 		}
 
@@ -475,6 +635,9 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
+			let connectivity = T::MetaverseInfoSource::get_land_connectivity(metaverse_id);
+			Self::ensure_contiguous(connectivity, &coordinates)?;
+
 			// Generate new estate id
 			let new_estate_id = Self::get_new_estate_id()?;
 
@@ -493,13 +656,20 @@ pub mod pallet {
 			}
 
 			// Update estate information
-			Self::update_estate_information(
+			let owner = Self::update_estate_information(
 				new_estate_id,
 				metaverse_id,
 				&beneficiary,
 				coordinates.clone(),
 				is_tokenized,
+				connectivity,
 			)?;
+			Self::deposit_event(Event::<T>::NewEstateMinted(
+				new_estate_id,
+				owner,
+				metaverse_id,
+				coordinates,
+			));
 
 			Ok(().into())
 		}
@@ -517,6 +687,7 @@ pub mod pallet {
 				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
 				Error::<T>::EstateAlreadyInAuction
 			);
+			Self::ensure_not_leased(&LeaseAsset::Estate(estate_id))?;
 
 			Self::do_transfer_estate(estate_id, &who, &to)?;
 
@@ -769,6 +940,7 @@ pub mod pallet {
 				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
 				Error::<T>::EstateAlreadyInAuction
 			);
+			Self::ensure_not_leased(&LeaseAsset::Estate(estate_id))?;
 			let estate_info = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
 
 			EstateOwner::<T>::try_mutate_exists(&estate_id, |estate_owner| {
@@ -792,6 +964,8 @@ pub mod pallet {
 
 				// Remove estate
 				Estates::<T>::remove(&estate_id);
+				EstateCommission::<T>::remove(&estate_id);
+				EstateLandConnectivity::<T>::remove(&estate_id);
 
 				// Update total estates
 				let total_estates_count = Self::all_estates_count();
@@ -835,6 +1009,7 @@ pub mod pallet {
 				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
 				Error::<T>::EstateAlreadyInAuction
 			);
+			Self::ensure_not_leased(&LeaseAsset::Estate(estate_id))?;
 
 			let estate_info: EstateInfo = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
 
@@ -857,6 +1032,10 @@ pub mod pallet {
 				);
 			}
 
+			let mut new_land_units = estate_info.land_units.clone();
+			new_land_units.extend(land_units.iter().cloned());
+			Self::ensure_contiguous(Self::get_estate_land_connectivity(estate_id), &new_land_units)?;
+
 			// Mutate estates
 			Estates::<T>::try_mutate_exists(&estate_id, |maybe_estate_info| {
 				// Append new coordinates to estate
@@ -905,6 +1084,7 @@ pub mod pallet {
 				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
 				Error::<T>::EstateAlreadyInAuction
 			);
+			Self::ensure_not_leased(&LeaseAsset::Estate(estate_id))?;
 
 			let estate_info: EstateInfo = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
 
@@ -915,6 +1095,14 @@ pub mod pallet {
 				Error::<T>::NoPermission
 			);
 
+			let remaining_land_units: Vec<(i32, i32)> = estate_info
+				.land_units
+				.iter()
+				.filter(|land_unit| !land_units.contains(land_unit))
+				.cloned()
+				.collect();
+			Self::ensure_contiguous(Self::get_estate_land_connectivity(estate_id), &remaining_land_units)?;
+
 			// Mutate estates
 			Estates::<T>::try_mutate_exists(&estate_id, |maybe_estate_info| {
 				let mut mut_estate_info = maybe_estate_info.as_mut().ok_or(Error::<T>::EstateDoesNotExist)?;
@@ -946,6 +1134,288 @@ pub mod pallet {
 			})
 		}
 
+		/// Merge the other estate into the estate, both owned by the origin. The other estate
+		/// is removed and its stakes are moved to the merged estate.
+		#[pallet::weight(T::WeightInfo::merge_estates())]
+		#[transactional]
+		pub fn merge_estates(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			other_estate_id: EstateId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(estate_id != other_estate_id, Error::<T>::CannotMergeSameEstate);
+			for id in [estate_id, other_estate_id] {
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::Estate(id)),
+					Error::<T>::EstateAlreadyInAuction
+				);
+				Self::ensure_not_leased(&LeaseAsset::Estate(id))?;
+			}
+
+			let estate_info: EstateInfo = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			let other_estate_info: EstateInfo =
+				Estates::<T>::get(other_estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			ensure!(
+				estate_info.metaverse_id == other_estate_info.metaverse_id,
+				Error::<T>::EstatesNotInSameMetaverse
+			);
+
+			// Check estates ownership
+			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
+			let other_estate_owner_value = Self::get_estate_owner(&other_estate_id).ok_or(Error::<T>::NoPermission)?;
+			ensure!(
+				Self::check_if_land_or_estate_owner(&who, &estate_owner_value, true)
+					&& Self::check_if_land_or_estate_owner(&who, &other_estate_owner_value, true),
+				Error::<T>::NoPermission
+			);
+
+			// Stakers leaving the other estate would lose their exit when moved to the merged estate
+			for (account_id, _) in <EstateStake<T>>::iter_prefix(other_estate_id) {
+				ensure!(
+					<ExitQueue<T>>::get(&account_id, other_estate_id) == None,
+					Error::<T>::EstateStakeAlreadyLeft
+				);
+			}
+
+			let mut merged_land_units = estate_info.land_units.clone();
+			merged_land_units.extend(other_estate_info.land_units.iter().cloned());
+			Self::ensure_contiguous(Self::get_estate_land_connectivity(estate_id), &merged_land_units)?;
+
+			// Burn the estate token of the merged estate
+			if let OwnerId::Token(t) = other_estate_owner_value {
+				T::NFTTokenizationSource::burn_nft(&who, &(ESTATE_CLASS_ID, t))?;
+			}
+			EstateOwner::<T>::remove(&other_estate_id);
+			Estates::<T>::remove(&other_estate_id);
+			EstateCommission::<T>::remove(&other_estate_id);
+			EstateLandConnectivity::<T>::remove(&other_estate_id);
+			Estates::<T>::insert(
+				estate_id,
+				EstateInfo {
+					metaverse_id: estate_info.metaverse_id,
+					land_units: merged_land_units,
+				},
+			);
+
+			// Update total estates
+			let total_estates_count = Self::all_estates_count();
+			let new_total_estates_count = total_estates_count
+				.checked_sub(One::one())
+				.ok_or("Overflow deduct count from total estates")?;
+			AllEstatesCount::<T>::put(new_total_estates_count);
+
+			// Move land units of the merged estate to the estate account
+			let estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account(estate_id);
+			Self::move_land_units_to_estate(
+				estate_info.metaverse_id,
+				&other_estate_info.land_units,
+				&estate_account_id,
+			);
+
+			// Move stakes of the merged estate, total stake stays the same
+			for (account_id, amount) in <EstateStake<T>>::drain_prefix(other_estate_id) {
+				<EstateStake<T>>::mutate(estate_id, &account_id, |staked| *staked = staked.saturating_add(amount));
+			}
+
+			Self::deposit_event(Event::<T>::EstatesMerged(
+				estate_id,
+				other_estate_id,
+				estate_owner_value,
+			));
+
+			Ok(().into())
+		}
+
+		/// Carve land units out of the estate into a new estate owned by the origin. Stakes stay
+		/// with the original estate.
+		#[pallet::weight(T::WeightInfo::split_estate())]
+		#[transactional]
+		pub fn split_estate(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			land_units: Vec<(i32, i32)>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
+				Error::<T>::EstateAlreadyInAuction
+			);
+			Self::ensure_not_leased(&LeaseAsset::Estate(estate_id))?;
+
+			let estate_info: EstateInfo = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+
+			// Check estate ownership
+			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
+			ensure!(
+				Self::check_if_land_or_estate_owner(&who, &estate_owner_value, true),
+				Error::<T>::NoPermission
+			);
+
+			let remaining_land_units: Vec<(i32, i32)> = estate_info
+				.land_units
+				.iter()
+				.filter(|land_unit| !land_units.contains(land_unit))
+				.cloned()
+				.collect();
+			let mut unique_land_units = land_units.clone();
+			unique_land_units.sort();
+			unique_land_units.dedup();
+			ensure!(
+				!land_units.is_empty()
+					&& !remaining_land_units.is_empty()
+					&& unique_land_units.len() == land_units.len()
+					&& remaining_land_units.len() + land_units.len() == estate_info.land_units.len(),
+				Error::<T>::InvalidEstateSplit
+			);
+			let connectivity = Self::get_estate_land_connectivity(estate_id);
+			Self::ensure_contiguous(connectivity, &land_units)?;
+			Self::ensure_contiguous(connectivity, &remaining_land_units)?;
+
+			Estates::<T>::insert(
+				estate_id,
+				EstateInfo {
+					metaverse_id: estate_info.metaverse_id,
+					land_units: remaining_land_units,
+				},
+			);
+
+			// The new estate is tokenized and keeps the land connectivity when the original estate is
+			let new_estate_id = Self::get_new_estate_id()?;
+			let is_tokenized = matches!(estate_owner_value, OwnerId::Token(_));
+			let new_estate_owner_value = Self::update_estate_information(
+				new_estate_id,
+				estate_info.metaverse_id,
+				&who,
+				land_units.clone(),
+				is_tokenized,
+				connectivity,
+			)?;
+
+			let new_estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account(new_estate_id);
+			Self::move_land_units_to_estate(estate_info.metaverse_id, &land_units, &new_estate_account_id);
+
+			Self::deposit_event(Event::<T>::EstateSplit(
+				estate_id,
+				new_estate_id,
+				new_estate_owner_value,
+				land_units,
+			));
+
+			Ok(().into())
+		}
+
+		/// Offer an estate or land unit for lease. Rent is paid each round in native token or
+		/// the social token of the metaverse.
+		#[pallet::weight(T::WeightInfo::offer_lease())]
+		pub fn offer_lease(
+			origin: OriginFor<T>,
+			asset: LeaseAsset,
+			rent_per_round: BalanceOf<T>,
+			use_social_token: bool,
+			duration: RoundIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let metaverse_id = Self::ensure_lease_asset_owner(&who, &asset)?;
+			Self::ensure_not_leased(&asset)?;
+			ensure!(duration > 0, Error::<T>::InvalidLeaseDuration);
+
+			let currency_id = if use_social_token {
+				match T::MetaverseInfoSource::get_metaverse_token(metaverse_id) {
+					Some(FungibleTokenId::NativeToken(_)) | None => {
+						return Err(Error::<T>::NoMetaverseSocialToken.into());
+					}
+					Some(social_token) => social_token,
+				}
+			} else {
+				FungibleTokenId::NativeToken(0)
+			};
+
+			let offer = LeaseOffer {
+				lessor: who,
+				currency_id,
+				rent_per_round,
+				duration,
+			};
+			LeaseOffers::<T>::insert(asset, offer.clone());
+
+			Self::deposit_event(Event::<T>::LeaseOffered(asset, offer));
+
+			Ok(().into())
+		}
+
+		/// Cancel a lease offer that has not been accepted yet
+		#[pallet::weight(T::WeightInfo::cancel_lease_offer())]
+		pub fn cancel_lease_offer(origin: OriginFor<T>, asset: LeaseAsset) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let offer = LeaseOffers::<T>::get(asset).ok_or(Error::<T>::LeaseOfferNotFound)?;
+			ensure!(offer.lessor == who, Error::<T>::NoPermission);
+
+			LeaseOffers::<T>::remove(asset);
+
+			Self::deposit_event(Event::<T>::LeaseOfferCancelled(asset));
+
+			Ok(().into())
+		}
+
+		/// Accept a lease offer, paying the rent of the current round upfront. The tenant gets
+		/// builder rights while the owner keeps the estate or land unit.
+		#[pallet::weight(T::WeightInfo::accept_lease())]
+		#[transactional]
+		pub fn accept_lease(origin: OriginFor<T>, asset: LeaseAsset) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let offer = LeaseOffers::<T>::get(asset).ok_or(Error::<T>::LeaseOfferNotFound)?;
+			ensure!(offer.lessor != who, Error::<T>::CannotLeaseOwnAsset);
+			ensure!(
+				Self::all_leases_count() < T::MaxLeases::get(),
+				Error::<T>::ExceedMaxLeases
+			);
+			// Tokenized assets can change hands outside this pallet
+			Self::ensure_lease_asset_owner(&offer.lessor, &asset)?;
+
+			T::MultiCurrency::transfer(offer.currency_id, &who, &offer.lessor, offer.rent_per_round)?;
+
+			let current_round = Self::round().current;
+			let end_round = current_round.saturating_add(offer.duration);
+			Self::insert_lease(
+				asset,
+				LeaseInfo {
+					lessor: offer.lessor,
+					tenant: who.clone(),
+					currency_id: offer.currency_id,
+					rent_per_round: offer.rent_per_round,
+					end_round,
+					missed_payments: 0,
+					collected_round: current_round,
+				},
+			);
+			LeaseOffers::<T>::remove(asset);
+
+			Self::deposit_event(Event::<T>::LeaseStarted(asset, who, end_round));
+
+			Ok(().into())
+		}
+
+		/// Tenant ends the lease before the end round
+		#[pallet::weight(T::WeightInfo::end_lease())]
+		pub fn end_lease(origin: OriginFor<T>, asset: LeaseAsset) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let lease = Leases::<T>::get(asset).ok_or(Error::<T>::LeaseNotFound)?;
+			ensure!(lease.tenant == who, Error::<T>::NoPermission);
+
+			Self::remove_lease(asset, &who);
+
+			Self::deposit_event(Event::<T>::LeaseEnded(asset, who));
+
+			Ok(().into())
+		}
+
 		/// Bond native token to the estate
 		#[pallet::weight(T::WeightInfo::bond_more())]
 		pub fn bond_more(origin: OriginFor<T>, estate_id: EstateId, more: BalanceOf<T>) -> DispatchResultWithPostInfo {
@@ -1054,53 +1524,129 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Set the share of the estate staking rewards paid to the estate owner
+		#[pallet::weight(T::WeightInfo::set_estate_commission())]
+		pub fn set_estate_commission(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			commission: Perbill,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+
+			// Check estate ownership
+			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
+			ensure!(
+				Self::check_if_land_or_estate_owner(&who, &estate_owner_value, true),
+				Error::<T>::NoPermission
+			);
+
+			ensure!(
+				commission <= T::MaxEstateCommission::get(),
+				Error::<T>::ExceedMaxEstateCommission
+			);
+
+			<EstateCommission<T>>::insert(estate_id, commission);
+
+			Self::deposit_event(Event::EstateCommissionSet(estate_id, commission));
+
+			Ok(().into())
+		}
+
+		/// Claim the staking rewards of a paid out round
+		#[pallet::weight(T::WeightInfo::claim_rewards())]
+		pub fn claim_rewards(origin: OriginFor<T>, round: RoundIndex) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let reward = <StakingRewards<T>>::get(&who, round);
+			ensure!(!reward.is_zero(), Error::<T>::NoStakingRewards);
+
+			let imb = T::Currency::deposit_into_existing(&who, reward)?;
+			<StakingRewards<T>>::remove(&who, round);
+
+			Self::deposit_event(Event::StakingRewarded(who, imb.peek()));
+
+			Ok(().into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		fn pay_stakers(next: RoundIndex) {
+		/// Record the staking rewards of the round delayed by `RewardPaymentDelay` in the staking
+		/// rewards ledger. Returns the number of stake snapshots read and ledger entries written.
+		fn pay_stakers(next: RoundIndex) -> (u64, u64) {
 			// payout is next - duration rounds ago => next - duration > 0 else return early
 			let duration = T::RewardPaymentDelay::get();
 			if next <= duration {
-				return;
+				return (0, 0);
 			}
 			let round_to_payout = next - duration;
 
+			// nothing was staked, no rewards to share
+			let total_staked = <Staked<T>>::take(round_to_payout);
+			if total_staked.is_zero() {
+				<AtStake<T>>::remove_prefix(round_to_payout, None);
+				return (0, 0);
+			}
+
 			// issue BIT for rewards distribution
-			let total_staked = <Staked<T>>::get(round_to_payout);
 			let total_issuance = Self::compute_issuance(total_staked);
+			let mut snapshots = 0u64;
+			let mut paid = 0u64;
 
-			let mut left_issuance = total_issuance;
-
-			// a local fn to transfer rewards to the account specified
-			let mint = |amt: BalanceOf<T>, to: T::AccountId| {
-				if let Ok(imb) = T::Currency::deposit_into_existing(&to, amt) {
-					Self::deposit_event(Event::StakingRewarded(to.clone(), imb.peek()));
+			// a local fn to record rewards of the account specified
+			let mut accrue = |amt: BalanceOf<T>, to: T::AccountId| {
+				if amt.is_zero() {
+					return;
 				}
+				<StakingRewards<T>>::mutate(&to, round_to_payout, |reward| *reward = reward.saturating_add(amt));
+				paid += 1;
+				Self::deposit_event(Event::StakingRewardAccrued(to, round_to_payout, amt));
 			};
 
 			for (estate_id, stake_snapshot) in <AtStake<T>>::drain_prefix(round_to_payout) {
-				for Bond { staker: owner, amount } in stake_snapshot.stakers {
-					// TODO: TBD on the rewards amount
-					let amount_due = amount;
-					mint(1u32.into(), owner);
+				snapshots += 1;
+				let estate_issuance = Perbill::from_rational(stake_snapshot.total_bond, total_staked) * total_issuance;
+
+				// estate owner takes the commission, stakers share the rest pro rata
+				let mut stakers_issuance = estate_issuance;
+				if let Some(owner) = Self::estate_owner_account(estate_id) {
+					let commission = <EstateCommission<T>>::get(estate_id) * estate_issuance;
+					stakers_issuance = estate_issuance.saturating_sub(commission);
+					accrue(commission, owner);
+				}
+
+				for Bond { staker, amount } in stake_snapshot.stakers {
+					let amount_due = Perbill::from_rational(amount, stake_snapshot.total_bond) * stakers_issuance;
+					accrue(amount_due, staker);
 				}
 			}
+
+			Self::deposit_event(Event::StakersPaid(round_to_payout));
+			(snapshots, paid)
 		}
 
-		/// Clear exit queue. return stake to account
-		fn clear_exit_queue(now: RoundIndex) {
-			for (account_id, estate_id, val) in <ExitQueue<T>>::drain() {
+		/// Clear exit queue. return stake to account. Returns the number of exits processed.
+		fn clear_exit_queue(_now: RoundIndex) -> u64 {
+			let mut exits = 0u64;
+			for (account_id, estate_id, _) in <ExitQueue<T>>::drain() {
 				let staked_amount = <EstateStake<T>>::get(estate_id, &account_id);
 
 				// return stake to account
 				T::Currency::unreserve(&account_id, staked_amount);
 
 				<EstateStake<T>>::remove(estate_id, &account_id);
+				exits += 1;
 			}
+			exits
 		}
 
-		fn update_stake_snapshot(next: RoundIndex) -> BalanceOf<T> {
+		/// Snapshot the stakes of every estate for the next round. Returns the total staked and
+		/// the number of stakes read.
+		fn update_stake_snapshot(next: RoundIndex) -> (BalanceOf<T>, u64) {
 			let mut total = BalanceOf::<T>::zero();
+			let mut stakes = 0u64;
 
 			for estate_id in <Estates<T>>::iter_keys() {
 				let mut total_bond = BalanceOf::<T>::zero();
@@ -1114,6 +1660,7 @@ pub mod pallet {
 
 					total += amount;
 					total_bond += amount;
+					stakes += 1;
 				}
 				if stakers.len() > 0 {
 					<AtStake<T>>::insert(next, estate_id, StakeSnapshot { stakers, total_bond });
@@ -1121,12 +1668,128 @@ pub mod pallet {
 			}
 
 			<TotalStake<T>>::put(total);
-			total
+			(total, stakes)
+		}
+
+		/// Collect rent of the next leases of the round, ending expired leases and evicting tenants
+		/// that missed too many payments. Returns the number of leases processed.
+		fn collect_rent(now: RoundIndex) -> u64 {
+			let cursor = match <RentCollectionCursor<T>>::get() {
+				Some(cursor) => cursor,
+				None => return 0,
+			};
+			let max_leases = T::MaxRentCollectionsPerBlock::get() as usize;
+			let leases = if cursor.is_empty() {
+				<Leases<T>>::iter()
+			} else {
+				<Leases<T>>::iter_from(cursor)
+			}
+			.take(max_leases)
+			.collect::<Vec<_>>();
+			let processed = leases.len() as u64;
+
+			match leases.last() {
+				Some((asset, _)) if leases.len() == max_leases => {
+					<RentCollectionCursor<T>>::put(<Leases<T>>::hashed_key_for(asset))
+				}
+				_ => <RentCollectionCursor<T>>::kill(),
+			}
+
+			for (asset, mut lease) in leases {
+				// Tokenized assets can be sold outside this pallet, which ends the lease
+				if now >= lease.end_round || !Self::is_lease_asset_owner(&lease.lessor, &asset) {
+					Self::remove_lease(asset, &lease.tenant);
+					Self::deposit_event(Event::LeaseEnded(asset, lease.tenant));
+					continue;
+				}
+				// Leases started this round paid the rent upfront
+				if lease.collected_round >= now {
+					continue;
+				}
+				lease.collected_round = now;
+
+				match T::MultiCurrency::transfer(lease.currency_id, &lease.tenant, &lease.lessor, lease.rent_per_round)
+				{
+					Ok(_) => {
+						lease.missed_payments = 0;
+						Self::deposit_event(Event::RentCollected(asset, lease.tenant.clone(), lease.rent_per_round));
+						<Leases<T>>::insert(asset, lease);
+					}
+					Err(_) => {
+						lease.missed_payments = lease.missed_payments.saturating_add(1);
+						if lease.missed_payments >= T::MaxMissedRentPayments::get() {
+							Self::remove_lease(asset, &lease.tenant);
+							Self::deposit_event(Event::TenantEvicted(asset, lease.tenant));
+						} else {
+							Self::deposit_event(Event::RentPaymentMissed(
+								asset,
+								lease.tenant.clone(),
+								lease.missed_payments,
+							));
+							<Leases<T>>::insert(asset, lease);
+						}
+					}
+				}
+			}
+
+			processed
 		}
 
 		fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
-			//TODO: need to decide on how much BIT need to be issued per session
-			staked.saturating_add(staked)
+			let expect = <StakingExpectation<T>>::get();
+			let expect = Range {
+				min: expect.min.saturated_into(),
+				ideal: expect.ideal.saturated_into(),
+				max: expect.max.saturated_into(),
+			};
+			round_staking_issuance::<T>(<MintingRateConfig<T>>::get(), expect, staked.saturated_into()).saturated_into()
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			let mut round = <Round<T>>::get();
+			let weight = if round.should_update(n) {
+				round.update(n);
+				<Round<T>>::put(round);
+
+				let round_issuance_per_round = round_issuance_range::<T>(<MintingRateConfig<T>>::get());
+				Self::deposit_event(Event::NewRound(n, round.current, round_issuance_per_round.max));
+
+				let (snapshots, paid) = Self::pay_stakers(round.current);
+				let exits = Self::clear_exit_queue(round.current);
+				Self::deposit_event(Event::ExitQueueCleared(round.current));
+				let (total_staked, stakes) = Self::update_stake_snapshot(round.current);
+				<Staked<T>>::insert(round.current, total_staked);
+				Self::deposit_event(Event::StakeSnapshotUpdated(round.current, total_staked));
+
+				// Rent of the new round is collected over the next blocks
+				<RentCollectionCursor<T>>::put(Vec::<u8>::new());
+
+				// Each paid snapshot reads the estate owner and commission, each exit reads and
+				// removes the queue entry, the stake and the staker account
+				let estates = <AllEstatesCount<T>>::get();
+				T::DbWeight::get().reads_writes(
+					6 + 4 * snapshots + paid + 3 * exits + 2 * estates + stakes,
+					5 + snapshots + paid + 3 * exits + estates,
+				)
+			} else {
+				T::DbWeight::get().reads(1)
+			};
+
+			let leases = Self::collect_rent(round.current);
+			weight.saturating_add(T::DbWeight::get().reads_writes(1 + 4 * leases, 1 + 4 * leases))
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() < STORAGE_VERSION {
+				let weight = Self::upgrade_land_bounds_v2();
+				STORAGE_VERSION.put::<Pallet<T>>();
+				weight
+			} else {
+				0
+			}
 		}
 	}
 }
@@ -1153,12 +1816,9 @@ impl<T: Config> Pallet<T> {
 
 		let max_bound = MaxBounds::<T>::get(metaverse_id);
 
-		// Check whether the coordinate is within the bound
-		ensure!(
-			(coordinate.0 >= max_bound.0 && max_bound.1 >= coordinate.0)
-				&& (coordinate.1 >= max_bound.0 && max_bound.1 >= coordinate.1),
-			Error::<T>::LandUnitIsOutOfBound
-		);
+		// Check whether the coordinate is within the bound and outside the exclusion zones
+		ensure!(max_bound.contains(coordinate), Error::<T>::LandUnitIsOutOfBound);
+		ensure!(!max_bound.is_excluded(coordinate), Error::<T>::LandUnitInExclusionZone);
 		let mut owner = OwnerId::Account(beneficiary.clone());
 
 		match land_unit_status {
@@ -1217,13 +1877,15 @@ impl<T: Config> Pallet<T> {
 		Ok(owner)
 	}
 
+	/// Store a new estate and mint its token when it is tokenized, returns the estate owner
 	fn update_estate_information(
 		new_estate_id: EstateId,
 		metaverse_id: MetaverseId,
 		beneficiary: &T::AccountId,
 		coordinates: Vec<(i32, i32)>,
 		is_tokenized: bool,
-	) -> DispatchResult {
+		connectivity: LandConnectivity,
+	) -> Result<OwnerId<T::AccountId, TokenId>, DispatchError> {
 		// Update total estates
 		let total_estates_count = Self::all_estates_count();
 		let new_total_estates_count = total_estates_count
@@ -1246,17 +1908,11 @@ impl<T: Config> Pallet<T> {
 		}
 
 		Estates::<T>::insert(new_estate_id, estate_info);
+		EstateLandConnectivity::<T>::insert(new_estate_id, connectivity);
 
 		EstateOwner::<T>::insert(new_estate_id, owner.clone());
 
-		Self::deposit_event(Event::<T>::NewEstateMinted(
-			new_estate_id.clone(),
-			owner.clone(),
-			metaverse_id,
-			coordinates.clone(),
-		));
-
-		Ok(())
+		Ok(owner)
 	}
 
 	fn get_new_undeployed_land_block_id() -> Result<UndeployedLandBlockId, DispatchError> {
@@ -1540,6 +2196,112 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Account of the estate owner, resolving tokenized estates to the token owner rather than its
+	/// renter
+	fn estate_owner_account(estate_id: EstateId) -> Option<T::AccountId> {
+		match Self::get_estate_owner(&estate_id)? {
+			OwnerId::Account(a) => Some(a),
+			OwnerId::Token(t) => T::NFTTokenizationSource::get_nft_owner(&(ESTATE_CLASS_ID, t)).ok(),
+		}
+	}
+
+	/// Metaverse and land units of the leased estate or land unit
+	fn lease_land_units(asset: &LeaseAsset) -> (MetaverseId, Vec<(i32, i32)>) {
+		match *asset {
+			LeaseAsset::Estate(estate_id) => Estates::<T>::get(estate_id)
+				.map(|estate_info| (estate_info.metaverse_id, estate_info.land_units))
+				.unwrap_or_default(),
+			LeaseAsset::LandUnit(metaverse_id, coordinate) => (metaverse_id, vec![coordinate]),
+		}
+	}
+
+	/// Store the lease and index its land units by tenant
+	fn insert_lease(asset: LeaseAsset, lease: LeaseInfo<T::AccountId, BalanceOf<T>>) {
+		let (metaverse_id, land_units) = Self::lease_land_units(&asset);
+		for coordinate in land_units {
+			LeasedLandUnits::<T>::insert(metaverse_id, coordinate, &lease.tenant);
+		}
+		TenantLeases::<T>::insert((lease.tenant.clone(), metaverse_id), asset, ());
+		Leases::<T>::insert(asset, lease);
+		AllLeasesCount::<T>::mutate(|count| *count = count.saturating_add(1));
+	}
+
+	/// Remove the lease and its tenant index
+	fn remove_lease(asset: LeaseAsset, tenant: &T::AccountId) {
+		let (metaverse_id, land_units) = Self::lease_land_units(&asset);
+		for coordinate in land_units {
+			LeasedLandUnits::<T>::remove(metaverse_id, coordinate);
+		}
+		TenantLeases::<T>::remove((tenant.clone(), metaverse_id), asset);
+		Leases::<T>::remove(asset);
+		AllLeasesCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+	}
+
+	fn ensure_not_leased(asset: &LeaseAsset) -> DispatchResult {
+		ensure!(!Self::is_leased(asset), Error::<T>::AssetIsLeased);
+		Ok(())
+	}
+
+	/// Whether the estate or land unit is leased or offered for lease
+	fn is_leased(asset: &LeaseAsset) -> bool {
+		LeaseOffers::<T>::contains_key(asset) || Leases::<T>::contains_key(asset)
+	}
+
+	/// Whether the account still owns the leased estate or land unit
+	fn is_lease_asset_owner(who: &T::AccountId, asset: &LeaseAsset) -> bool {
+		match *asset {
+			LeaseAsset::Estate(estate_id) => Self::get_estate_owner(&estate_id)
+				.map_or(false, |owner| Self::check_if_land_or_estate_owner(who, &owner, true)),
+			LeaseAsset::LandUnit(metaverse_id, coordinate) => Self::get_land_units(metaverse_id, coordinate)
+				.map_or(false, |owner| Self::check_if_land_or_estate_owner(who, &owner, false)),
+		}
+	}
+
+	/// Check the account owns the estate or land unit and it is not in auction, returns the
+	/// metaverse of the asset
+	fn ensure_lease_asset_owner(who: &T::AccountId, asset: &LeaseAsset) -> Result<MetaverseId, DispatchError> {
+		match *asset {
+			LeaseAsset::Estate(estate_id) => {
+				let estate_info = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+				let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
+				ensure!(
+					Self::check_if_land_or_estate_owner(who, &estate_owner_value, true),
+					Error::<T>::NoPermission
+				);
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
+					Error::<T>::EstateAlreadyInAuction
+				);
+				Ok(estate_info.metaverse_id)
+			}
+			LeaseAsset::LandUnit(metaverse_id, coordinate) => {
+				let land_owner_value =
+					Self::get_land_units(metaverse_id, coordinate).ok_or(Error::<T>::LandUnitDoesNotExist)?;
+				ensure!(
+					Self::check_if_land_or_estate_owner(who, &land_owner_value, false),
+					Error::<T>::NoPermission
+				);
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::LandUnit(coordinate, metaverse_id)),
+					Error::<T>::LandUnitAlreadyInAuction
+				);
+				Ok(metaverse_id)
+			}
+		}
+	}
+
+	fn move_land_units_to_estate(
+		metaverse_id: MetaverseId,
+		land_units: &[(i32, i32)],
+		estate_account_id: &T::AccountId,
+	) {
+		for land_unit in land_units {
+			LandUnits::<T>::mutate_exists(&metaverse_id, land_unit, |maybe_account| {
+				*maybe_account = Some(Self::update_land_on_estate_change(estate_account_id, &*maybe_account));
+			});
+		}
+	}
+
 	fn check_if_land_or_estate_owner(
 		who: &T::AccountId,
 		owner_id: &OwnerId<T::AccountId, TokenId>,
@@ -1556,6 +2318,30 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 	}
+
+	fn ensure_contiguous(connectivity: LandConnectivity, land_units: &[(i32, i32)]) -> DispatchResult {
+		ensure!(
+			contiguity::is_contiguous(land_units, connectivity),
+			Error::<T>::EstateIsNotContiguous
+		);
+		Ok(())
+	}
+
+	pub fn upgrade_land_bounds_v2() -> Weight {
+		let mut num_metaverses = 0;
+
+		MaxBounds::<T>::translate(|_k, max_bound: (i32, i32)| {
+			num_metaverses += 1;
+			// Existing square bounds apply the same range to both axes without exclusion zones
+			Some(LandBounds {
+				x: max_bound,
+				y: max_bound,
+				exclusion_zones: Vec::new(),
+			})
+		});
+
+		T::DbWeight::get().reads_writes(num_metaverses, num_metaverses)
+	}
 }
 
 impl<T: Config> MetaverseLandTrait<T::AccountId> for Pallet<T> {
@@ -1595,6 +2381,16 @@ impl<T: Config> MetaverseLandTrait<T::AccountId> for Pallet<T> {
 	fn is_user_own_metaverse_land(who: &T::AccountId, metaverse_id: &MetaverseId) -> bool {
 		Self::get_user_land_units(&who, metaverse_id).len() > 0
 	}
+
+	fn get_user_leased_land_units(who: &T::AccountId, metaverse_id: &MetaverseId) -> Vec<(i32, i32)> {
+		TenantLeases::<T>::iter_prefix((who.clone(), *metaverse_id))
+			.flat_map(|(asset, _)| Self::lease_land_units(&asset).1)
+			.collect()
+	}
+
+	fn get_land_unit_tenant(metaverse_id: &MetaverseId, coordinate: &(i32, i32)) -> Option<T::AccountId> {
+		Self::get_leased_land_unit_tenant(metaverse_id, coordinate)
+	}
 }
 
 impl<T: Config> UndeployedLandBlocksTrait<T::AccountId> for Pallet<T> {
@@ -1647,6 +2443,7 @@ impl<T: Config> Estate<T::AccountId> for Pallet<T> {
 			T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
 			Error::<T>::EstateNotInAuction
 		);
+		Self::ensure_not_leased(&LeaseAsset::Estate(estate_id))?;
 
 		let estate_id = Self::do_transfer_estate(estate_id, from, to)?;
 		Ok(estate_id)
@@ -1661,6 +2458,37 @@ impl<T: Config> Estate<T::AccountId> for Pallet<T> {
 			T::AuctionHandler::check_item_in_auction(ItemId::LandUnit(coordinate, to.1)),
 			Error::<T>::LandUnitNotInAuction
 		);
+		Self::ensure_not_leased(&LeaseAsset::LandUnit(to.1, coordinate))?;
+
+		let coordinate = Self::do_transfer_landunit(coordinate, from, &(to).0, to.1)?;
+		Ok(coordinate)
+	}
+
+	fn transfer_estate_by_offer(
+		estate_id: EstateId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+	) -> Result<EstateId, DispatchError> {
+		ensure!(
+			!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
+			Error::<T>::EstateAlreadyInAuction
+		);
+		Self::ensure_not_leased(&LeaseAsset::Estate(estate_id))?;
+
+		let estate_id = Self::do_transfer_estate(estate_id, from, to)?;
+		Ok(estate_id)
+	}
+
+	fn transfer_landunit_by_offer(
+		coordinate: (i32, i32),
+		from: &T::AccountId,
+		to: &(T::AccountId, MetaverseId),
+	) -> Result<(i32, i32), DispatchError> {
+		ensure!(
+			!T::AuctionHandler::check_item_in_auction(ItemId::LandUnit(coordinate, to.1)),
+			Error::<T>::LandUnitAlreadyInAuction
+		);
+		Self::ensure_not_leased(&LeaseAsset::LandUnit(to.1, coordinate))?;
 
 		let coordinate = Self::do_transfer_landunit(coordinate, from, &(to).0, to.1)?;
 		Ok(coordinate)
@@ -1674,6 +2502,25 @@ impl<T: Config> Estate<T::AccountId> for Pallet<T> {
 		Ok(LandUnits::<T>::contains_key(metaverse_id, coordinate))
 	}
 
+	fn check_estate_ownership(owner: &T::AccountId, estate_id: EstateId) -> Result<bool, DispatchError> {
+		let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+		Ok(Self::check_if_land_or_estate_owner(owner, &estate_owner_value, true))
+	}
+
+	fn check_landunit_ownership(
+		owner: &T::AccountId,
+		metaverse_id: MetaverseId,
+		coordinate: (i32, i32),
+	) -> Result<bool, DispatchError> {
+		let land_unit_owner_value =
+			Self::get_land_units(metaverse_id, coordinate).ok_or(Error::<T>::LandUnitDoesNotExist)?;
+		Ok(Self::check_if_land_or_estate_owner(
+			owner,
+			&land_unit_owner_value,
+			false,
+		))
+	}
+
 	fn get_total_land_units() -> u64 {
 		AllLandUnitsCount::<T>::get()
 	}
@@ -1681,4 +2528,12 @@ impl<T: Config> Estate<T::AccountId> for Pallet<T> {
 	fn get_total_undeploy_land_units() -> u64 {
 		TotalUndeployedLandUnit::<T>::get()
 	}
+
+	fn is_estate_leased(estate_id: EstateId) -> bool {
+		Self::is_leased(&LeaseAsset::Estate(estate_id))
+	}
+
+	fn is_landunit_leased(metaverse_id: MetaverseId, coordinate: (i32, i32)) -> bool {
+		Self::is_leased(&LeaseAsset::LandUnit(metaverse_id, coordinate))
+	}
 }
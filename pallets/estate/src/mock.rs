@@ -1,15 +1,22 @@
 #![cfg(test)]
 
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 
+use frame_support::traits::Nothing;
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
 use frame_system::EnsureSignedBy;
+use orml_traits::parameter_type_with_key;
 use sp_core::H256;
-use sp_runtime::{testing::Header, traits::IdentityLookup, DispatchError, Perbill};
+use sp_runtime::{
+	testing::Header,
+	traits::{AccountIdConversion, IdentityLookup},
+	DispatchError, Perbill,
+};
 use sp_std::default::Default;
 
 use auction_manager::{Auction, AuctionInfo, AuctionType, CheckAuctionItemHandler, ListingLevel};
-use primitives::{AssetId, Attributes, ClassId, FungibleTokenId, GroupCollectionId, NftMetadata, TokenId};
+use primitives::{Amount, AssetId, Attributes, ClassId, FungibleTokenId, GroupCollectionId, NftMetadata, TokenId};
 
 use crate as estate;
 
@@ -29,12 +36,17 @@ pub const METAVERSE_ID: MetaverseId = 0;
 pub const DOLLARS: Balance = 1_000_000_000_000_000_000;
 pub const ALICE_METAVERSE_ID: MetaverseId = 1;
 pub const BOB_METAVERSE_ID: MetaverseId = 2;
-pub const MAX_BOUND: (i32, i32) = (-100, 100);
+pub const MAX_BOUND: LandBounds = LandBounds {
+	x: (-100, 100),
+	y: (-100, 100),
+	exclusion_zones: Vec::new(),
+};
 pub const COORDINATE_IN_1: (i32, i32) = (-10, 10);
-pub const COORDINATE_IN_2: (i32, i32) = (-5, 5);
+pub const COORDINATE_IN_2: (i32, i32) = (-10, 11);
 pub const COORDINATE_OUT: (i32, i32) = (0, 101);
 pub const COORDINATE_IN_AUCTION: (i32, i32) = (99, 99);
 pub const ESTATE_IN_AUCTION: EstateId = 99;
+pub const BOB_SOCIAL_TOKEN: FungibleTokenId = FungibleTokenId::FungibleToken(1);
 
 pub const BOND_AMOUNT_1: Balance = 1000;
 pub const BOND_AMOUNT_2: Balance = 2000;
@@ -54,6 +66,15 @@ pub const OWNER_ID_ALICE: OwnerId<AccountId, TokenId> = OwnerId::Account(ALICE);
 pub const OWNER_LAND_ASSET_ID: OwnerId<AccountId, TokenId> = OwnerId::Token(ASSET_ID_1);
 pub const OWNER_ESTATE_ASSET_ID: OwnerId<AccountId, TokenId> = OwnerId::Token(ASSET_ID_2);
 
+thread_local! {
+	/// Accounts estate tokens are minted for by the mock NFT handler
+	pub static MINTED_ESTATE_NFTS: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+	/// Tokens burnt by the mock NFT handler
+	pub static BURNT_NFTS: RefCell<Vec<(ClassId, TokenId)>> = RefCell::new(Vec::new());
+	/// Owners of tokens moved outside the estate pallet, e.g. sold through the nft pallet
+	pub static NFT_OWNERS: RefCell<BTreeMap<(ClassId, TokenId), AccountId>> = RefCell::new(BTreeMap::new());
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = ALICE;
 }
@@ -110,14 +131,40 @@ impl pallet_balances::Config for Runtime {
 	type ReserveIdentifier = ();
 }
 
-// pub type AdaptedBasicCurrency =
-// currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: FungibleTokenId| -> Balance {
+		Default::default()
+	};
+}
 
 parameter_types! {
 	pub const GetNativeCurrencyId: FungibleTokenId = FungibleTokenId::NativeToken(0);
 	pub const MiningCurrencyId: FungibleTokenId = FungibleTokenId::MiningResource(0);
 	pub const LandTreasuryPalletId: PalletId = PalletId(*b"bit/land");
 	pub const MinimumLandPrice: Balance = 10 * DOLLARS;
+	pub const MetaverseTreasuryPalletId: PalletId = PalletId(*b"bit/trsy");
+	pub TreasuryModuleAccount: AccountId = MetaverseTreasuryPalletId::get().into_account();
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = FungibleTokenId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = orml_tokens::TransferDust<Runtime, TreasuryModuleAccount>;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+}
+
+pub type AdaptedBasicCurrency = currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
+
+impl currencies::Config for Runtime {
+	type Event = Event;
+	type MultiSocialCurrency = Tokens;
+	type NativeCurrency = AdaptedBasicCurrency;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 }
 
 pub struct MetaverseInfoSource {}
@@ -135,13 +182,23 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 		None
 	}
 
-	fn get_metaverse_token(_metaverse_id: u64) -> Option<FungibleTokenId> {
+	fn get_metaverse_token(metaverse_id: u64) -> Option<FungibleTokenId> {
+		if metaverse_id == BOB_METAVERSE_ID {
+			return Some(BOB_SOCIAL_TOKEN);
+		}
 		None
 	}
 
 	fn update_metaverse_token(_metaverse_id: u64, _currency_id: FungibleTokenId) -> Result<(), DispatchError> {
 		Ok(())
 	}
+
+	fn get_land_connectivity(metaverse_id: u64) -> primitives::estate::LandConnectivity {
+		if metaverse_id == BOB_METAVERSE_ID {
+			return primitives::estate::LandConnectivity::EightConnected;
+		}
+		primitives::estate::LandConnectivity::FourConnected
+	}
 }
 
 pub struct MockAuctionManager;
@@ -174,6 +231,7 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		_initial_amount: Self::Balance,
 		_start: u64,
 		_listing_level: ListingLevel<AccountId>,
+		_currency_id: FungibleTokenId,
 	) -> Result<u64, DispatchError> {
 		Ok(1)
 	}
@@ -239,6 +297,9 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 
 	fn check_ownership(who: &AccountId, asset_id: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
 		let nft_value = *asset_id;
+		if let Some(owner) = NFT_OWNERS.with(|owners| owners.borrow().get(&nft_value).cloned()) {
+			return Ok(owner == *who);
+		}
 		if (*who == ALICE && (nft_value.1 == 1 || nft_value.1 == 3))
 			|| (*who == BOB && (nft_value.1 == 2 || nft_value.1 == 4))
 			|| (*who == BENEFICIARY_ID && (nft_value.1 == 100 || nft_value.1 == 101))
@@ -288,6 +349,7 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 		metadata: NftMetadata,
 		attributes: Attributes,
 	) -> Result<TokenId, DispatchError> {
+		MINTED_ESTATE_NFTS.with(|minted| minted.borrow_mut().push(account));
 		match account {
 			ALICE => Ok(3),
 			BOB => Ok(4),
@@ -305,6 +367,7 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 	}
 
 	fn burn_nft(account: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult {
+		BURNT_NFTS.with(|burnt| burnt.borrow_mut().push(*nft));
 		Ok(())
 	}
 	fn is_transferable(nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
@@ -315,15 +378,39 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 		CLASS_FUND_ID
 	}
 
-	fn get_nft_detail(asset_id: (Self::ClassId, Self::TokenId)) -> Result<(NftClassData<Balance>), DispatchError> {
+	fn get_nft_detail(
+		asset_id: (Self::ClassId, Self::TokenId),
+	) -> Result<(NftClassData<Balance, AccountId>), DispatchError> {
 		let new_data = NftClassData {
 			deposit: 0,
 			attributes: test_attributes(1),
 			token_type: TokenType::Transferable,
 			collection_type: CollectionType::Collectable,
+			royalty_fee: Perbill::from_percent(0),
+			royalty_recipient: ALICE,
 		};
 		Ok(new_data)
 	}
+
+	fn get_user_of(nft: &(Self::ClassId, Self::TokenId)) -> Result<AccountId, DispatchError> {
+		Ok(ALICE)
+	}
+
+	fn get_nft_owner(nft: &(Self::ClassId, Self::TokenId)) -> Result<AccountId, DispatchError> {
+		if let Some(owner) = NFT_OWNERS.with(|owners| owners.borrow().get(nft).cloned()) {
+			return Ok(owner);
+		}
+		match nft.1 {
+			1 | 3 => Ok(ALICE),
+			2 | 4 => Ok(BOB),
+			100 | 101 => Ok(BENEFICIARY_ID),
+			_ => Err(DispatchError::Other("Token not found")),
+		}
+	}
+
+	fn get_royalty_info(class_id: &Self::ClassId) -> Result<(Perbill, AccountId), DispatchError> {
+		Ok((Perbill::from_percent(0), ALICE))
+	}
 }
 
 parameter_types! {
@@ -331,6 +418,11 @@ parameter_types! {
 	pub const MinimumStake: Balance = 200;
 	/// Reward payments are delayed by 2 hours (2 * 300 * block_time)
 	pub const RewardPaymentDelay: u32 = 2;
+	pub const MaxExclusionZones: u32 = 2;
+	pub const MaxMissedRentPayments: u32 = 2;
+	pub const MaxLeases: u32 = 2;
+	pub const MaxRentCollectionsPerBlock: u32 = 1;
+	pub const MaxEstateCommission: Perbill = Perbill::from_percent(50);
 }
 
 impl Config for Runtime {
@@ -346,6 +438,12 @@ impl Config for Runtime {
 	type MinimumStake = MinimumStake;
 	type RewardPaymentDelay = RewardPaymentDelay;
 	type NFTTokenizationSource = MockNFTHandler;
+	type MaxExclusionZones = MaxExclusionZones;
+	type MultiCurrency = Currencies;
+	type MaxMissedRentPayments = MaxMissedRentPayments;
+	type MaxLeases = MaxLeases;
+	type MaxRentCollectionsPerBlock = MaxRentCollectionsPerBlock;
+	type MaxEstateCommission = MaxEstateCommission;
 }
 
 construct_runtime!(
@@ -356,6 +454,8 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Currencies: currencies::{Pallet, Storage, Call, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Call, Event<T>},
 		Estate: estate:: {Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -385,6 +485,12 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: vec![(ALICE, BOB_SOCIAL_TOKEN, 1000)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
@@ -15,7 +15,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-// Helper methods to compute the issuance rate for undeployed land.
+// Helper methods to compute the issuance rate for undeployed land and estate staking rewards.
 use crate::pallet::{Config, Pallet};
 use crate::{AllLandUnitsCount, TotalUndeployedLandUnit};
 use codec::{Decode, Encode};
@@ -55,14 +55,8 @@ pub fn round_issuance_range<T: Config>(config: MintingRateInfo) -> Range<u64> {
 	// Total circulating
 	let circulating = total_land_unit_circulating.saturating_add(total_undeployed_land_unit_circulating);
 
-	// Total annual minting percent
-	let annual_percentage = Perbill::from_percent(config.annual as u32).deconstruct();
-
 	// Round percentage minting rate
-	let round_percentage = annual_percentage.checked_div(total_round_per_year).unwrap();
-
-	// Convert to percentage
-	let round_percentage_per_bill = Perbill::from_parts(round_percentage);
+	let round_percentage_per_bill = round_rate(config.annual, total_round_per_year);
 
 	// Return range - could implement more cases in the future.
 	Range {
@@ -72,6 +66,32 @@ pub fn round_issuance_range<T: Config>(config: MintingRateInfo) -> Range<u64> {
 	}
 }
 
+/// Compute round staking issuance from the round minting rate, the expected total stake and the
+/// total staked of the round
+pub fn round_staking_issuance<T: Config>(config: MintingRateInfo, expect: Range<u128>, staked: u128) -> u128 {
+	let round_percentage_per_bill = round_rate(config.annual, rounds_per_year::<T>());
+	staking_issuance(round_percentage_per_bill, expect, staked)
+}
+
+/// Convert the annual minting percentage to the minting rate of a single round
+fn round_rate(annual: u64, total_round_per_year: u32) -> Perbill {
+	let annual_percentage = Perbill::from_percent(annual as u32).deconstruct();
+	Perbill::from_parts(annual_percentage.checked_div(total_round_per_year).unwrap())
+}
+
+/// Staking issuance follows the total staked above the ideal stake, capped at the issuance of the
+/// maximum stake. Stakes between the minimum and ideal expectation share the issuance of the ideal
+/// stake, below the minimum that boosted issuance is scaled down with the stake.
+fn staking_issuance(round_rate: Perbill, expect: Range<u128>, staked: u128) -> u128 {
+	if staked < expect.min {
+		Perbill::from_rational(staked, expect.min) * (round_rate * expect.ideal)
+	} else if staked <= expect.ideal {
+		round_rate * expect.ideal
+	} else {
+		round_rate * staked.min(expect.max)
+	}
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
 pub struct Range<T> {
@@ -164,4 +184,32 @@ mod tests {
 		assert_eq!(round_issuance.ideal, 10);
 		assert_eq!(round_issuance.max, 10);
 	}
+
+	#[test]
+	fn staking_issuance_within_expectations() {
+		// 10% per round, expect between 1_000 and 5_000 staked
+		let expect = Range {
+			min: 1_000,
+			ideal: 2_000,
+			max: 5_000,
+		};
+		let rate = round_rate(10, 1);
+
+		assert_eq!(staking_issuance(rate, expect, 2_500), 250);
+		// Boosted to the issuance of the ideal expectation
+		assert_eq!(staking_issuance(rate, expect, 1_500), 200);
+		// Boost is scaled down below the minimum expectation
+		assert_eq!(staking_issuance(rate, expect, 500), 100);
+		assert_eq!(staking_issuance(rate, expect, 0), 0);
+		// Capped to the issuance of the maximum expectation
+		assert_eq!(staking_issuance(rate, expect, 10_000), 500);
+		// No expectations, no staking issuance
+		assert_eq!(staking_issuance(rate, Default::default(), 10_000), 0);
+	}
+
+	#[test]
+	fn round_rate_splits_annual_rate() {
+		assert_eq!(round_rate(5, 10), Perbill::from_parts(5_000_000));
+		assert_eq!(round_rate(10, 1), Perbill::from_percent(10));
+	}
 }
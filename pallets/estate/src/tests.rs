@@ -17,7 +17,10 @@
 
 #![cfg(test)]
 
+use frame_support::traits::OnInitialize;
 use frame_support::{assert_err, assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use primitives::{staking::RoundInfo, RoundIndex};
 use sp_runtime::traits::BadOrigin;
 
 use mock::{Event, *};
@@ -86,6 +89,119 @@ fn mint_land_should_reject_out_bound() {
 	});
 }
 
+#[test]
+fn set_max_bound_should_reject_invalid_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		let invalid_range = LandBounds {
+			x: (100, -100),
+			..MAX_BOUND
+		};
+		assert_noop!(
+			EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, invalid_range),
+			Error::<Runtime>::InvalidBounds
+		);
+
+		let invalid_zone = LandBounds {
+			exclusion_zones: vec![((5, 5), (0, 0))],
+			..MAX_BOUND
+		};
+		assert_noop!(
+			EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, invalid_zone),
+			Error::<Runtime>::InvalidBounds
+		);
+
+		let too_many_zones = LandBounds {
+			exclusion_zones: vec![((0, 0), (1, 1)), ((2, 2), (3, 3)), ((4, 4), (5, 5))],
+			..MAX_BOUND
+		};
+		assert_noop!(
+			EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, too_many_zones),
+			Error::<Runtime>::ExceedMaxExclusionZones
+		);
+	});
+}
+
+#[test]
+fn mint_land_should_respect_separate_axis_ranges() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bounds = LandBounds {
+			x: (-10, 10),
+			y: (0, 200),
+			exclusion_zones: vec![],
+		};
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, bounds));
+
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			(10, 150),
+			false
+		));
+
+		assert_noop!(
+			EstateModule::mint_land(Origin::root(), BENEFICIARY_ID, METAVERSE_ID, (11, 150), false),
+			Error::<Runtime>::LandUnitIsOutOfBound
+		);
+		assert_noop!(
+			EstateModule::mint_land(Origin::root(), BENEFICIARY_ID, METAVERSE_ID, (0, -1), false),
+			Error::<Runtime>::LandUnitIsOutOfBound
+		);
+	});
+}
+
+#[test]
+fn mint_land_should_reject_exclusion_zone() {
+	ExtBuilder::default().build().execute_with(|| {
+		let bounds = LandBounds {
+			exclusion_zones: vec![((-1, -100), (1, 100))],
+			..MAX_BOUND
+		};
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, bounds));
+
+		assert_noop!(
+			EstateModule::mint_land(Origin::root(), BENEFICIARY_ID, METAVERSE_ID, (0, 50), false),
+			Error::<Runtime>::LandUnitInExclusionZone
+		);
+		assert_noop!(
+			EstateModule::mint_lands(
+				Origin::root(),
+				BENEFICIARY_ID,
+				METAVERSE_ID,
+				vec![(1, -100), COORDINATE_IN_1],
+				false
+			),
+			Error::<Runtime>::LandUnitInExclusionZone
+		);
+
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			(2, 50),
+			false
+		));
+	});
+}
+
+#[test]
+fn upgrade_land_bounds_v2_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		frame_support::storage::unhashed::put(&MaxBounds::<Runtime>::hashed_key_for(METAVERSE_ID), &(-50i32, 50i32));
+
+		EstateModule::upgrade_land_bounds_v2();
+
+		assert_eq!(
+			EstateModule::get_max_bounds(METAVERSE_ID),
+			LandBounds {
+				x: (-50, 50),
+				y: (-50, 50),
+				exclusion_zones: vec![],
+			}
+		);
+	});
+}
+
 #[test]
 fn mint_land_should_work_with_one_coordinate() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -817,6 +933,163 @@ fn remove_land_unit_from_estate_should_work() {
 	});
 }
 
+#[test]
+fn mint_estate_should_reject_non_contiguous_land_units() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+
+		assert_noop!(
+			EstateModule::mint_estate(
+				Origin::root(),
+				BENEFICIARY_ID,
+				METAVERSE_ID,
+				vec![COORDINATE_IN_1, (-9, 11)],
+				false
+			),
+			Error::<Runtime>::EstateIsNotContiguous
+		);
+
+		assert_ok!(EstateModule::mint_lands(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, (0, 0)],
+			false
+		));
+		assert_noop!(
+			EstateModule::create_estate(
+				Origin::root(),
+				BENEFICIARY_ID,
+				METAVERSE_ID,
+				vec![COORDINATE_IN_1, (0, 0)],
+				false
+			),
+			Error::<Runtime>::EstateIsNotContiguous
+		);
+	});
+}
+
+#[test]
+fn mint_estate_should_accept_diagonal_land_units_with_eight_connectivity() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(
+			Origin::root(),
+			BOB_METAVERSE_ID,
+			MAX_BOUND
+		));
+
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			BOB_METAVERSE_ID,
+			vec![COORDINATE_IN_1, (-9, 11)],
+			false
+		));
+
+		assert_eq!(
+			EstateModule::get_estates(0),
+			Some(EstateInfo {
+				metaverse_id: BOB_METAVERSE_ID,
+				land_units: vec![COORDINATE_IN_1, (-9, 11)]
+			})
+		);
+	});
+}
+
+#[test]
+fn estate_should_keep_land_connectivity_it_was_created_with() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(
+			Origin::root(),
+			BOB_METAVERSE_ID,
+			MAX_BOUND
+		));
+
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			BOB_METAVERSE_ID,
+			vec![COORDINATE_IN_1, (-9, 11), (-8, 12), (-7, 13)],
+			false
+		));
+		assert_eq!(
+			EstateModule::get_estate_land_connectivity(0),
+			LandConnectivity::EightConnected
+		);
+
+		// split estate keeps the land connectivity of the original estate
+		assert_ok!(EstateModule::split_estate(
+			Origin::signed(BENEFICIARY_ID),
+			0,
+			vec![(-7, 13)]
+		));
+		assert_eq!(
+			EstateModule::get_estate_land_connectivity(1),
+			LandConnectivity::EightConnected
+		);
+
+		// shape is validated against the connectivity recorded for the estate
+		EstateLandConnectivity::<Runtime>::insert(0, LandConnectivity::FourConnected);
+		assert_noop!(
+			EstateModule::split_estate(Origin::signed(BENEFICIARY_ID), 0, vec![(-8, 12)]),
+			Error::<Runtime>::EstateIsNotContiguous
+		);
+	});
+}
+
+#[test]
+fn add_land_unit_to_estate_should_reject_non_contiguous_land_units() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			false
+		));
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			(0, 0),
+			false
+		));
+
+		assert_noop!(
+			EstateModule::add_land_unit_to_estate(Origin::signed(BENEFICIARY_ID), 0, vec![(0, 0)]),
+			Error::<Runtime>::EstateIsNotContiguous
+		);
+	});
+}
+
+#[test]
+fn remove_land_unit_from_estate_should_reject_splitting_estate() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2, (-10, 12)],
+			false
+		));
+
+		assert_noop!(
+			EstateModule::remove_land_unit_from_estate(Origin::signed(BENEFICIARY_ID), 0, vec![COORDINATE_IN_2]),
+			Error::<Runtime>::EstateIsNotContiguous
+		);
+
+		assert_ok!(EstateModule::remove_land_unit_from_estate(
+			Origin::signed(BENEFICIARY_ID),
+			0,
+			vec![(-10, 12)]
+		));
+	});
+}
+
 #[test]
 fn mint_estate_and_land_should_return_correct_total_land_unit() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -2568,3 +2841,874 @@ fn bond_more_should_reject_stake_has_already_left() {
 		);
 	});
 }
+
+#[test]
+fn merge_estates_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			false
+		));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_2],
+			false
+		));
+		assert_ok!(EstateModule::bond_more(
+			Origin::signed(BENEFICIARY_ID),
+			1,
+			BOND_AMOUNT_1
+		));
+		assert_eq!(EstateModule::all_estates_count(), 2);
+
+		assert_ok!(EstateModule::merge_estates(Origin::signed(BENEFICIARY_ID), 0, 1));
+
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstatesMerged(0, 1, OWNER_ACCOUNT_ID))
+		);
+		assert_eq!(
+			EstateModule::get_estates(0),
+			Some(EstateInfo {
+				metaverse_id: METAVERSE_ID,
+				land_units: vec![COORDINATE_IN_1, COORDINATE_IN_2]
+			})
+		);
+		assert_eq!(EstateModule::get_estates(1), None);
+		assert_eq!(EstateModule::get_estate_owner(1), None);
+		assert_eq!(EstateModule::all_estates_count(), 1);
+
+		let estate_account_id: AccountId = LandTreasuryPalletId::get().into_sub_account(0);
+		assert_eq!(
+			EstateModule::get_land_units(METAVERSE_ID, COORDINATE_IN_2),
+			Some(OwnerId::Account(estate_account_id))
+		);
+
+		// Stake follows the merged land
+		assert_eq!(EstateModule::estate_stake(0, BENEFICIARY_ID), BOND_AMOUNT_1);
+		assert_eq!(EstateModule::estate_stake(1, BENEFICIARY_ID), 0);
+		assert_eq!(EstateModule::total_stake(), BOND_AMOUNT_1);
+		assert_eq!(Balances::reserved_balance(BENEFICIARY_ID), BOND_AMOUNT_1);
+	});
+}
+
+#[test]
+fn merge_tokenized_estates_should_burn_merged_estate_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			true
+		));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_2],
+			true
+		));
+
+		assert_ok!(EstateModule::merge_estates(Origin::signed(BENEFICIARY_ID), 0, 1));
+
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstatesMerged(0, 1, OWNER_ESTATE_ASSET_ID))
+		);
+		assert_eq!(
+			BURNT_NFTS.with(|burnt| burnt.borrow().clone()),
+			vec![(ESTATE_CLASS_ID, ASSET_ID_2)]
+		);
+		assert_eq!(EstateModule::get_estate_owner(0), Some(OWNER_ESTATE_ASSET_ID));
+		assert_eq!(EstateModule::get_estate_owner(1), None);
+		assert_eq!(EstateModule::all_estates_count(), 1);
+	});
+}
+
+#[test]
+fn merge_estates_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			false
+		));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_2],
+			false
+		));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![(0, 0)],
+			false
+		));
+
+		assert_noop!(
+			EstateModule::merge_estates(Origin::signed(BENEFICIARY_ID), 0, 0),
+			Error::<Runtime>::CannotMergeSameEstate
+		);
+		assert_noop!(
+			EstateModule::merge_estates(Origin::signed(ALICE), 0, 1),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::merge_estates(Origin::signed(BENEFICIARY_ID), 0, 2),
+			Error::<Runtime>::EstateIsNotContiguous
+		);
+
+		assert_ok!(EstateModule::bond_more(
+			Origin::signed(BENEFICIARY_ID),
+			1,
+			BOND_AMOUNT_1
+		));
+		assert_ok!(EstateModule::leave_staking(Origin::signed(BENEFICIARY_ID), 1));
+		assert_noop!(
+			EstateModule::merge_estates(Origin::signed(BENEFICIARY_ID), 0, 1),
+			Error::<Runtime>::EstateStakeAlreadyLeft
+		);
+	});
+}
+
+#[test]
+fn split_estate_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2, (-10, 12)],
+			false
+		));
+		assert_ok!(EstateModule::bond_more(
+			Origin::signed(BENEFICIARY_ID),
+			0,
+			BOND_AMOUNT_1
+		));
+
+		assert_ok!(EstateModule::split_estate(
+			Origin::signed(BENEFICIARY_ID),
+			0,
+			vec![(-10, 12)]
+		));
+
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateSplit(0, 1, OWNER_ACCOUNT_ID, vec![(-10, 12)]))
+		);
+		assert_eq!(
+			EstateModule::get_estates(0),
+			Some(EstateInfo {
+				metaverse_id: METAVERSE_ID,
+				land_units: vec![COORDINATE_IN_1, COORDINATE_IN_2]
+			})
+		);
+		assert_eq!(
+			EstateModule::get_estates(1),
+			Some(EstateInfo {
+				metaverse_id: METAVERSE_ID,
+				land_units: vec![(-10, 12)]
+			})
+		);
+		assert_eq!(EstateModule::get_estate_owner(1), Some(OWNER_ACCOUNT_ID));
+		assert_eq!(EstateModule::all_estates_count(), 2);
+
+		let new_estate_account_id: AccountId = LandTreasuryPalletId::get().into_sub_account(1);
+		assert_eq!(
+			EstateModule::get_land_units(METAVERSE_ID, (-10, 12)),
+			Some(OwnerId::Account(new_estate_account_id))
+		);
+
+		// Stake stays with the original estate
+		assert_eq!(EstateModule::estate_stake(0, BENEFICIARY_ID), BOND_AMOUNT_1);
+		assert_eq!(EstateModule::estate_stake(1, BENEFICIARY_ID), 0);
+		assert_eq!(EstateModule::total_stake(), BOND_AMOUNT_1);
+	});
+}
+
+#[test]
+fn split_tokenized_estate_should_mint_new_estate_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2, (-10, 12)],
+			true
+		));
+		assert_eq!(
+			MINTED_ESTATE_NFTS.with(|minted| minted.borrow().clone()),
+			vec![BENEFICIARY_ID]
+		);
+		System::reset_events();
+
+		assert_ok!(EstateModule::split_estate(
+			Origin::signed(BENEFICIARY_ID),
+			0,
+			vec![(-10, 12)]
+		));
+
+		// only the split event is emitted for the new estate
+		assert_eq!(
+			System::events()
+				.into_iter()
+				.map(|record| record.event)
+				.filter(|event| matches!(event, Event::Estate(_)))
+				.collect::<Vec<_>>(),
+			vec![Event::Estate(crate::Event::EstateSplit(
+				0,
+				1,
+				OWNER_ESTATE_ASSET_ID,
+				vec![(-10, 12)]
+			))]
+		);
+		assert_eq!(
+			MINTED_ESTATE_NFTS.with(|minted| minted.borrow().clone()),
+			vec![BENEFICIARY_ID, BENEFICIARY_ID]
+		);
+		assert_eq!(EstateModule::get_estate_owner(1), Some(OWNER_ESTATE_ASSET_ID));
+		assert!(BURNT_NFTS.with(|burnt| burnt.borrow().is_empty()));
+	});
+}
+
+#[test]
+fn split_estate_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2, (-10, 12)],
+			false
+		));
+
+		assert_noop!(
+			EstateModule::split_estate(Origin::signed(ALICE), 0, vec![(-10, 12)]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::split_estate(Origin::signed(BENEFICIARY_ID), 0, vec![]),
+			Error::<Runtime>::InvalidEstateSplit
+		);
+		assert_noop!(
+			EstateModule::split_estate(
+				Origin::signed(BENEFICIARY_ID),
+				0,
+				vec![COORDINATE_IN_1, COORDINATE_IN_2, (-10, 12)]
+			),
+			Error::<Runtime>::InvalidEstateSplit
+		);
+		assert_noop!(
+			EstateModule::split_estate(Origin::signed(BENEFICIARY_ID), 0, vec![(0, 0)]),
+			Error::<Runtime>::InvalidEstateSplit
+		);
+		assert_noop!(
+			EstateModule::split_estate(Origin::signed(BENEFICIARY_ID), 0, vec![(-10, 12), (-10, 12)]),
+			Error::<Runtime>::InvalidEstateSplit
+		);
+		assert_noop!(
+			EstateModule::split_estate(Origin::signed(BENEFICIARY_ID), 0, vec![COORDINATE_IN_2]),
+			Error::<Runtime>::EstateIsNotContiguous
+		);
+	});
+}
+
+#[test]
+fn offer_lease_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			COORDINATE_IN_1,
+			false
+		));
+		let asset = LeaseAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_1);
+
+		assert_noop!(
+			EstateModule::offer_lease(Origin::signed(ALICE), asset, 100, false, 2),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::offer_lease(Origin::signed(BENEFICIARY_ID), asset, 100, false, 0),
+			Error::<Runtime>::InvalidLeaseDuration
+		);
+		assert_noop!(
+			EstateModule::offer_lease(Origin::signed(BENEFICIARY_ID), asset, 100, true, 2),
+			Error::<Runtime>::NoMetaverseSocialToken
+		);
+
+		assert_ok!(EstateModule::offer_lease(
+			Origin::signed(BENEFICIARY_ID),
+			asset,
+			100,
+			false,
+			2
+		));
+		assert_noop!(
+			EstateModule::offer_lease(Origin::signed(BENEFICIARY_ID), asset, 200, false, 2),
+			Error::<Runtime>::AssetIsLeased
+		);
+		assert_noop!(
+			EstateModule::transfer_land(Origin::signed(BENEFICIARY_ID), ALICE, METAVERSE_ID, COORDINATE_IN_1),
+			Error::<Runtime>::AssetIsLeased
+		);
+		assert_noop!(
+			EstateModule::accept_lease(Origin::signed(BENEFICIARY_ID), asset),
+			Error::<Runtime>::CannotLeaseOwnAsset
+		);
+		assert_noop!(
+			EstateModule::cancel_lease_offer(Origin::signed(ALICE), asset),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(EstateModule::cancel_lease_offer(Origin::signed(BENEFICIARY_ID), asset));
+		assert_eq!(last_event(), Event::Estate(crate::Event::LeaseOfferCancelled(asset)));
+		assert_eq!(EstateModule::get_lease_offer(asset), None);
+		assert_noop!(
+			EstateModule::accept_lease(Origin::signed(ALICE), asset),
+			Error::<Runtime>::LeaseOfferNotFound
+		);
+		assert_ok!(EstateModule::transfer_land(
+			Origin::signed(BENEFICIARY_ID),
+			ALICE,
+			METAVERSE_ID,
+			COORDINATE_IN_1
+		));
+	});
+}
+
+#[test]
+fn leased_land_unit_should_not_be_transferred_by_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			COORDINATE_IN_AUCTION,
+			false
+		));
+		let asset = LeaseAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_AUCTION);
+		LeaseOffers::<Runtime>::insert(
+			asset,
+			LeaseOffer {
+				lessor: BENEFICIARY_ID,
+				currency_id: FungibleTokenId::NativeToken(0),
+				rent_per_round: 100,
+				duration: 2,
+			},
+		);
+
+		assert!(<EstateModule as Estate<AccountId>>::is_landunit_leased(
+			METAVERSE_ID,
+			COORDINATE_IN_AUCTION
+		));
+		assert_noop!(
+			<EstateModule as Estate<AccountId>>::transfer_landunit(
+				COORDINATE_IN_AUCTION,
+				&BENEFICIARY_ID,
+				&(ALICE, METAVERSE_ID)
+			),
+			Error::<Runtime>::AssetIsLeased
+		);
+
+		LeaseOffers::<Runtime>::remove(asset);
+		assert_ok!(<EstateModule as Estate<AccountId>>::transfer_landunit(
+			COORDINATE_IN_AUCTION,
+			&BENEFICIARY_ID,
+			&(ALICE, METAVERSE_ID)
+		));
+		assert_eq!(
+			EstateModule::get_land_units(METAVERSE_ID, COORDINATE_IN_AUCTION),
+			Some(OwnerId::Account(ALICE))
+		);
+	});
+}
+
+#[test]
+fn lease_land_unit_should_collect_rent_each_round() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			COORDINATE_IN_1,
+			false
+		));
+		let asset = LeaseAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_1);
+
+		assert_ok!(EstateModule::offer_lease(
+			Origin::signed(BENEFICIARY_ID),
+			asset,
+			100,
+			false,
+			2
+		));
+		assert_ok!(EstateModule::accept_lease(Origin::signed(ALICE), asset));
+		assert_eq!(last_event(), Event::Estate(crate::Event::LeaseStarted(asset, ALICE, 3)));
+		assert_eq!(Balances::free_balance(ALICE), 99900);
+		assert_eq!(EstateModule::get_lease_offer(asset), None);
+		assert_eq!(
+			EstateModule::get_land_unit_tenant(&METAVERSE_ID, &COORDINATE_IN_1),
+			Some(ALICE)
+		);
+		assert_eq!(
+			EstateModule::get_user_leased_land_units(&ALICE, &METAVERSE_ID),
+			vec![COORDINATE_IN_1]
+		);
+		// Owner keeps the land unit
+		assert_eq!(
+			EstateModule::get_land_units(METAVERSE_ID, COORDINATE_IN_1),
+			Some(OWNER_ACCOUNT_ID)
+		);
+
+		EstateModule::on_initialize(21);
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::RentCollected(asset, ALICE, 100))
+		);
+		assert_eq!(Balances::free_balance(ALICE), 99800);
+		assert_eq!(Balances::free_balance(BENEFICIARY_ID), 1000200);
+
+		EstateModule::on_initialize(41);
+		assert_eq!(last_event(), Event::Estate(crate::Event::LeaseEnded(asset, ALICE)));
+		assert_eq!(EstateModule::get_lease(asset), None);
+		assert_eq!(
+			EstateModule::get_land_unit_tenant(&METAVERSE_ID, &COORDINATE_IN_1),
+			None
+		);
+		assert_eq!(Balances::free_balance(ALICE), 99800);
+	});
+}
+
+#[test]
+fn lease_should_evict_tenant_after_missed_payments() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(
+			Origin::root(),
+			BOB_METAVERSE_ID,
+			MAX_BOUND
+		));
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BOB,
+			BOB_METAVERSE_ID,
+			COORDINATE_IN_1,
+			false
+		));
+		let asset = LeaseAsset::LandUnit(BOB_METAVERSE_ID, COORDINATE_IN_1);
+
+		assert_ok!(EstateModule::offer_lease(Origin::signed(BOB), asset, 600, true, 10));
+		assert_eq!(
+			EstateModule::get_lease_offer(asset).unwrap().currency_id,
+			BOB_SOCIAL_TOKEN
+		);
+		assert_ok!(EstateModule::accept_lease(Origin::signed(ALICE), asset));
+		assert_eq!(Tokens::free_balance(BOB_SOCIAL_TOKEN, &ALICE), 400);
+		assert_eq!(Tokens::free_balance(BOB_SOCIAL_TOKEN, &BOB), 600);
+
+		EstateModule::on_initialize(21);
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::RentPaymentMissed(asset, ALICE, 1))
+		);
+		assert_eq!(EstateModule::get_lease(asset).unwrap().missed_payments, 1);
+
+		EstateModule::on_initialize(41);
+		assert_eq!(last_event(), Event::Estate(crate::Event::TenantEvicted(asset, ALICE)));
+		assert_eq!(EstateModule::get_lease(asset), None);
+		assert_eq!(Tokens::free_balance(BOB_SOCIAL_TOKEN, &ALICE), 400);
+	});
+}
+
+#[test]
+fn lease_estate_should_expose_tenant_of_land_units() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+		let asset = LeaseAsset::Estate(ESTATE_ID);
+
+		assert_ok!(EstateModule::offer_lease(
+			Origin::signed(BENEFICIARY_ID),
+			asset,
+			100,
+			false,
+			5
+		));
+		assert_ok!(EstateModule::accept_lease(Origin::signed(ALICE), asset));
+
+		assert_eq!(
+			EstateModule::get_land_unit_tenant(&METAVERSE_ID, &COORDINATE_IN_2),
+			Some(ALICE)
+		);
+		assert_eq!(
+			EstateModule::get_user_leased_land_units(&ALICE, &METAVERSE_ID),
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		);
+		assert_eq!(
+			EstateModule::get_user_leased_land_units(&ALICE, &BOB_METAVERSE_ID),
+			vec![]
+		);
+		assert_noop!(
+			EstateModule::transfer_estate(Origin::signed(BENEFICIARY_ID), BOB, ESTATE_ID),
+			Error::<Runtime>::AssetIsLeased
+		);
+		assert_noop!(
+			EstateModule::end_lease(Origin::signed(BENEFICIARY_ID), asset),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(EstateModule::end_lease(Origin::signed(ALICE), asset));
+		assert_eq!(last_event(), Event::Estate(crate::Event::LeaseEnded(asset, ALICE)));
+		assert_eq!(
+			EstateModule::get_land_unit_tenant(&METAVERSE_ID, &COORDINATE_IN_2),
+			None
+		);
+		assert_ok!(EstateModule::transfer_estate(
+			Origin::signed(BENEFICIARY_ID),
+			BOB,
+			ESTATE_ID
+		));
+	});
+}
+
+#[test]
+fn lease_should_end_when_tokenized_asset_changes_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			COORDINATE_IN_1,
+			true
+		));
+		let asset = LeaseAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_1);
+
+		assert_ok!(EstateModule::offer_lease(
+			Origin::signed(BENEFICIARY_ID),
+			asset,
+			100,
+			false,
+			5
+		));
+		assert_ok!(EstateModule::accept_lease(Origin::signed(ALICE), asset));
+		assert_eq!(Balances::free_balance(ALICE), 99900);
+
+		// Land token is sold through the nft pallet
+		NFT_OWNERS.with(|owners| owners.borrow_mut().insert((LAND_CLASS_ID, ASSET_ID_1), BOB));
+
+		EstateModule::on_initialize(21);
+		assert_eq!(last_event(), Event::Estate(crate::Event::LeaseEnded(asset, ALICE)));
+		assert_eq!(EstateModule::get_lease(asset), None);
+		assert_eq!(
+			EstateModule::get_land_unit_tenant(&METAVERSE_ID, &COORDINATE_IN_1),
+			None
+		);
+		assert_eq!(EstateModule::all_leases_count(), 0);
+		assert_eq!(Balances::free_balance(ALICE), 99900);
+		assert_eq!(Balances::free_balance(BENEFICIARY_ID), 1000100);
+	});
+}
+
+#[test]
+fn lease_rent_should_be_collected_over_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		let coordinates = vec![COORDINATE_IN_1, COORDINATE_IN_2, (-10, 12)];
+		for coordinate in coordinates.clone() {
+			assert_ok!(EstateModule::mint_land(
+				Origin::root(),
+				BENEFICIARY_ID,
+				METAVERSE_ID,
+				coordinate,
+				false
+			));
+			assert_ok!(EstateModule::offer_lease(
+				Origin::signed(BENEFICIARY_ID),
+				LeaseAsset::LandUnit(METAVERSE_ID, coordinate),
+				100,
+				false,
+				5
+			));
+		}
+
+		assert_ok!(EstateModule::accept_lease(
+			Origin::signed(ALICE),
+			LeaseAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_1)
+		));
+		assert_ok!(EstateModule::accept_lease(
+			Origin::signed(ALICE),
+			LeaseAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_2)
+		));
+		assert_noop!(
+			EstateModule::accept_lease(Origin::signed(ALICE), LeaseAsset::LandUnit(METAVERSE_ID, (-10, 12))),
+			Error::<Runtime>::ExceedMaxLeases
+		);
+		assert_eq!(EstateModule::all_leases_count(), 2);
+		let mut leased_land_units = EstateModule::get_user_leased_land_units(&ALICE, &METAVERSE_ID);
+		leased_land_units.sort();
+		assert_eq!(leased_land_units, vec![COORDINATE_IN_1, COORDINATE_IN_2]);
+		assert_eq!(Balances::free_balance(ALICE), 99800);
+
+		// One lease is collected per block
+		EstateModule::on_initialize(21);
+		assert_eq!(Balances::free_balance(ALICE), 99700);
+		assert!(EstateModule::rent_collection_cursor().is_some());
+		EstateModule::on_initialize(22);
+		assert_eq!(Balances::free_balance(ALICE), 99600);
+
+		assert_ok!(EstateModule::end_lease(
+			Origin::signed(ALICE),
+			LeaseAsset::LandUnit(METAVERSE_ID, COORDINATE_IN_1)
+		));
+		assert_eq!(EstateModule::all_leases_count(), 1);
+		assert_eq!(
+			EstateModule::get_user_leased_land_units(&ALICE, &METAVERSE_ID),
+			vec![COORDINATE_IN_2]
+		);
+		assert_ok!(EstateModule::accept_lease(
+			Origin::signed(ALICE),
+			LeaseAsset::LandUnit(METAVERSE_ID, (-10, 12))
+		));
+		assert_eq!(Balances::free_balance(ALICE), 99500);
+
+		// Rent of the new lease was paid upfront for the round
+		EstateModule::on_initialize(23);
+		EstateModule::on_initialize(24);
+		assert_eq!(Balances::free_balance(ALICE), 99500);
+		assert_eq!(EstateModule::rent_collection_cursor(), None);
+
+		EstateModule::on_initialize(31);
+		assert_eq!(Balances::free_balance(ALICE), 99400);
+		EstateModule::on_initialize(32);
+		assert_eq!(Balances::free_balance(ALICE), 99300);
+	});
+}
+
+// One round per year, the round minting rate equals the annual minting rate
+const BLOCKS_PER_YEAR_ROUND: u32 = 2_629_800;
+
+fn setup_staking_issuance(annual: u64) {
+	Round::<Runtime>::put(RoundInfo::new(1, 1, BLOCKS_PER_YEAR_ROUND));
+	MintingRateConfig::<Runtime>::put(MintingRateInfo {
+		expect: Default::default(),
+		annual,
+		max: 100_000,
+	});
+	StakingExpectation::<Runtime>::put(Range {
+		min: 500,
+		ideal: 1000,
+		max: 2000,
+	});
+}
+
+fn run_to_round(round: RoundIndex) {
+	let block = 1 + (round as u64 - 1) * BLOCKS_PER_YEAR_ROUND as u64;
+	EstateModule::on_initialize(block);
+	assert_eq!(EstateModule::round().current, round);
+}
+
+#[test]
+fn set_estate_commission_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			false
+		));
+
+		assert_noop!(
+			EstateModule::set_estate_commission(Origin::signed(ALICE), ESTATE_ID, Perbill::from_percent(10)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::set_estate_commission(Origin::signed(BENEFICIARY_ID), 1, Perbill::from_percent(10)),
+			Error::<Runtime>::EstateDoesNotExist
+		);
+		assert_noop!(
+			EstateModule::set_estate_commission(Origin::signed(BENEFICIARY_ID), ESTATE_ID, Perbill::from_percent(60)),
+			Error::<Runtime>::ExceedMaxEstateCommission
+		);
+
+		assert_ok!(EstateModule::set_estate_commission(
+			Origin::signed(BENEFICIARY_ID),
+			ESTATE_ID,
+			Perbill::from_percent(10)
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateCommissionSet(ESTATE_ID, Perbill::from_percent(10)))
+		);
+		assert_eq!(EstateModule::estate_commission(ESTATE_ID), Perbill::from_percent(10));
+	});
+}
+
+#[test]
+fn staking_rewards_should_pay_commission_to_tokenized_estate_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_staking_issuance(10);
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			true
+		));
+		assert_ok!(EstateModule::bond_more(
+			Origin::signed(BENEFICIARY_ID),
+			ESTATE_ID,
+			BOND_AMOUNT_1
+		));
+		assert_ok!(EstateModule::set_estate_commission(
+			Origin::signed(BENEFICIARY_ID),
+			ESTATE_ID,
+			Perbill::from_percent(10)
+		));
+
+		// The estate token is rented to ALICE in the mock, commission stays with the owner
+		run_to_round(2);
+		run_to_round(3);
+		run_to_round(4);
+		assert_eq!(EstateModule::staking_rewards(ALICE, 2), 0);
+		assert_eq!(EstateModule::staking_rewards(BENEFICIARY_ID, 2), 100);
+	});
+}
+
+#[test]
+fn staking_rewards_should_pay_commission_and_stakers() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_staking_issuance(10);
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			false
+		));
+		assert_ok!(EstateModule::bond_more(
+			Origin::signed(BENEFICIARY_ID),
+			ESTATE_ID,
+			BOND_AMOUNT_1
+		));
+		// Estate owner changes, stake stays with the staker
+		assert_ok!(EstateModule::transfer_estate(
+			Origin::signed(BENEFICIARY_ID),
+			ALICE,
+			ESTATE_ID
+		));
+		assert_ok!(EstateModule::set_estate_commission(
+			Origin::signed(ALICE),
+			ESTATE_ID,
+			Perbill::from_percent(10)
+		));
+
+		run_to_round(2);
+		assert_eq!(EstateModule::staked(2), BOND_AMOUNT_1);
+		run_to_round(3);
+		assert_eq!(EstateModule::staking_rewards(ALICE, 1), 0);
+
+		// Round 2 is paid out after the reward payment delay, 10% of 1000 staked
+		run_to_round(4);
+		assert_eq!(EstateModule::staking_rewards(ALICE, 2), 10);
+		assert_eq!(EstateModule::staking_rewards(BENEFICIARY_ID, 2), 90);
+		assert_eq!(EstateModule::staked(2), 0);
+
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(EstateModule::claim_rewards(Origin::signed(ALICE), 2));
+		assert_eq!(last_event(), Event::Estate(crate::Event::StakingRewarded(ALICE, 10)));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 10);
+
+		let beneficiary_balance = Balances::free_balance(BENEFICIARY_ID);
+		assert_ok!(EstateModule::claim_rewards(Origin::signed(BENEFICIARY_ID), 2));
+		assert_eq!(Balances::free_balance(BENEFICIARY_ID), beneficiary_balance + 90);
+
+		assert_noop!(
+			EstateModule::claim_rewards(Origin::signed(BENEFICIARY_ID), 2),
+			Error::<Runtime>::NoStakingRewards
+		);
+		assert_noop!(
+			EstateModule::claim_rewards(Origin::signed(BOB), 2),
+			Error::<Runtime>::NoStakingRewards
+		);
+	});
+}
+
+#[test]
+fn staking_rewards_should_stay_within_expectations() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_staking_issuance(10);
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			false
+		));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_2],
+			false
+		));
+		assert_ok!(EstateModule::bond_more(
+			Origin::signed(BENEFICIARY_ID),
+			0,
+			BOND_AMOUNT_1
+		));
+		assert_ok!(EstateModule::bond_more(
+			Origin::signed(BENEFICIARY_ID),
+			1,
+			BOND_AMOUNT_2
+		));
+
+		// 3000 staked is above the expected maximum of 2000, issuance is capped to 200
+		run_to_round(2);
+		run_to_round(3);
+		run_to_round(4);
+		assert_eq!(EstateModule::staking_rewards(BENEFICIARY_ID, 2), 200);
+
+		// Leaving stakers are not part of the next snapshots
+		assert_ok!(EstateModule::leave_staking(Origin::signed(BENEFICIARY_ID), 1));
+		run_to_round(5);
+		assert_eq!(EstateModule::estate_stake(1, BENEFICIARY_ID), 0);
+		assert_eq!(EstateModule::staked(5), BOND_AMOUNT_1);
+		run_to_round(6);
+		run_to_round(7);
+		assert_eq!(EstateModule::staking_rewards(BENEFICIARY_ID, 5), 100);
+	});
+}
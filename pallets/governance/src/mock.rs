@@ -140,6 +140,10 @@ impl MetaverseTrait<AccountId> for MetaverseInfo {
 	fn update_metaverse_token(_metaverse_id: u64, _currency_id: FungibleTokenId) -> Result<(), DispatchError> {
 		Ok(())
 	}
+
+	fn get_land_connectivity(_metaverse_id: u64) -> primitives::estate::LandConnectivity {
+		primitives::estate::LandConnectivity::FourConnected
+	}
 }
 
 pub struct MetaverseLandInfo {}
@@ -156,6 +160,14 @@ impl MetaverseLandTrait<AccountId> for MetaverseLandInfo {
 			_ => false,
 		}
 	}
+
+	fn get_user_leased_land_units(_who: &u64, _metaverse_id: &u64) -> Vec<(i32, i32)> {
+		Vec::default()
+	}
+
+	fn get_land_unit_tenant(_metaverse_id: &u64, _coordinate: &(i32, i32)) -> Option<AccountId> {
+		None
+	}
 }
 
 parameter_types! {
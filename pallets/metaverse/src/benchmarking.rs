@@ -203,6 +203,16 @@ benchmarks! {
 		let staking_info = crate::Pallet::<T>::staking_info(caller);
 		assert_eq!(staking_info, amount.into());
 	}
+
+	// set_land_connectivity
+	set_land_connectivity{
+		let caller = funded_account::<T>("caller", 0);
+
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), caller.clone(), vec![1]);
+	}: _(RawOrigin::Signed(caller.clone()), 0, LandConnectivity::EightConnected)
+	verify {
+		assert_eq!(crate::Pallet::<T>::get_land_connectivity(0), LandConnectivity::EightConnected);
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::benchmarking::tests::new_test_ext(), crate::mock::Test);
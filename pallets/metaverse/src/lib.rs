@@ -37,6 +37,7 @@ use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 use core_primitives::*;
 use core_primitives::{MetaverseInfo, MetaverseTrait};
 pub use pallet::*;
+use primitives::estate::LandConnectivity;
 use primitives::staking::MetaverseStakingTrait;
 use primitives::{FungibleTokenId, MetaverseId, RoundIndex};
 pub use weights::WeightInfo;
@@ -181,6 +182,12 @@ pub mod pallet {
 	#[pallet::getter(fn staking_info)]
 	pub(crate) type StakingInfo<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	/// Rule deciding which land units of an estate count as connected in each metaverse
+	#[pallet::storage]
+	#[pallet::getter(fn get_land_connectivity)]
+	pub type MetaverseLandConnectivity<T: Config> =
+		StorageMap<_, Twox64Concat, MetaverseId, LandConnectivity, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -194,6 +201,7 @@ pub mod pallet {
 		MetaverseStaked(T::AccountId, MetaverseId, BalanceOf<T>),
 		MetaverseUnstaked(T::AccountId, MetaverseId, BalanceOf<T>),
 		MetaverseStakingRewarded(T::AccountId, MetaverseId, RoundIndex, BalanceOf<T>),
+		LandConnectivityUpdated(MetaverseId, LandConnectivity),
 	}
 
 	#[pallet::error]
@@ -349,10 +357,31 @@ pub mod pallet {
 
 			MetaverseOwner::<T>::remove(metaverse_info.owner, &metaverse_id);
 			Metaverses::<T>::remove(&metaverse_id);
+			MetaverseLandConnectivity::<T>::remove(&metaverse_id);
 			Self::deposit_event(Event::<T>::MetaverseDestroyed(metaverse_id));
 			Ok(().into())
 		}
 
+		/// Set which land units count as connected when validating estates of the metaverse
+		/// only metaverse owner can update land connectivity. Existing estates keep the
+		/// connectivity they were created with, the change applies to new estates.
+		#[pallet::weight(T::WeightInfo::set_land_connectivity())]
+		pub fn set_land_connectivity(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			connectivity: LandConnectivity,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let metaverse_info = Self::get_metaverse(metaverse_id).ok_or(Error::<T>::MetaverseInfoNotFound)?;
+			ensure!(metaverse_info.owner == who, Error::<T>::NoPermission);
+
+			MetaverseLandConnectivity::<T>::insert(metaverse_id, connectivity);
+			Self::deposit_event(Event::<T>::LandConnectivityUpdated(metaverse_id, connectivity));
+
+			Ok(().into())
+		}
+
 		/// Register metaverse for staking
 		/// only metaverse owner can register for staking
 		#[pallet::weight(T::WeightInfo::register_metaverse())]
@@ -672,6 +701,10 @@ impl<T: Config> MetaverseTrait<T::AccountId> for Pallet<T> {
 			Ok(())
 		})
 	}
+
+	fn get_land_connectivity(metaverse_id: MetaverseId) -> LandConnectivity {
+		Self::get_land_connectivity(metaverse_id)
+	}
 }
 
 impl<T: Config> MetaverseStakingTrait<BalanceOf<T>> for Pallet<T> {
@@ -145,6 +145,43 @@ fn destroy_metaverse_with_no_id_should_fail() {
 	})
 }
 
+#[test]
+fn set_land_connectivity_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_eq!(
+			<MetaverseModule as MetaverseTrait<AccountId>>::get_land_connectivity(METAVERSE_ID),
+			LandConnectivity::FourConnected
+		);
+
+		assert_ok!(MetaverseModule::set_land_connectivity(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			LandConnectivity::EightConnected
+		));
+		assert_eq!(
+			<MetaverseModule as MetaverseTrait<AccountId>>::get_land_connectivity(METAVERSE_ID),
+			LandConnectivity::EightConnected
+		);
+		let event = Event::Metaverse(crate::Event::LandConnectivityUpdated(
+			METAVERSE_ID,
+			LandConnectivity::EightConnected,
+		));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn set_land_connectivity_should_fail_no_permission() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_noop!(
+			MetaverseModule::set_land_connectivity(Origin::signed(BOB), METAVERSE_ID, LandConnectivity::EightConnected),
+			Error::<Runtime>::NoPermission
+		);
+	});
+}
+
 #[test]
 fn register_metaverse_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
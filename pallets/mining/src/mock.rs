@@ -146,6 +146,18 @@ impl Estate<u128> for EstateHandler {
 		Ok(coordinate)
 	}
 
+	fn transfer_estate_by_offer(estate_id: EstateId, from: &u128, to: &u128) -> Result<EstateId, DispatchError> {
+		Ok(estate_id)
+	}
+
+	fn transfer_landunit_by_offer(
+		coordinate: (i32, i32),
+		from: &u128,
+		to: &(u128, primitives::MetaverseId),
+	) -> Result<(i32, i32), DispatchError> {
+		Ok(coordinate)
+	}
+
 	fn check_estate(_estate_id: EstateId) -> Result<bool, DispatchError> {
 		Ok(true)
 	}
@@ -154,6 +166,18 @@ impl Estate<u128> for EstateHandler {
 		Ok(true)
 	}
 
+	fn check_estate_ownership(_owner: &u128, _estate_id: EstateId) -> Result<bool, DispatchError> {
+		Ok(true)
+	}
+
+	fn check_landunit_ownership(
+		_owner: &u128,
+		_metaverse_id: primitives::MetaverseId,
+		_coordinate: (i32, i32),
+	) -> Result<bool, DispatchError> {
+		Ok(true)
+	}
+
 	fn get_total_land_units() -> u64 {
 		10
 	}
@@ -161,6 +185,14 @@ impl Estate<u128> for EstateHandler {
 	fn get_total_undeploy_land_units() -> u64 {
 		10
 	}
+
+	fn is_estate_leased(_estate_id: EstateId) -> bool {
+		false
+	}
+
+	fn is_landunit_leased(_metaverse_id: primitives::MetaverseId, _coordinate: (i32, i32)) -> bool {
+		false
+	}
 }
 
 pub struct MetaverseStakingHandler;
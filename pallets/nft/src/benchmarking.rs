@@ -20,10 +20,11 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
+use codec::Encode;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_support::traits::Get;
 use frame_system::RawOrigin;
-use orml_traits::BasicCurrencyExtended;
+use orml_traits::{BasicCurrencyExtended, MultiCurrency};
 use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto};
 use sp_std::prelude::*;
 use sp_std::vec;
@@ -115,6 +116,238 @@ benchmarks! {
 		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
 	}: _(RawOrigin::Signed(signer), 0u32.into(), 100u32.into() )
 
+	burn{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()))
+
+	destroy_class{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+	}: _(RawOrigin::Signed(caller), 0u32.into())
+
+	set_royalty_recipient{
+		let caller = funded_account::<T>("caller", 0);
+		let recipient = funded_account::<T>("recipient", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+	}: _(RawOrigin::Signed(caller), 0u32.into(), recipient)
+
+	withdraw_class_fund{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		let class_fund: T::AccountId = crate::Pallet::<T>::get_class_fund(&0u32.into());
+		<T as pallet::Config>::Currency::deposit_creating(&class_fund, dollar(1).unique_saturated_into());
+	}: _(RawOrigin::Signed(caller), 0u32.into())
+
+	approve{
+		let caller = funded_account::<T>("caller", 0);
+		let spender = funded_account::<T>("spender", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()), Some(spender))
+
+	set_approval_for_all{
+		let caller = funded_account::<T>("caller", 0);
+		let operator = funded_account::<T>("operator", 0);
+	}: _(RawOrigin::Signed(caller), operator, true)
+
+	list_for_rent{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()), dollar(1).unique_saturated_into(), 100u32.into())
+
+	cancel_rent_listing{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+		crate::Pallet::<T>::list_for_rent(RawOrigin::Signed(caller.clone()).into(), (0u32.into(), 0u32.into()), dollar(1).unique_saturated_into(), 100u32.into());
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()))
+
+	rent{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+		let renter = funded_account::<T>("renter", 0);
+		<T as pallet::Config>::Currency::make_free_balance_be(&renter, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::list_for_rent(RawOrigin::Signed(caller.clone()).into(), (0u32.into(), 0u32.into()), dollar(1).unique_saturated_into(), 100u32.into());
+	}: _(RawOrigin::Signed(renter), (0u32.into(), 0u32.into()), 10u32.into(), dollar(10).unique_saturated_into())
+
+	withdraw_class_fund_token{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		let class_fund: T::AccountId = crate::Pallet::<T>::get_class_fund(&0u32.into());
+		T::MultiCurrency::deposit(T::MiningResourceId::get(), &class_fund, dollar(1).unique_saturated_into());
+	}: _(RawOrigin::Signed(caller), 0u32.into(), T::MiningResourceId::get())
+
+	set_mint_settings{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		let settings = MintSettings {
+			max_supply: Some(100u32.into()),
+			start_block: None,
+			end_block: None,
+			currency_id: T::MiningResourceId::get(),
+			price: 0u32.into(),
+			per_account_limit: Some(10),
+			allowlist_only: true,
+		};
+	}: _(RawOrigin::Signed(caller), 0u32.into(), Some(settings))
+
+	set_mint_allowlist{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		let accounts: Vec<T::AccountId> = (0..10).map(|i| account("minter", i, SEED)).collect();
+	}: _(RawOrigin::Signed(caller), 0u32.into(), accounts, true)
+
+	mint_from_class{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		let settings = MintSettings {
+			max_supply: Some(100u32.into()),
+			start_block: None,
+			end_block: None,
+			currency_id: T::MiningResourceId::get(),
+			price: 0u32.into(),
+			per_account_limit: Some(10),
+			allowlist_only: true,
+		};
+		let minter = funded_account::<T>("minter", 0);
+		<T as pallet::Config>::Currency::make_free_balance_be(&minter, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::set_mint_settings(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), Some(settings));
+		crate::Pallet::<T>::set_mint_allowlist(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![minter.clone()], true);
+	}: _(RawOrigin::Signed(minter), 0u32.into(), 3)
+
+	use_nft{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		let executable = ExecutableNftData {
+			call: <T as pallet::Config>::Call::from(frame_system::Call::<T>::remark_with_event { remark: vec![1] }),
+			burn_on_use: true,
+			cooldown: 0u32.into(),
+		};
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Executable(executable.encode()), Perbill::from_percent(0u32));
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()))
+
+	set_attribute_update_authority{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+	}: _(RawOrigin::Signed(caller), 0u32.into(), AttributeUpdateAuthority::TokenOwner)
+
+	set_token_attribute{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()), vec![9], vec![9, 9])
+
+	remove_token_attribute{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()), vec![1, 6])
+
+	set_wearable_slot{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Wearable, Perbill::from_percent(0u32));
+	}: _(RawOrigin::Signed(caller), 1u32.into(), vec![1])
+
+	equip{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Wearable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 1u32.into(), vec![1], test_attributes(1), 1);
+		crate::Pallet::<T>::set_wearable_slot(RawOrigin::Signed(caller.clone()).into(), 1u32.into(), vec![1]);
+	}: _(RawOrigin::Signed(caller), (1u32.into(), 0u32.into()), (0u32.into(), 0u32.into()))
+
+	unequip{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Wearable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 1u32.into(), vec![1], test_attributes(1), 1);
+		crate::Pallet::<T>::set_wearable_slot(RawOrigin::Signed(caller.clone()).into(), 1u32.into(), vec![1]);
+		crate::Pallet::<T>::equip(RawOrigin::Signed(caller.clone()).into(), (1u32.into(), 0u32.into()), (0u32.into(), 0u32.into()));
+	}: _(RawOrigin::Signed(caller), (1u32.into(), 0u32.into()))
+
 }
 
 impl_benchmark_test_suite!(Pallet, crate::benchmarking::tests::new_test_ext(), crate::mock::Test);
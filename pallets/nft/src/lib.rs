@@ -34,18 +34,20 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		schedule::{DispatchTime, Named as ScheduleNamed},
-		Currency, ExistenceRequirement, Get, LockIdentifier, ReservableCurrency,
+		Contains, Currency, ExistenceRequirement, Get, LockIdentifier, ReservableCurrency,
 	},
+	transactional,
+	weights::{GetDispatchInfo, PostDispatchInfo},
 	PalletId,
 };
 use frame_system::pallet_prelude::*;
-use orml_nft::{ClassInfo, ClassInfoOf, Classes, Pallet as NftModule};
+use orml_nft::{ClassInfo, ClassInfoOf, Classes, Pallet as NftModule, TokenInfoOf};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::traits::Saturating;
 use sp_runtime::{
-	traits::{AccountIdConversion, Dispatchable, One},
+	traits::{AccountIdConversion, Dispatchable, One, UniqueSaturatedInto, Zero},
 	DispatchError,
 };
 use sp_runtime::{Perbill, RuntimeDebug};
@@ -55,9 +57,13 @@ use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 use auction_manager::{Auction, CheckAuctionItemHandler};
 pub use pallet::*;
 use primitive_traits::NftAssetData;
-pub use primitive_traits::{Attributes, NFTTrait, NftClassData, NftGroupCollectionData, NftMetadata, TokenType};
+pub use primitive_traits::{
+	AttributeUpdateAuthority, Attributes, CollectionType, NFTTrait, NftClassData, NftGroupCollectionData, NftMetadata,
+	TokenType,
+};
 use primitives::{
-	AssetId, BlockNumber, ClassId, GroupCollectionId, Hash, ItemId, TokenId, ESTATE_CLASS_ID, LAND_CLASS_ID,
+	AssetId, BlockNumber, ClassId, FungibleTokenId, GroupCollectionId, Hash, ItemId, TokenId, ESTATE_CLASS_ID,
+	LAND_CLASS_ID,
 };
 pub use weights::WeightInfo;
 
@@ -78,6 +84,69 @@ pub enum StorageVersion {
 	V1,
 }
 
+/// Payload of an executable NFT class, stored encoded in `CollectionType::Executable`
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct ExecutableNftData<Call, BlockNumber> {
+	/// Call dispatched on behalf of the holder when the NFT is used
+	pub call: Call,
+	/// Burn the NFT when it is used
+	pub burn_on_use: bool,
+	/// Blocks before the same NFT can be used again
+	pub cooldown: BlockNumber,
+}
+
+/// Mint settings of a class, the class is open to public minting once they are set
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct MintSettings<TokenId, Balance, BlockNumber> {
+	/// Max number of tokens ever minted from the class, enforced on every mint of the class. It
+	/// can only be lowered once set.
+	pub max_supply: Option<TokenId>,
+	/// First block of the public mint
+	pub start_block: Option<BlockNumber>,
+	/// Block the public mint closes at
+	pub end_block: Option<BlockNumber>,
+	/// Currency of the mint price
+	pub currency_id: FungibleTokenId,
+	/// Price of each token minted by the public, paid into the class fund
+	pub price: Balance,
+	/// Max number of tokens an account can mint
+	pub per_account_limit: Option<u32>,
+	/// Only accounts on the mint allowlist can mint
+	pub allowlist_only: bool,
+}
+
+/// Terms of a token listed for rent by its owner
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct RentalListing<Balance, BlockNumber> {
+	/// Price the renter pays upfront for each block of the rental
+	pub price_per_block: Balance,
+	/// Longest rental in blocks
+	pub max_duration: BlockNumber,
+}
+
+/// Rental of a token, the renter is the user of the token until the rental expires
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct RentalInfo<AccountId, BlockNumber> {
+	pub renter: AccountId,
+	pub expires_at: BlockNumber,
+}
+
+/// Class data layout before the royalty fee and royalty recipient were added
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct OldNftClassData<Balance> {
+	pub deposit: Balance,
+	pub attributes: Attributes,
+	pub token_type: TokenType,
+	pub collection_type: CollectionType,
+}
+
+pub type OldClassInfoOf<T> = ClassInfo<
+	TokenIdOf<T>,
+	<T as frame_system::Config>::AccountId,
+	OldNftClassData<BalanceOf<T>>,
+	orml_nft::ClassMetadataOf<T>,
+>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use orml_traits::{MultiCurrency, MultiCurrencyExtended};
@@ -87,14 +156,22 @@ pub mod pallet {
 
 	use super::*;
 
+	/// Class data gained the royalty fee and royalty recipient and token deposits are stored per
+	/// token in version 1
+	const STORAGE_VERSION: frame_support::traits::StorageVersion = frame_support::traits::StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config
-		+ orml_nft::Config<TokenData = NftAssetData<BalanceOf<Self>>, ClassData = NftClassData<BalanceOf<Self>>>
+		+ orml_nft::Config<
+			TokenData = NftAssetData<BalanceOf<Self>>,
+			ClassData = NftClassData<BalanceOf<Self>, Self::AccountId>,
+		>
 	{
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		/// The data deposit per byte to calculate fee
@@ -127,6 +204,19 @@ pub mod pallet {
 		/// Fungible token id for promotion incentive
 		#[pallet::constant]
 		type MiningResourceId: Get<FungibleTokenId>;
+		/// Runtime call that executable NFTs dispatch when used
+		type Call: Parameter
+			+ Dispatchable<Origin = <Self as frame_system::Config>::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+		/// Calls that executable NFTs are allowed to dispatch. The call is authored by the class
+		/// owner but dispatched with the holder's signed origin, so only calls that can not move
+		/// or lock the holder's assets, grant permissions or wrap other calls (e.g. batches)
+		/// should be allowed.
+		type ExecutableCallFilter: Contains<<Self as Config>::Call>;
+		/// Max weight of the call dispatched by an executable NFT
+		#[pallet::constant]
+		type MaxExecutableCallWeight: Get<Weight>;
 	}
 
 	pub type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
@@ -173,6 +263,110 @@ pub mod pallet {
 	#[pallet::getter(fn get_locked_collection)]
 	pub(super) type LockedCollection<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, (), OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn get_attribute_update_authority)]
+	/// Who can update the token attributes of each class
+	pub(super) type AttributeUpdateAuthorities<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, AttributeUpdateAuthority, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_last_used)]
+	/// Block each executable NFT was last used at, for the cooldown
+	pub(super) type LastUsed<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_wearable_slot)]
+	/// Slot of an avatar that the wearables of a class are equipped on, e.g. head, body, hand
+	pub(super) type WearableSlots<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, Vec<u8>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_equipped_item)]
+	/// Wearable equipped on each slot of an avatar
+	pub(super) type EquippedItems<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		Blake2_128Concat,
+		Vec<u8>,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_equipped_to)]
+	/// Avatar and slot each equipped wearable is attached to
+	pub(super) type EquippedTo<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		((ClassIdOf<T>, TokenIdOf<T>), Vec<u8>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_approved)]
+	/// Account approved to transfer a token on behalf of its owner, cleared on transfer
+	pub(super) type Approvals<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_rental_listing)]
+	/// Tokens listed for rent by their owner
+	pub(super) type RentalListings<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		RentalListing<BalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_rental)]
+	/// Rentals of tokens, a rental is active until its expiry block
+	pub(super) type Rentals<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		RentalInfo<T::AccountId, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_mint_settings)]
+	/// Mint settings of classes open to public minting
+	pub(super) type ClassMintSettings<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ClassIdOf<T>,
+		MintSettings<TokenIdOf<T>, BalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_max_supply)]
+	/// Max supply of classes, kept when the public mint of the class is closed
+	pub(super) type ClassMaxSupply<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, TokenIdOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_mint_allowlist)]
+	/// Accounts allowed to mint from a class with an allowlist only mint
+	pub(super) type MintAllowlist<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClassIdOf<T>, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_minted_count)]
+	/// Number of tokens each account minted from a class
+	pub(super) type MintedCount<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClassIdOf<T>, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_operator_approval)]
+	/// Operators approved to transfer every token of an owner
+	pub(super) type OperatorApprovals<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -218,6 +412,18 @@ pub mod pallet {
 		PromotionEnabled(bool),
 		/// Burn NFT
 		BurnedNft((ClassIdOf<T>, TokenIdOf<T>)),
+		/// Deposit of a burned NFT is refunded to its owner
+		NftDepositRefunded(
+			(ClassIdOf<T>, TokenIdOf<T>),
+			<T as frame_system::Config>::AccountId,
+			BalanceOf<T>,
+		),
+		/// Empty NFT class is destroyed, its deposit and class fund paid out to the class owner
+		ClassDestroyed(ClassIdOf<T>, <T as frame_system::Config>::AccountId, BalanceOf<T>),
+		/// Royalty recipient of a class is updated
+		RoyaltyRecipientUpdated(ClassIdOf<T>, <T as frame_system::Config>::AccountId),
+		/// Class owner withdrew the free balance of the class fund
+		ClassFundWithdrawn(ClassIdOf<T>, <T as frame_system::Config>::AccountId, BalanceOf<T>),
 		/// Executed NFT
 		ExecutedNft(AssetId),
 		/// Scheduled time capsule
@@ -226,6 +432,62 @@ pub mod pallet {
 		CollectionLocked(ClassIdOf<T>),
 		/// Collection is unlocked
 		CollectionUnlocked(ClassIdOf<T>),
+		/// Wearable slot of a class is set
+		WearableSlotSet(ClassIdOf<T>, Vec<u8>),
+		/// Wearable is equipped on an avatar
+		NftEquipped((ClassIdOf<T>, TokenIdOf<T>), (ClassIdOf<T>, TokenIdOf<T>), Vec<u8>),
+		/// Wearable is unequipped from an avatar
+		NftUnequipped((ClassIdOf<T>, TokenIdOf<T>), (ClassIdOf<T>, TokenIdOf<T>)),
+		/// Executable NFT is used by its holder
+		NftUsed((ClassIdOf<T>, TokenIdOf<T>), <T as frame_system::Config>::AccountId),
+		/// Attribute update authority of a class is set
+		AttributeUpdateAuthoritySet(ClassIdOf<T>, AttributeUpdateAuthority),
+		/// Token attribute is set
+		TokenAttributeSet((ClassIdOf<T>, TokenIdOf<T>), Vec<u8>, Vec<u8>),
+		/// Token attribute is removed
+		TokenAttributeRemoved((ClassIdOf<T>, TokenIdOf<T>), Vec<u8>),
+		/// Approved account of a token is set or cleared
+		NftApproved(
+			(ClassIdOf<T>, TokenIdOf<T>),
+			<T as frame_system::Config>::AccountId,
+			Option<<T as frame_system::Config>::AccountId>,
+		),
+		/// Operator is approved or revoked for every token of an owner
+		ApprovalForAll(
+			<T as frame_system::Config>::AccountId,
+			<T as frame_system::Config>::AccountId,
+			bool,
+		),
+		/// Token is listed for rent with the price per block and max duration
+		NftListedForRent(
+			(ClassIdOf<T>, TokenIdOf<T>),
+			<T as frame_system::Config>::AccountId,
+			BalanceOf<T>,
+			T::BlockNumber,
+		),
+		/// Rent listing of a token is cancelled
+		RentListingCancelled((ClassIdOf<T>, TokenIdOf<T>)),
+		/// Token is rented until the expiry block for the total price
+		NftRented(
+			(ClassIdOf<T>, TokenIdOf<T>),
+			<T as frame_system::Config>::AccountId,
+			T::BlockNumber,
+			BalanceOf<T>,
+		),
+		/// Mint settings of a class are updated, `None` closes the public mint
+		MintSettingsUpdated(
+			ClassIdOf<T>,
+			Option<MintSettings<TokenIdOf<T>, BalanceOf<T>, T::BlockNumber>>,
+		),
+		/// Accounts are added to or removed from the mint allowlist of a class
+		MintAllowlistUpdated(ClassIdOf<T>, Vec<<T as frame_system::Config>::AccountId>, bool),
+		/// Class owner withdraws a fungible token from the class fund
+		ClassFundTokenWithdrawn(
+			ClassIdOf<T>,
+			<T as frame_system::Config>::AccountId,
+			FungibleTokenId,
+			BalanceOf<T>,
+		),
 	}
 
 	#[pallet::error]
@@ -282,6 +544,68 @@ pub mod pallet {
 		CollectionIsNotLocked,
 		/// NFT Royalty fee exceed 50%
 		RoyaltyFeeExceedLimit,
+		/// Only wearable collection
+		OnlyForWearableCollectionType,
+		/// Wearable slot is empty or too long
+		InvalidWearableSlot,
+		/// Wearable slot of the collection is not set
+		WearableSlotNotSet,
+		/// Avatar already has a wearable on this slot
+		WearableSlotOccupied,
+		/// Wearables can not be used as avatar
+		InvalidAvatar,
+		/// NFT is equipped on an avatar
+		NftIsEquipped,
+		/// NFT is not equipped
+		NftIsNotEquipped,
+		/// Avatar has equipped wearables
+		AvatarHasEquippedItems,
+		/// Only executable collection
+		OnlyForExecutableCollectionType,
+		/// Executable payload can not be decoded into a call
+		InvalidExecutablePayload,
+		/// Call of the executable NFT is not allowed
+		ExecutableCallNotAllowed,
+		/// Call of the executable NFT exceeds the max weight
+		ExecutableCallWeightTooHigh,
+		/// Executable NFT is used again before its cooldown ends
+		NftIsOnCooldown,
+		/// Attributes of the class are frozen
+		AttributesAreFrozen,
+		/// Attribute not found
+		AttributeNotFound,
+		/// Class still has tokens
+		ClassIsNotEmpty,
+		/// Class fund has no balance to withdraw
+		ClassFundIsEmpty,
+		/// Owner can not approve itself
+		ApproveToCaller,
+		/// NFT is rented
+		NftIsRented,
+		/// NFT is not listed for rent
+		RentListingNotFound,
+		/// Rental duration is zero or exceeds the max duration
+		InvalidRentalDuration,
+		/// Owner can not rent its own NFT
+		RentOwnNft,
+		/// Rental price is higher than the max price the renter accepts
+		RentPriceExceedsMaxPrice,
+		/// Class is not open to public minting
+		PublicMintNotEnabled,
+		/// Public mint has not started
+		PublicMintNotStarted,
+		/// Public mint has ended
+		PublicMintEnded,
+		/// Minting exceeds the max supply of the class
+		ExceedMaxSupply,
+		/// Minting exceeds the per account limit of the class
+		ExceedPerAccountMintLimit,
+		/// Account is not on the mint allowlist
+		NotOnMintAllowlist,
+		/// Max supply is below the number of tokens minted from the class
+		InvalidMaxSupply,
+		/// Max supply of the class is set and can only be lowered
+		MaxSupplyCanOnlyBeLowered,
 	}
 
 	#[pallet::call]
@@ -342,6 +666,10 @@ pub mod pallet {
 				Error::<T>::RoyaltyFeeExceedLimit
 			);
 
+			if let CollectionType::Executable(payload) = &collection_type {
+				Self::decode_executable_payload(payload)?;
+			}
+
 			// Class fund
 			let class_fund: T::AccountId = T::PalletId::get().into_sub_account(next_class_id);
 
@@ -357,6 +685,8 @@ pub mod pallet {
 				token_type,
 				collection_type,
 				attributes: attributes,
+				royalty_fee,
+				royalty_recipient: sender.clone(),
 			};
 
 			NftModule::<T>::create_class(&sender, metadata, class_data)?;
@@ -390,6 +720,7 @@ pub mod pallet {
 
 			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
 			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+			Self::ensure_within_max_supply(&class_id, quantity)?;
 			let token_deposit = Self::calculate_fee_deposit(&attributes, &metadata)?;
 			let class_fund: T::AccountId = T::PalletId::get().into_sub_account(class_id);
 			let deposit = token_deposit.saturating_mul(Into::<BalanceOf<T>>::into(quantity));
@@ -398,7 +729,7 @@ pub mod pallet {
 			<T as Config>::Currency::reserve(&class_fund, deposit)?;
 
 			let new_nft_data = NftAssetData {
-				deposit,
+				deposit: token_deposit,
 				attributes: attributes,
 			};
 
@@ -445,6 +776,7 @@ pub mod pallet {
 		}
 
 		#[pallet::weight(T::WeightInfo::transfer_batch(tos.len() as u32))]
+		#[transactional]
 		pub fn transfer_batch(
 			origin: OriginFor<T>,
 			tos: Vec<(T::AccountId, (ClassIdOf<T>, TokenIdOf<T>))>,
@@ -468,8 +800,9 @@ pub mod pallet {
 						let asset_info =
 							NftModule::<T>::tokens((item.1).0, (item.1).1).ok_or(Error::<T>::AssetInfoNotFound)?;
 						ensure!(owner.clone() == asset_info.owner, Error::<T>::NoPermission);
+						Self::ensure_not_rented(&item.1)?;
 
-						NftModule::<T>::transfer(&owner, &item.0, item.1)?;
+						Self::transfer_and_clear_delegations(&owner, &item.0, item.1)?;
 						Self::deposit_event(Event::<T>::TransferedNft(
 							owner.clone(),
 							item.0.clone(),
@@ -477,7 +810,7 @@ pub mod pallet {
 							item.1.clone(),
 						));
 					}
-					_ => (),
+					TokenType::BoundToAddress => return Err(Error::<T>::NonTransferable.into()),
 				};
 			}
 
@@ -532,15 +865,455 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::sign_asset())]
+		#[pallet::weight(T::WeightInfo::burn())]
+		#[transactional]
 		pub fn burn(origin: OriginFor<T>, asset_id: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
-			NftModule::<T>::burn(&sender, asset_id)?;
+			Self::do_burn(&sender, &asset_id)?;
 			Self::deposit_event(Event::<T>::BurnedNft(asset_id));
 			Ok(().into())
 		}
 
+		/// Class owner destroys a class without tokens, the class deposit is refunded and the free
+		/// balance left in the class fund is swept to the owner
+		#[pallet::weight(T::WeightInfo::destroy_class())]
+		#[transactional]
+		pub fn destroy_class(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(!Self::is_collection_locked(&class_id), Error::<T>::CollectionIsLocked);
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+			ensure!(class_info.total_issuance.is_zero(), Error::<T>::ClassIsNotEmpty);
+
+			NftModule::<T>::destroy_class(&sender, class_id)?;
+			let refunded = Self::refund_deposit(&class_id, &sender, class_info.data.deposit)?;
+
+			// Nothing can withdraw from the class fund once the class is gone
+			let class_fund = Self::get_class_fund(&class_id);
+			let remaining = <T as Config>::Currency::free_balance(&class_fund);
+			<T as Config>::Currency::transfer(&class_fund, &sender, remaining, ExistenceRequirement::AllowDeath)?;
+			let refunded = refunded.saturating_add(remaining);
+
+			ClassDataCollection::<T>::remove(class_id);
+			WearableSlots::<T>::remove(class_id);
+			AttributeUpdateAuthorities::<T>::remove(class_id);
+			ClassMintSettings::<T>::remove(class_id);
+			ClassMaxSupply::<T>::remove(class_id);
+			MintAllowlist::<T>::remove_prefix(class_id, None);
+			MintedCount::<T>::remove_prefix(class_id, None);
+
+			Self::deposit_event(Event::<T>::ClassDestroyed(class_id, sender, refunded));
+			Ok(().into())
+		}
+
+		/// Class owner sets the account receiving the royalty fee of the class
+		#[pallet::weight(T::WeightInfo::set_royalty_recipient())]
+		pub fn set_royalty_recipient(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			recipient: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			orml_nft::Classes::<T>::try_mutate(class_id, |class_info| -> DispatchResult {
+				let class_info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+				ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+
+				class_info.data.royalty_recipient = recipient.clone();
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::RoyaltyRecipientUpdated(class_id, recipient));
+			Ok(().into())
+		}
+
+		/// Class owner withdraws the free balance of the class fund, the reserved deposits of the
+		/// class and its tokens stay in the fund
+		#[pallet::weight(T::WeightInfo::withdraw_class_fund())]
+		pub fn withdraw_class_fund(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+
+			let class_fund = Self::get_class_fund(&class_id);
+			let amount = <T as Config>::Currency::free_balance(&class_fund);
+			ensure!(!amount.is_zero(), Error::<T>::ClassFundIsEmpty);
+
+			<T as Config>::Currency::transfer(&class_fund, &sender, amount, ExistenceRequirement::KeepAlive)?;
+
+			Self::deposit_event(Event::<T>::ClassFundWithdrawn(class_id, sender, amount));
+			Ok(().into())
+		}
+
+		/// Class owner withdraws the free balance of a fungible token from the class fund, such as
+		/// the proceeds of a public mint priced in that token
+		#[pallet::weight(T::WeightInfo::withdraw_class_fund_token())]
+		pub fn withdraw_class_fund_token(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			currency_id: FungibleTokenId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+
+			let class_fund = Self::get_class_fund(&class_id);
+			let amount = T::MultiCurrency::free_balance(currency_id, &class_fund);
+			ensure!(!amount.is_zero(), Error::<T>::ClassFundIsEmpty);
+
+			T::MultiCurrency::transfer(currency_id, &class_fund, &sender, amount)?;
+
+			Self::deposit_event(Event::<T>::ClassFundTokenWithdrawn(
+				class_id,
+				sender,
+				currency_id,
+				amount,
+			));
+			Ok(().into())
+		}
+
+		/// Class owner opens the class to public minting with `mint_from_class`, `None` closes the
+		/// public mint. The max supply can only be lowered once set, down to the number of tokens
+		/// already minted from the class.
+		#[pallet::weight(T::WeightInfo::set_mint_settings())]
+		pub fn set_mint_settings(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			settings: Option<MintSettings<TokenIdOf<T>, BalanceOf<T>, T::BlockNumber>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+
+			match &settings {
+				Some(mint_settings) => {
+					if let Some(current_max_supply) = Self::get_max_supply(class_id) {
+						ensure!(
+							mint_settings
+								.max_supply
+								.map_or(false, |max_supply| max_supply <= current_max_supply),
+							Error::<T>::MaxSupplyCanOnlyBeLowered
+						);
+					}
+					if let Some(max_supply) = mint_settings.max_supply {
+						ensure!(
+							max_supply >= NftModule::<T>::next_token_id(class_id),
+							Error::<T>::InvalidMaxSupply
+						);
+						ClassMaxSupply::<T>::insert(class_id, max_supply);
+					}
+					ClassMintSettings::<T>::insert(class_id, mint_settings);
+				}
+				None => ClassMintSettings::<T>::remove(class_id),
+			}
+
+			Self::deposit_event(Event::<T>::MintSettingsUpdated(class_id, settings));
+			Ok(().into())
+		}
+
+		/// Class owner adds accounts to or removes accounts from the mint allowlist of the class
+		#[pallet::weight(T::WeightInfo::set_mint_allowlist(accounts.len() as u32))]
+		pub fn set_mint_allowlist(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			accounts: Vec<T::AccountId>,
+			allowed: bool,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+
+			for account in accounts.iter() {
+				if allowed {
+					MintAllowlist::<T>::insert(class_id, account, ());
+				} else {
+					MintAllowlist::<T>::remove(class_id, account);
+				}
+			}
+
+			Self::deposit_event(Event::<T>::MintAllowlistUpdated(class_id, accounts, allowed));
+			Ok(().into())
+		}
+
+		/// Anyone mints tokens of a class open to public minting. The tokens take the metadata and
+		/// attributes of the class, the minter pays the mint price into the class fund and the
+		/// token deposit.
+		#[pallet::weight(T::WeightInfo::mint_from_class(*quantity))]
+		#[transactional]
+		pub fn mint_from_class(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			quantity: u32,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(!Self::is_collection_locked(&class_id), Error::<T>::CollectionIsLocked);
+			ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
+			ensure!(
+				quantity <= T::MaxBatchMinting::get(),
+				Error::<T>::ExceedMaximumBatchMinting
+			);
+
+			let mint_settings = Self::get_mint_settings(class_id).ok_or(Error::<T>::PublicMintNotEnabled)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				mint_settings.start_block.map_or(true, |start_block| now >= start_block),
+				Error::<T>::PublicMintNotStarted
+			);
+			ensure!(
+				mint_settings.end_block.map_or(true, |end_block| now < end_block),
+				Error::<T>::PublicMintEnded
+			);
+			ensure!(
+				!mint_settings.allowlist_only || MintAllowlist::<T>::contains_key(class_id, &sender),
+				Error::<T>::NotOnMintAllowlist
+			);
+
+			let minted_count = Self::get_minted_count(class_id, &sender).saturating_add(quantity);
+			ensure!(
+				mint_settings
+					.per_account_limit
+					.map_or(true, |limit| minted_count <= limit),
+				Error::<T>::ExceedPerAccountMintLimit
+			);
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			Self::ensure_within_max_supply(&class_id, quantity)?;
+
+			let class_fund: T::AccountId = T::PalletId::get().into_sub_account(class_id);
+			let price = mint_settings.price.saturating_mul(Into::<BalanceOf<T>>::into(quantity));
+			if !price.is_zero() {
+				T::MultiCurrency::transfer(mint_settings.currency_id, &sender, &class_fund, price)?;
+			}
+
+			let metadata = class_info.metadata.to_vec();
+			let attributes = class_info.data.attributes;
+			let token_deposit = Self::calculate_fee_deposit(&attributes, &metadata)?;
+			let deposit = token_deposit.saturating_mul(Into::<BalanceOf<T>>::into(quantity));
+
+			<T as Config>::Currency::transfer(&sender, &class_fund, deposit, ExistenceRequirement::KeepAlive)?;
+			<T as Config>::Currency::reserve(&class_fund, deposit)?;
+
+			let new_nft_data = NftAssetData {
+				deposit: token_deposit,
+				attributes: attributes,
+			};
+
+			let mut new_asset_ids: Vec<(ClassIdOf<T>, TokenIdOf<T>)> = Vec::new();
+			let mut last_token_id: TokenIdOf<T> = Default::default();
+
+			for _ in 0..quantity {
+				let token_id = NftModule::<T>::mint(&sender, class_id, metadata.clone(), new_nft_data.clone())?;
+				new_asset_ids.push((class_id, token_id));
+
+				last_token_id = token_id;
+			}
+			MintedCount::<T>::insert(class_id, &sender, minted_count);
+
+			Self::deposit_event(Event::<T>::NewNftMinted(
+				*new_asset_ids.first().unwrap(),
+				*new_asset_ids.last().unwrap(),
+				sender,
+				class_id,
+				quantity,
+				last_token_id,
+			));
+
+			Ok(().into())
+		}
+
+		/// Owner or operator of the owner approves an account to transfer a token, `None` clears
+		/// the approval
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			spender: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let token_info = NftModule::<T>::tokens(asset_id.0, asset_id.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			let owner = token_info.owner;
+			ensure!(
+				sender == owner || OperatorApprovals::<T>::contains_key(&owner, &sender),
+				Error::<T>::NoPermission
+			);
+			// Soulbound tokens can not be moved by an approved account either
+			let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(class_info.data.token_type.is_transferable(), Error::<T>::NonTransferable);
+			ensure!(spender.as_ref() != Some(&owner), Error::<T>::ApproveToCaller);
+
+			match &spender {
+				Some(spender) => Approvals::<T>::insert(asset_id, spender),
+				None => Approvals::<T>::remove(asset_id),
+			}
+
+			Self::deposit_event(Event::<T>::NftApproved(asset_id, owner, spender));
+			Ok(().into())
+		}
+
+		/// Owner approves or revokes an operator to transfer all of its tokens
+		#[pallet::weight(T::WeightInfo::set_approval_for_all())]
+		pub fn set_approval_for_all(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			approved: bool,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(sender != operator, Error::<T>::ApproveToCaller);
+
+			if approved {
+				OperatorApprovals::<T>::insert(&sender, &operator, ());
+			} else {
+				OperatorApprovals::<T>::remove(&sender, &operator);
+			}
+
+			Self::deposit_event(Event::<T>::ApprovalForAll(sender, operator, approved));
+			Ok(().into())
+		}
+
+		/// Owner, approved account or operator transfers a token from its owner
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				Self::check_item_on_listing(asset_id.0, asset_id.1)? == false,
+				Error::<T>::AssetAlreadyInAuction
+			);
+			ensure!(
+				Self::is_approved_or_owner(&sender, &from, &asset_id),
+				Error::<T>::NoPermission
+			);
+
+			let token_id = Self::do_transfer(&from, &to, asset_id)?;
+
+			Self::deposit_event(Event::<T>::TransferedNft(from, to, token_id, asset_id));
+
+			Ok(().into())
+		}
+
+		/// Owner lists a token for rent, the terms apply to the next rentals
+		#[pallet::weight(T::WeightInfo::list_for_rent())]
+		pub fn list_for_rent(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			price_per_block: BalanceOf<T>,
+			max_duration: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				Self::check_nft_ownership(&sender, &asset_id)?,
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				Self::check_item_on_listing(asset_id.0, asset_id.1)? == false,
+				Error::<T>::AssetAlreadyInAuction
+			);
+			ensure!(!max_duration.is_zero(), Error::<T>::InvalidRentalDuration);
+
+			RentalListings::<T>::insert(
+				asset_id,
+				RentalListing {
+					price_per_block,
+					max_duration,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::NftListedForRent(
+				asset_id,
+				sender,
+				price_per_block,
+				max_duration,
+			));
+			Ok(().into())
+		}
+
+		/// Owner cancels the rent listing of a token, an active rental runs until it expires
+		#[pallet::weight(T::WeightInfo::cancel_rent_listing())]
+		pub fn cancel_rent_listing(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				Self::check_nft_ownership(&sender, &asset_id)?,
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				RentalListings::<T>::contains_key(asset_id),
+				Error::<T>::RentListingNotFound
+			);
+
+			RentalListings::<T>::remove(asset_id);
+
+			Self::deposit_event(Event::<T>::RentListingCancelled(asset_id));
+			Ok(().into())
+		}
+
+		/// Renter pays the owner upfront and becomes the user of a listed token for `duration`
+		/// blocks, the owner keeps the ownership but can not move the token until it expires. The
+		/// rental fails if the listing was repriced above `max_price`.
+		#[pallet::weight(T::WeightInfo::rent())]
+		pub fn rent(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			duration: T::BlockNumber,
+			max_price: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let listing = Self::get_rental_listing(asset_id).ok_or(Error::<T>::RentListingNotFound)?;
+			let token_info = NftModule::<T>::tokens(asset_id.0, asset_id.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			ensure!(sender != token_info.owner, Error::<T>::RentOwnNft);
+			ensure!(
+				!duration.is_zero() && duration <= listing.max_duration,
+				Error::<T>::InvalidRentalDuration
+			);
+			Self::ensure_not_rented(&asset_id)?;
+			ensure!(
+				Self::check_item_on_listing(asset_id.0, asset_id.1)? == false,
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			let duration_blocks: u32 = duration.unique_saturated_into();
+			let price = listing.price_per_block.saturating_mul(duration_blocks.into());
+			ensure!(price <= max_price, Error::<T>::RentPriceExceedsMaxPrice);
+			<T as Config>::Currency::transfer(
+				&sender,
+				&token_info.owner,
+				price,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			let expires_at = <frame_system::Pallet<T>>::block_number().saturating_add(duration);
+			Rentals::<T>::insert(
+				asset_id,
+				RentalInfo {
+					renter: sender.clone(),
+					expires_at,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::NftRented(asset_id, sender, expires_at, price));
+			Ok(().into())
+		}
+
 		#[pallet::weight(T::WeightInfo::sign_asset())]
 		pub fn force_lock_collection(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResult {
 			ensure_root(origin)?;
@@ -571,7 +1344,8 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Force NFT transfer which only triggered by governance
+		/// Force NFT transfer which only triggered by governance. This is the recovery path of
+		/// soulbound tokens, which can not be moved otherwise.
 		#[pallet::weight(T::WeightInfo::transfer())]
 		pub fn force_transfer(
 			origin: OriginFor<T>,
@@ -591,10 +1365,235 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Holder uses an executable NFT, the call of its class is dispatched on behalf of the
+		/// holder. The NFT is burnt if the class burns on use, otherwise it can be used again
+		/// after the cooldown.
+		///
+		/// The call is checked against `ExecutableCallFilter` again before it is dispatched, so
+		/// classes created before a call was removed from the filter can no longer dispatch it.
+		/// The max call weight is charged upfront and refunded down to the actual call weight.
+		#[pallet::weight(T::WeightInfo::use_nft().saturating_add(T::MaxExecutableCallWeight::get()))]
+		#[transactional]
+		pub fn use_nft(origin: OriginFor<T>, asset_id: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(Self::check_nft_ownership(&sender, &asset_id)?, Error::<T>::NoPermission);
+			ensure!(
+				Self::check_item_on_listing(asset_id.0, asset_id.1)? == false,
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			let executable = match class_info.data.collection_type {
+				CollectionType::Executable(payload) => Self::decode_executable_payload(&payload)?,
+				_ => return Err(Error::<T>::OnlyForExecutableCollectionType.into()),
+			};
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			if let Some(last_used) = Self::get_last_used(asset_id) {
+				ensure!(
+					now >= last_used.saturating_add(executable.cooldown),
+					Error::<T>::NftIsOnCooldown
+				);
+			}
+
+			if executable.burn_on_use {
+				Self::do_burn(&sender, &asset_id)?;
+				LastUsed::<T>::remove(asset_id);
+			} else {
+				LastUsed::<T>::insert(asset_id, now);
+			}
+
+			let call_weight = executable.call.get_dispatch_info().weight;
+			let post_info = executable
+				.call
+				.dispatch(frame_system::RawOrigin::Signed(sender.clone()).into())
+				.map_err(|e| e.error)?;
+
+			Self::deposit_event(Event::<T>::NftUsed(asset_id, sender));
+
+			Ok(Some(T::WeightInfo::use_nft().saturating_add(post_info.actual_weight.unwrap_or(call_weight))).into())
+		}
+
+		/// Class owner sets who can update the token attributes of the class. Frozen attributes
+		/// can not be unfrozen.
+		#[pallet::weight(T::WeightInfo::set_attribute_update_authority())]
+		pub fn set_attribute_update_authority(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			authority: AttributeUpdateAuthority,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				Self::get_attribute_update_authority(class_id) != AttributeUpdateAuthority::Frozen,
+				Error::<T>::AttributesAreFrozen
+			);
+
+			AttributeUpdateAuthorities::<T>::insert(class_id, authority);
+
+			Self::deposit_event(Event::<T>::AttributeUpdateAuthoritySet(class_id, authority));
+
+			Ok(().into())
+		}
+
+		/// Update authority sets a token attribute, the token deposit is re-priced
+		#[pallet::weight(T::WeightInfo::set_token_attribute())]
+		pub fn set_token_attribute(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			key: Vec<u8>,
+			value: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			Self::do_update_token_attributes(&sender, asset_id, |attributes| {
+				attributes.insert(key.clone(), value.clone());
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TokenAttributeSet(asset_id, key, value));
+
+			Ok(().into())
+		}
+
+		/// Update authority removes a token attribute, the token deposit is re-priced
+		#[pallet::weight(T::WeightInfo::remove_token_attribute())]
+		pub fn remove_token_attribute(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			key: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			Self::do_update_token_attributes(&sender, asset_id, |attributes| {
+				attributes.remove(&key).ok_or(Error::<T>::AttributeNotFound)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TokenAttributeRemoved(asset_id, key));
+
+			Ok(().into())
+		}
+
+		/// Class owner sets the avatar slot the wearables of the class are equipped on
+		#[pallet::weight(T::WeightInfo::set_wearable_slot())]
+		pub fn set_wearable_slot(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			slot: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				class_info.data.collection_type.is_wearable(),
+				Error::<T>::OnlyForWearableCollectionType
+			);
+			ensure!(
+				!slot.is_empty() && slot.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::InvalidWearableSlot
+			);
+
+			WearableSlots::<T>::insert(class_id, slot.clone());
+
+			Self::deposit_event(Event::<T>::WearableSlotSet(class_id, slot));
+
+			Ok(().into())
+		}
+
+		/// Equip a wearable on an avatar. The wearable is owned by the avatar until it is
+		/// unequipped, it moves with the avatar and can not be transferred or listed on its own.
+		#[pallet::weight(T::WeightInfo::equip())]
+		pub fn equip(
+			origin: OriginFor<T>,
+			wearable: (ClassIdOf<T>, TokenIdOf<T>),
+			avatar: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				Self::check_nft_ownership(&sender, &wearable)? && Self::check_nft_ownership(&sender, &avatar)?,
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				Self::check_item_on_listing(wearable.0, wearable.1)? == false
+					&& Self::check_item_on_listing(avatar.0, avatar.1)? == false,
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			let wearable_class = NftModule::<T>::classes(wearable.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(
+				wearable_class.data.collection_type.is_wearable(),
+				Error::<T>::OnlyForWearableCollectionType
+			);
+			// Equipped wearables move with their avatar, soulbound wearables stay with the holder
+			ensure!(
+				wearable_class.data.token_type.is_transferable(),
+				Error::<T>::NonTransferable
+			);
+			Self::ensure_not_rented(&wearable)?;
+			// Wearables can not be equipped on each other
+			let avatar_class = NftModule::<T>::classes(avatar.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(
+				!avatar_class.data.collection_type.is_wearable(),
+				Error::<T>::InvalidAvatar
+			);
+
+			let slot = Self::get_wearable_slot(wearable.0).ok_or(Error::<T>::WearableSlotNotSet)?;
+			ensure!(
+				!EquippedItems::<T>::contains_key(avatar, &slot),
+				Error::<T>::WearableSlotOccupied
+			);
+
+			Self::transfer_and_clear_delegations(&sender, &Self::get_avatar_account(&avatar), wearable)?;
+			EquippedItems::<T>::insert(avatar, &slot, wearable);
+			EquippedTo::<T>::insert(wearable, (avatar, slot.clone()));
+
+			Self::deposit_event(Event::<T>::NftEquipped(wearable, avatar, slot));
+
+			Ok(().into())
+		}
+
+		/// Avatar owner can unequip a wearable, which is returned to the avatar owner
+		#[pallet::weight(T::WeightInfo::unequip())]
+		pub fn unequip(origin: OriginFor<T>, wearable: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (avatar, slot) = Self::get_equipped_to(wearable).ok_or(Error::<T>::NftIsNotEquipped)?;
+			ensure!(Self::check_nft_ownership(&sender, &avatar)?, Error::<T>::NoPermission);
+			// The loadout of a listed avatar is part of the listing
+			ensure!(
+				Self::check_item_on_listing(avatar.0, avatar.1)? == false,
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			Self::transfer_and_clear_delegations(&Self::get_avatar_account(&avatar), &sender, wearable)?;
+			EquippedItems::<T>::remove(avatar, &slot);
+			EquippedTo::<T>::remove(wearable);
+
+			Self::deposit_event(Event::<T>::NftUnequipped(wearable, avatar));
+
+			Ok(().into())
+		}
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() < STORAGE_VERSION {
+				let weight = Self::upgrade_class_data_v1().saturating_add(Self::upgrade_token_deposit_v1());
+				STORAGE_VERSION.put::<Pallet<T>>();
+				weight
+			} else {
+				0
+			}
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -606,6 +1605,94 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_sub_account(class_id)
 	}
 
+	/// Account that owns the wearables equipped on an avatar
+	pub fn get_avatar_account(avatar: &(ClassIdOf<T>, TokenIdOf<T>)) -> T::AccountId {
+		T::PalletId::get().into_sub_account((b"avatar", avatar))
+	}
+
+	/// Update the attributes of a token on behalf of the class update authority. The token
+	/// deposit is re-priced for the new attributes, the updater pays any increase to the class
+	/// fund and is refunded any decrease.
+	fn do_update_token_attributes(
+		sender: &T::AccountId,
+		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+		update: impl FnOnce(&mut Attributes) -> DispatchResult,
+	) -> DispatchResult {
+		ensure!(!Self::is_collection_locked(&asset_id.0), Error::<T>::CollectionIsLocked);
+		ensure!(
+			Self::check_item_on_listing(asset_id.0, asset_id.1)? == false,
+			Error::<T>::AssetAlreadyInAuction
+		);
+
+		let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+
+		orml_nft::Tokens::<T>::try_mutate(asset_id.0, asset_id.1, |token_info| -> DispatchResult {
+			let token_info = token_info.as_mut().ok_or(Error::<T>::AssetInfoNotFound)?;
+
+			let is_authority = match Self::get_attribute_update_authority(asset_id.0) {
+				AttributeUpdateAuthority::ClassOwner => *sender == class_info.owner,
+				AttributeUpdateAuthority::TokenOwner => *sender == token_info.owner,
+				AttributeUpdateAuthority::Frozen => return Err(Error::<T>::AttributesAreFrozen.into()),
+			};
+			ensure!(is_authority, Error::<T>::NoPermission);
+
+			let metadata = token_info.metadata.to_vec();
+			let old_deposit = Self::calculate_fee_deposit(&token_info.data.attributes, &metadata)?;
+			update(&mut token_info.data.attributes)?;
+			let new_deposit = Self::calculate_fee_deposit(&token_info.data.attributes, &metadata)?;
+
+			let class_fund = Self::get_class_fund(&asset_id.0);
+			if new_deposit > old_deposit {
+				let deposit_increase = new_deposit.saturating_sub(old_deposit);
+				<T as Config>::Currency::transfer(
+					sender,
+					&class_fund,
+					deposit_increase,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				<T as Config>::Currency::reserve(&class_fund, deposit_increase)?;
+				token_info.data.deposit = token_info.data.deposit.saturating_add(deposit_increase);
+			} else {
+				// The updater may not have paid the deposit being lowered, so the difference is
+				// refunded to the token owner as on burn
+				let deposit_decrease = old_deposit.saturating_sub(new_deposit);
+				Self::refund_deposit(&asset_id.0, &token_info.owner, deposit_decrease)?;
+				token_info.data.deposit = token_info.data.deposit.saturating_sub(deposit_decrease);
+			}
+
+			Ok(())
+		})
+	}
+
+	/// Decode the payload of an executable class and check its call is allowed
+	fn decode_executable_payload(
+		payload: &[u8],
+	) -> Result<ExecutableNftData<<T as Config>::Call, T::BlockNumber>, DispatchError> {
+		let executable = ExecutableNftData::<<T as Config>::Call, T::BlockNumber>::decode(&mut &payload[..])
+			.map_err(|_| Error::<T>::InvalidExecutablePayload)?;
+
+		ensure!(
+			T::ExecutableCallFilter::contains(&executable.call),
+			Error::<T>::ExecutableCallNotAllowed
+		);
+		ensure!(
+			executable.call.get_dispatch_info().weight <= T::MaxExecutableCallWeight::get(),
+			Error::<T>::ExecutableCallWeightTooHigh
+		);
+
+		Ok(executable)
+	}
+
+	/// Ensure the NFT is neither equipped nor an avatar with equipped wearables
+	fn ensure_not_equipped(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		ensure!(!EquippedTo::<T>::contains_key(asset_id), Error::<T>::NftIsEquipped);
+		ensure!(
+			EquippedItems::<T>::iter_prefix(asset_id).next().is_none(),
+			Error::<T>::AvatarHasEquippedItems
+		);
+		Ok(())
+	}
+
 	fn do_create_group_collection(name: Vec<u8>, properties: Vec<u8>) -> Result<GroupCollectionId, DispatchError> {
 		let next_group_collection_id =
 			NextGroupCollectionId::<T>::try_mutate(|collection_id| -> Result<GroupCollectionId, DispatchError> {
@@ -631,6 +1718,8 @@ impl<T: Config> Pallet<T> {
 		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
 	) -> Result<<T as orml_nft::Config>::TokenId, DispatchError> {
 		ensure!(!Self::is_collection_locked(&asset_id.0), Error::<T>::CollectionIsLocked);
+		ensure!(!EquippedTo::<T>::contains_key(&asset_id), Error::<T>::NftIsEquipped);
+		Self::ensure_not_rented(&asset_id)?;
 
 		let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
 		let data = class_info.data;
@@ -640,7 +1729,7 @@ impl<T: Config> Pallet<T> {
 				let check_ownership = Self::check_nft_ownership(&sender, &asset_id)?;
 				ensure!(check_ownership, Error::<T>::NoPermission);
 
-				NftModule::<T>::transfer(&sender, &to, asset_id.clone())?;
+				Self::transfer_and_clear_delegations(&sender, &to, asset_id)?;
 				Ok(asset_id.1)
 			}
 			TokenType::BoundToAddress => Err(Error::<T>::NonTransferable.into()),
@@ -672,11 +1761,66 @@ impl<T: Config> Pallet<T> {
 		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
 	) -> Result<<T as orml_nft::Config>::TokenId, DispatchError> {
 		ensure!(!Self::is_collection_locked(&asset_id.0), Error::<T>::CollectionIsLocked);
+		ensure!(!EquippedTo::<T>::contains_key(&asset_id), Error::<T>::NftIsEquipped);
 
-		NftModule::<T>::transfer(&sender, &to, asset_id.clone())?;
+		Self::transfer_and_clear_delegations(&sender, &to, asset_id)?;
 		Ok(asset_id.1)
 	}
 
+	/// Transfer a token and clear its approved account, rent listing and expired rental
+	fn transfer_and_clear_delegations(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		NftModule::<T>::transfer(from, to, asset_id)?;
+		Approvals::<T>::remove(asset_id);
+		RentalListings::<T>::remove(asset_id);
+		Rentals::<T>::remove(asset_id);
+		Ok(())
+	}
+
+	/// Renter of a token while its rental is active
+	pub fn active_renter(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> Option<T::AccountId> {
+		Rentals::<T>::get(asset_id)
+			.filter(|rental| <frame_system::Pallet<T>>::block_number() < rental.expires_at)
+			.map(|rental| rental.renter)
+	}
+
+	/// Check minting `quantity` more tokens stays within the max supply of the class. Burnt
+	/// tokens still count as minted, the next token id is the number of tokens ever minted.
+	fn ensure_within_max_supply(class_id: &ClassIdOf<T>, quantity: u32) -> DispatchResult {
+		if let Some(max_supply) = Self::get_max_supply(class_id) {
+			ensure!(
+				NftModule::<T>::next_token_id(class_id).saturating_add(quantity.into()) <= max_supply,
+				Error::<T>::ExceedMaxSupply
+			);
+		}
+		Ok(())
+	}
+
+	fn ensure_not_rented(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		ensure!(Self::active_renter(asset_id).is_none(), Error::<T>::NftIsRented);
+		Ok(())
+	}
+
+	/// Check if `who` may transfer a token owned by `owner`, either as the owner, the approved
+	/// account of the token or an operator of the owner
+	pub fn is_approved_or_owner(
+		who: &T::AccountId,
+		owner: &T::AccountId,
+		asset_id: &(ClassIdOf<T>, TokenIdOf<T>),
+	) -> bool {
+		match NftModule::<T>::tokens(asset_id.0, asset_id.1) {
+			Some(token_info) if token_info.owner == *owner => {
+				who == owner
+					|| Approvals::<T>::get(asset_id).as_ref() == Some(who)
+					|| OperatorApprovals::<T>::contains_key(owner, who)
+			}
+			_ => false,
+		}
+	}
+
 	fn do_mint_nfts(
 		sender: &T::AccountId,
 		class_id: ClassIdOf<T>,
@@ -697,6 +1841,7 @@ impl<T: Config> Pallet<T> {
 
 		let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
 		ensure!(sender.clone() == class_info.owner, Error::<T>::NoPermission);
+		Self::ensure_within_max_supply(&class_id, quantity)?;
 		let token_deposit = Self::calculate_fee_deposit(&attributes, &metadata)?;
 		let class_fund: T::AccountId = T::PalletId::get().into_sub_account(class_id);
 		let deposit = token_deposit.saturating_mul(Into::<BalanceOf<T>>::into(quantity));
@@ -705,7 +1850,7 @@ impl<T: Config> Pallet<T> {
 		<T as Config>::Currency::reserve(&class_fund, deposit)?;
 
 		let new_nft_data = NftAssetData {
-			deposit,
+			deposit: token_deposit,
 			attributes: attributes,
 		};
 
@@ -748,12 +1893,13 @@ impl<T: Config> Pallet<T> {
 		Ok(total_deposit_required)
 	}
 
-	pub fn upgrade_class_data_v2() -> Weight {
-		log::info!("Start upgrading nft class data v2");
+	/// Classes created before version 1 paid the global royalty fee of 0.1% to their class fund,
+	/// they keep paying it until the class owner changes the royalty recipient
+	pub fn upgrade_class_data_v1() -> Weight {
+		log::info!("Start upgrading nft class data v1");
 		let mut num_nft_classes = 0;
-		let mut asset_by_owner_updates = 0;
 
-		orml_nft::Classes::<T>::translate(|_k, class_info: ClassInfoOf<T>| {
+		orml_nft::Classes::<T>::translate(|class_id, class_info: OldClassInfoOf<T>| {
 			num_nft_classes += 1;
 			log::info!("Upgrading class data");
 			let new_data = NftClassData {
@@ -761,6 +1907,8 @@ impl<T: Config> Pallet<T> {
 				attributes: class_info.data.attributes,
 				token_type: class_info.data.token_type,
 				collection_type: class_info.data.collection_type,
+				royalty_fee: Perbill::from_rational(10u32, 10_000),
+				royalty_recipient: Self::get_class_fund(&class_id),
 			};
 
 			let v: ClassInfoOf<T> = ClassInfo {
@@ -773,13 +1921,57 @@ impl<T: Config> Pallet<T> {
 		});
 
 		log::info!("Classes upgraded: {}", num_nft_classes);
-		0
+		T::DbWeight::get().reads_writes(num_nft_classes, num_nft_classes)
 	}
 
+	/// Tokens of a batch mint used to store the deposit of the whole batch, rewrite it to the
+	/// deposit of the token alone so burning a token does not release the deposit of the batch
+	pub fn upgrade_token_deposit_v1() -> Weight {
+		log::info!("Start upgrading nft token deposit v1");
+		let mut num_nft_tokens = 0;
+
+		orml_nft::Tokens::<T>::translate(|_class_id, _token_id, mut token_info: TokenInfoOf<T>| {
+			num_nft_tokens += 1;
+			let metadata = token_info.metadata.to_vec();
+			if let Ok(token_deposit) = Self::calculate_fee_deposit(&token_info.data.attributes, &metadata) {
+				token_info.data.deposit = token_info.data.deposit.min(token_deposit);
+			}
+			Some(token_info)
+		});
+
+		log::info!("Tokens upgraded: {}", num_nft_tokens);
+		T::DbWeight::get().reads_writes(num_nft_tokens, num_nft_tokens)
+	}
+
+	/// Burn a token and refund its deposit to the owner
 	fn do_burn(sender: &T::AccountId, asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		Self::ensure_not_equipped(asset_id)?;
+		Self::ensure_not_rented(asset_id)?;
+
+		let token_info = NftModule::<T>::tokens(asset_id.0, asset_id.1).ok_or(Error::<T>::AssetInfoNotFound)?;
 		NftModule::<T>::burn(&sender, *asset_id)?;
+		Approvals::<T>::remove(asset_id);
+		RentalListings::<T>::remove(asset_id);
+		Rentals::<T>::remove(asset_id);
+
+		let refunded = Self::refund_deposit(&asset_id.0, sender, token_info.data.deposit)?;
+		Self::deposit_event(Event::<T>::NftDepositRefunded(*asset_id, sender.clone(), refunded));
 		Ok(())
 	}
+
+	/// Release a deposit reserved in the class fund and transfer it to the beneficiary, returns
+	/// the refunded amount
+	fn refund_deposit(
+		class_id: &ClassIdOf<T>,
+		beneficiary: &T::AccountId,
+		deposit: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let class_fund = Self::get_class_fund(class_id);
+		let refunded = deposit.saturating_sub(<T as Config>::Currency::unreserve(&class_fund, deposit));
+		<T as Config>::Currency::transfer(&class_fund, beneficiary, refunded, ExistenceRequirement::AllowDeath)?;
+
+		Ok(refunded)
+	}
 }
 
 impl<T: Config> NFTTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
@@ -798,7 +1990,9 @@ impl<T: Config> NFTTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
 		Ok(who == &asset_info.owner)
 	}
 
-	fn get_nft_detail(asset_id: (Self::ClassId, Self::TokenId)) -> Result<(NftClassData<BalanceOf<T>>), DispatchError> {
+	fn get_nft_detail(
+		asset_id: (Self::ClassId, Self::TokenId),
+	) -> Result<(NftClassData<BalanceOf<T>, T::AccountId>), DispatchError> {
 		let asset_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::AssetInfoNotFound)?;
 
 		Ok(asset_info.data)
@@ -864,7 +2058,15 @@ impl<T: Config> NFTTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
 	}
 
 	fn transfer_nft(sender: &T::AccountId, to: &T::AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult {
-		Self::do_transfer(sender, to, nft.clone())?;
+		// The sender may move the token on behalf of its owner when approved
+		let owner = NftModule::<T>::tokens(nft.0, nft.1)
+			.ok_or(Error::<T>::AssetInfoNotFound)?
+			.owner;
+		ensure!(
+			Self::is_approved_or_owner(sender, &owner, nft),
+			Error::<T>::NoPermission
+		);
+		Self::do_transfer(&owner, to, nft.clone())?;
 
 		Ok(())
 	}
@@ -872,10 +2074,31 @@ impl<T: Config> NFTTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
 	fn is_transferable(nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
 		let class_info = NftModule::<T>::classes(nft.0).ok_or(Error::<T>::ClassIdNotFound)?;
 		let data = class_info.data;
-		Ok(data.token_type.is_transferable())
+		// Equipped wearables move with their avatar only, rented tokens stay until the rental expires
+		Ok(data.token_type.is_transferable()
+			&& !EquippedTo::<T>::contains_key(nft)
+			&& Self::active_renter(nft).is_none())
 	}
 
 	fn get_class_fund(class_id: &Self::ClassId) -> T::AccountId {
 		T::PalletId::get().into_sub_account(class_id)
 	}
+
+	fn get_user_of(nft: &(Self::ClassId, Self::TokenId)) -> Result<T::AccountId, DispatchError> {
+		let token_info = NftModule::<T>::tokens(nft.0, nft.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+
+		Ok(Self::active_renter(nft).unwrap_or(token_info.owner))
+	}
+
+	fn get_nft_owner(nft: &(Self::ClassId, Self::TokenId)) -> Result<T::AccountId, DispatchError> {
+		let token_info = NftModule::<T>::tokens(nft.0, nft.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+
+		Ok(token_info.owner)
+	}
+
+	fn get_royalty_info(class_id: &Self::ClassId) -> Result<(Perbill, T::AccountId), DispatchError> {
+		let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+
+		Ok((class_info.data.royalty_fee, class_info.data.royalty_recipient))
+	}
 }
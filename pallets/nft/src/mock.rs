@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use codec::{Decode, Encode};
-use frame_support::traits::{EqualPrivilegeOnly, Nothing};
+use frame_support::traits::{Contains, EqualPrivilegeOnly, Nothing};
 use frame_support::{construct_runtime, parameter_types};
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use orml_traits::parameter_type_with_key;
@@ -114,6 +114,7 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		_initial_amount: Self::Balance,
 		_start: u64,
 		_listing_level: ListingLevel<AccountId>,
+		_currency_id: FungibleTokenId,
 	) -> Result<u64, DispatchError> {
 		Ok(0)
 	}
@@ -214,6 +215,22 @@ impl pallet_scheduler::Config for Runtime {
 	type NoPreimagePostponement = ();
 }
 
+parameter_types! {
+	pub MaxExecutableCallWeight: Weight = 1_000_000_000;
+}
+
+pub struct ExecutableCallFilter;
+
+impl Contains<Call> for ExecutableCallFilter {
+	fn contains(call: &Call) -> bool {
+		matches!(
+			call,
+			Call::Balances(pallet_balances::Call::transfer { .. })
+				| Call::System(frame_system::Call::remark_with_event { .. })
+		)
+	}
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
@@ -226,6 +243,9 @@ impl Config for Runtime {
 	type MultiCurrency = Currencies;
 	type MiningResourceId = MiningCurrencyId;
 	type DataDepositPerByte = MetadataDataDepositPerByte;
+	type Call = Call;
+	type ExecutableCallFilter = ExecutableCallFilter;
+	type MaxExecutableCallWeight = MaxExecutableCallWeight;
 }
 
 parameter_types! {
@@ -236,7 +256,7 @@ parameter_types! {
 impl orml_nft::Config for Runtime {
 	type ClassId = u32;
 	type TokenId = u64;
-	type ClassData = NftClassData<Balance>;
+	type ClassData = NftClassData<Balance, AccountId>;
 	type TokenData = NftAssetData<Balance>;
 	type MaxClassMetadata = MaxClassMetadata;
 	type MaxTokenMetadata = MaxTokenMetadata;
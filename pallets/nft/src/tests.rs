@@ -57,7 +57,7 @@ fn init_bound_to_address_nft(owner: Origin) {
 		vec![1],
 		test_attributes(1),
 		COLLECTION_ID,
-		TokenType::Transferable,
+		TokenType::BoundToAddress,
 		CollectionType::Collectable,
 		Perbill::from_percent(0u32)
 	));
@@ -143,6 +143,8 @@ fn create_class_should_work() {
 				token_type: TokenType::Transferable,
 				collection_type: CollectionType::Collectable,
 				attributes: test_attributes(1),
+				royalty_fee: Perbill::from_percent(0u32),
+				royalty_recipient: ALICE,
 			}
 		);
 
@@ -278,139 +280,1190 @@ fn burn_nft_should_work() {
 }
 
 #[test]
-fn transfer_batch_should_work() {
+fn burn_nft_should_refund_token_deposit() {
 	ExtBuilder::default().build().execute_with(|| {
 		let origin = Origin::signed(ALICE);
 		init_test_nft(origin.clone());
-		assert_ok!(Nft::create_class(
-			origin.clone(),
-			vec![1],
-			test_attributes(1),
-			COLLECTION_ID,
-			TokenType::Transferable,
-			CollectionType::Collectable,
-			Perbill::from_percent(0u32)
-		));
-		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 4));
-		assert_ok!(Nft::transfer_batch(origin, vec![(BOB, (1, 0)), (BOB, (1, 1))]));
-		let event = mock::Event::Nft(crate::Event::TransferedNft(ALICE, BOB, 1, (1, 1)));
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 2));
+		// 5 bytes for class deposit and 5 bytes for each of the 3 tokens
+		assert_eq!(reserved_balance(&class_id_account()), 20);
+		assert_eq!(OrmlNft::tokens(CLASS_ID, 1).unwrap().data.deposit, 5);
+
+		let alice_balance = free_native_balance(ALICE);
+		assert_ok!(Nft::burn(origin, (CLASS_ID, 1)));
+
+		assert_eq!(free_native_balance(ALICE), alice_balance + 5);
+		assert_eq!(reserved_balance(&class_id_account()), 15);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == mock::Event::Nft(crate::Event::NftDepositRefunded((CLASS_ID, 1), ALICE, 5))));
+	})
+}
+
+#[test]
+fn upgrade_token_deposit_should_store_deposit_per_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 2));
+		// tokens minted in a batch before the upgrade stored the deposit of the whole batch
+		for token_id in 1..=2 {
+			orml_nft::Tokens::<Runtime>::mutate(CLASS_ID, token_id, |token_info| {
+				token_info.as_mut().unwrap().data.deposit = 10;
+			});
+		}
+
+		Nft::upgrade_token_deposit_v1();
+
+		assert_eq!(OrmlNft::tokens(CLASS_ID, 0).unwrap().data.deposit, 5);
+		assert_eq!(OrmlNft::tokens(CLASS_ID, 1).unwrap().data.deposit, 5);
+		assert_eq!(OrmlNft::tokens(CLASS_ID, 2).unwrap().data.deposit, 5);
+
+		let alice_balance = free_native_balance(ALICE);
+		assert_ok!(Nft::burn(origin, (CLASS_ID, 1)));
+		assert_eq!(free_native_balance(ALICE), alice_balance + 5);
+		assert_eq!(reserved_balance(&class_id_account()), 15);
+	})
+}
+
+#[test]
+fn destroy_class_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		let alice_balance = free_native_balance(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::burn(origin.clone(), (CLASS_ID, TOKEN_ID)));
+
+		assert_ok!(Nft::destroy_class(origin, CLASS_ID));
+
+		assert_eq!(OrmlNft::classes(CLASS_ID), None);
+		assert_eq!(reserved_balance(&class_id_account()), 0);
+		assert_eq!(free_native_balance(ALICE), alice_balance);
+		assert!(!ClassDataCollection::<Runtime>::contains_key(CLASS_ID));
+
+		let event = mock::Event::Nft(crate::Event::ClassDestroyed(CLASS_ID, ALICE, 5));
 		assert_eq!(last_event(), event);
 	})
 }
 
 #[test]
-fn transfer_batch_exceed_length_should_fail() {
+fn destroy_class_should_sweep_class_fund() {
 	ExtBuilder::default().build().execute_with(|| {
 		let origin = Origin::signed(ALICE);
 		init_test_nft(origin.clone());
-		assert_ok!(Nft::create_class(
-			origin.clone(),
-			vec![1],
-			test_attributes(1),
-			COLLECTION_ID,
-			TokenType::Transferable,
-			CollectionType::Collectable,
-			Perbill::from_percent(0u32)
-		));
-		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 4));
+		assert_ok!(Nft::burn(origin.clone(), (CLASS_ID, TOKEN_ID)));
+
+		// royalty fees paid into the class fund
+		let _ = <Runtime as Config>::Currency::deposit_creating(&class_id_account(), 20);
+
+		let alice_balance = free_native_balance(ALICE);
+		assert_ok!(Nft::destroy_class(origin, CLASS_ID));
+
+		assert_eq!(free_native_balance(ALICE), alice_balance + 25);
+		assert_eq!(free_native_balance(class_id_account()), 0);
+		assert_eq!(reserved_balance(&class_id_account()), 0);
+
+		let event = mock::Event::Nft(crate::Event::ClassDestroyed(CLASS_ID, ALICE, 25));
+		assert_eq!(last_event(), event);
+	})
+}
+
+#[test]
+fn set_royalty_recipient_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
 		assert_noop!(
-			Nft::transfer_batch(origin, vec![(BOB, (0, 0)), (BOB, (0, 1)), (BOB, (0, 2)), (BOB, (0, 3))]),
-			Error::<Runtime>::ExceedMaximumBatchTransfer
+			Nft::set_royalty_recipient(Origin::signed(BOB), CLASS_ID, BOB),
+			Error::<Runtime>::NoPermission
+		);
+		assert_eq!(
+			Nft::get_royalty_info(&CLASS_ID),
+			Ok((Perbill::from_percent(0u32), ALICE))
 		);
+
+		assert_ok!(Nft::set_royalty_recipient(origin, CLASS_ID, BOB));
+
+		assert_eq!(Nft::get_royalty_info(&CLASS_ID), Ok((Perbill::from_percent(0u32), BOB)));
+		let event = mock::Event::Nft(crate::Event::RoyaltyRecipientUpdated(CLASS_ID, BOB));
+		assert_eq!(last_event(), event);
 	})
 }
 
 #[test]
-fn transfer_batch_should_fail() {
+fn upgrade_class_data_should_keep_global_royalty_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin);
+		// class stored in the layout before the royalty fee was set per class
+		let class_info = OrmlNft::classes(CLASS_ID).unwrap();
+		let old_class_info: OldClassInfoOf<Runtime> = ClassInfo {
+			metadata: class_info.metadata,
+			total_issuance: class_info.total_issuance,
+			owner: class_info.owner,
+			data: OldNftClassData {
+				deposit: class_info.data.deposit,
+				attributes: class_info.data.attributes,
+				token_type: class_info.data.token_type,
+				collection_type: class_info.data.collection_type,
+			},
+		};
+		frame_support::storage::unhashed::put(&orml_nft::Classes::<Runtime>::hashed_key_for(CLASS_ID), &old_class_info);
+
+		Nft::upgrade_class_data_v1();
+
+		assert_eq!(
+			Nft::get_royalty_info(&CLASS_ID),
+			Ok((Perbill::from_rational(10u32, 10_000), class_id_account()))
+		);
+	})
+}
+
+#[test]
+fn withdraw_class_fund_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		let origin = Origin::signed(ALICE);
 		init_test_nft(origin.clone());
-		assert_ok!(Nft::create_class(
-			origin.clone(),
-			vec![1],
-			test_attributes(1),
-			COLLECTION_ID,
-			TokenType::Transferable,
-			CollectionType::Collectable,
-			Perbill::from_percent(0u32)
+
+		assert_noop!(
+			Nft::withdraw_class_fund(origin.clone(), CLASS_ID),
+			Error::<Runtime>::ClassFundIsEmpty
+		);
+
+		// royalty fees paid into the class fund
+		assert_ok!(<Runtime as Config>::Currency::transfer(
+			&ALICE,
+			&class_id_account(),
+			20,
+			ExistenceRequirement::KeepAlive
 		));
-		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 1));
 		assert_noop!(
-			Nft::transfer_batch(origin.clone(), vec![(BOB, (0, 3)), (BOB, (0, 4))]),
-			Error::<Runtime>::AssetInfoNotFound
+			Nft::withdraw_class_fund(Origin::signed(BOB), CLASS_ID),
+			Error::<Runtime>::NoPermission
 		);
+
+		let alice_balance = free_native_balance(ALICE);
+		assert_ok!(Nft::withdraw_class_fund(origin, CLASS_ID));
+
+		assert_eq!(free_native_balance(ALICE), alice_balance + 20);
+		// deposits stay reserved in the class fund
+		assert_eq!(reserved_balance(&class_id_account()), 10);
+		let event = mock::Event::Nft(crate::Event::ClassFundWithdrawn(CLASS_ID, ALICE, 20));
+		assert_eq!(last_event(), event);
 	})
 }
 
 #[test]
-fn do_create_group_collection_should_work() {
+fn approve_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(Nft::do_create_group_collection(vec![1], vec![1]));
-		let collection_data = NftGroupCollectionData {
-			name: vec![1],
-			properties: vec![1],
-		};
-		assert_eq!(Nft::get_group_collection(0), Some(collection_data));
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::approve(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), Some(BOB)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::approve(origin.clone(), (CLASS_ID, TOKEN_ID), Some(ALICE)),
+			Error::<Runtime>::ApproveToCaller
+		);
+
+		assert_ok!(Nft::approve(origin.clone(), (CLASS_ID, TOKEN_ID), Some(BOB)));
+		assert_eq!(Nft::get_approved((CLASS_ID, TOKEN_ID)), Some(BOB));
+		let event = mock::Event::Nft(crate::Event::NftApproved((CLASS_ID, TOKEN_ID), ALICE, Some(BOB)));
+		assert_eq!(last_event(), event);
+
+		assert_ok!(Nft::approve(origin, (CLASS_ID, TOKEN_ID), None));
+		assert_eq!(Nft::get_approved((CLASS_ID, TOKEN_ID)), None);
 	})
 }
 
 #[test]
-fn do_transfer_should_work() {
-	let origin = Origin::signed(ALICE);
+fn transfer_from_should_work_for_approved_account() {
 	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
 		init_test_nft(origin.clone());
-		assert_ok!(Nft::do_transfer(&ALICE, &BOB, (0, 0)));
-		assert_eq!(OrmlNft::tokens_by_owner((BOB, 0, 0)), ());
+
+		assert_noop!(
+			Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(Nft::approve(origin, (CLASS_ID, TOKEN_ID), Some(BOB)));
+		assert_ok!(Nft::transfer_from(Origin::signed(BOB), ALICE, 3, (CLASS_ID, TOKEN_ID)));
+
+		assert_eq!(Nft::check_nft_ownership(&3, &(CLASS_ID, TOKEN_ID)), Ok(true));
+		// approval is cleared on transfer
+		assert_eq!(Nft::get_approved((CLASS_ID, TOKEN_ID)), None);
+		let event = mock::Event::Nft(crate::Event::TransferedNft(ALICE, 3, TOKEN_ID, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(last_event(), event);
 	})
 }
 
 #[test]
-fn do_transfer_should_fail() {
-	let origin = Origin::signed(ALICE);
+fn transfer_from_should_work_for_operator() {
 	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
 		assert_noop!(
-			Nft::do_transfer(&ALICE, &BOB, (0, 0)),
-			Error::<Runtime>::ClassIdNotFound
+			Nft::set_approval_for_all(origin.clone(), ALICE, true),
+			Error::<Runtime>::ApproveToCaller
 		);
+		assert_ok!(Nft::set_approval_for_all(origin.clone(), BOB, true));
+		assert_eq!(Nft::get_operator_approval(ALICE, BOB), Some(()));
+		let event = mock::Event::Nft(crate::Event::ApprovalForAll(ALICE, BOB, true));
+		assert_eq!(last_event(), event);
+
+		// operator can approve on behalf of the owner
+		assert_ok!(Nft::approve(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), Some(3)));
+		assert_ok!(Nft::transfer_from(
+			Origin::signed(BOB),
+			ALICE,
+			BOB,
+			(CLASS_ID, TOKEN_ID)
+		));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, TOKEN_ID)), Ok(true));
+		assert_eq!(Nft::get_approved((CLASS_ID, TOKEN_ID)), None);
+
+		assert_ok!(Nft::set_approval_for_all(origin, BOB, false));
+		assert_eq!(Nft::get_operator_approval(ALICE, BOB), None);
+	})
+}
 
+#[test]
+fn transfer_nft_should_respect_approvals() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
 		init_test_nft(origin.clone());
 
-		assert_noop!(Nft::do_transfer(&BOB, &ALICE, (0, 0)), Error::<Runtime>::NoPermission);
+		assert_noop!(
+			<Nft as NFTTrait<AccountId, Balance>>::transfer_nft(&BOB, &BOB, &(CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
 
-		assert_ok!(Nft::create_class(
-			origin.clone(),
-			vec![1],
-			test_attributes(1),
-			COLLECTION_ID,
-			TokenType::BoundToAddress,
-			CollectionType::Collectable,
-			Perbill::from_percent(0u32)
+		assert_ok!(Nft::approve(origin, (CLASS_ID, TOKEN_ID), Some(BOB)));
+		assert_ok!(<Nft as NFTTrait<AccountId, Balance>>::transfer_nft(
+			&BOB,
+			&BOB,
+			&(CLASS_ID, TOKEN_ID)
 		));
-		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 1));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, TOKEN_ID)), Ok(true));
+	})
+}
+
+#[test]
+fn list_for_rent_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
 
 		assert_noop!(
-			Nft::do_transfer(&ALICE, &BOB, (0, 1)),
-			Error::<Runtime>::AssetInfoNotFound
+			Nft::list_for_rent(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 2, 10),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::list_for_rent(origin.clone(), (CLASS_ID, TOKEN_ID), 2, 0),
+			Error::<Runtime>::InvalidRentalDuration
+		);
+		assert_ok!(Nft::list_for_rent(origin.clone(), (CLASS_ID, TOKEN_ID), 2, 10));
+		assert_eq!(
+			Nft::get_rental_listing((CLASS_ID, TOKEN_ID)),
+			Some(RentalListing {
+				price_per_block: 2,
+				max_duration: 10
+			})
+		);
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::NftListedForRent((CLASS_ID, TOKEN_ID), ALICE, 2, 10))
+		);
+
+		assert_ok!(Nft::cancel_rent_listing(origin.clone(), (CLASS_ID, TOKEN_ID)));
+		assert_eq!(Nft::get_rental_listing((CLASS_ID, TOKEN_ID)), None);
+		assert_noop!(
+			Nft::cancel_rent_listing(origin, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::RentListingNotFound
 		);
 	})
 }
 
 #[test]
-fn do_check_nft_ownership_should_work() {
-	let origin = Origin::signed(ALICE);
+fn rent_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
 		init_test_nft(origin.clone());
-		assert_ok!(Nft::check_nft_ownership(&ALICE, &(CLASS_ID, TOKEN_ID)), true);
-		assert_ok!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, TOKEN_ID)), false);
+		assert_ok!(<Runtime as Config>::Currency::transfer(
+			&ALICE,
+			&BOB,
+			100,
+			ExistenceRequirement::KeepAlive
+		));
+
+		assert_noop!(
+			Nft::rent(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 5, 10),
+			Error::<Runtime>::RentListingNotFound
+		);
+		assert_ok!(Nft::list_for_rent(origin.clone(), (CLASS_ID, TOKEN_ID), 2, 10));
+		assert_noop!(
+			Nft::rent(origin.clone(), (CLASS_ID, TOKEN_ID), 5, 10),
+			Error::<Runtime>::RentOwnNft
+		);
+		assert_noop!(
+			Nft::rent(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 11, 22),
+			Error::<Runtime>::InvalidRentalDuration
+		);
+
+		// listing is repriced above the price the renter accepts
+		assert_ok!(Nft::list_for_rent(origin.clone(), (CLASS_ID, TOKEN_ID), 3, 10));
+		assert_noop!(
+			Nft::rent(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 5, 10),
+			Error::<Runtime>::RentPriceExceedsMaxPrice
+		);
+		assert_ok!(Nft::list_for_rent(origin.clone(), (CLASS_ID, TOKEN_ID), 2, 10));
+
+		let alice_balance = free_native_balance(ALICE);
+		assert_ok!(Nft::rent(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 5, 10));
+		assert_eq!(free_native_balance(ALICE), alice_balance + 10);
+		assert_eq!(free_native_balance(BOB), 90);
+		assert_eq!(
+			Nft::get_rental((CLASS_ID, TOKEN_ID)),
+			Some(RentalInfo {
+				renter: BOB,
+				expires_at: 6
+			})
+		);
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::NftRented((CLASS_ID, TOKEN_ID), BOB, 6, 10))
+		);
+
+		// renter is the user of the token, the owner keeps the ownership
+		assert_eq!(
+			<Nft as NFTTrait<AccountId, Balance>>::get_user_of(&(CLASS_ID, TOKEN_ID)),
+			Ok(BOB)
+		);
+		assert_eq!(Nft::check_nft_ownership(&ALICE, &(CLASS_ID, TOKEN_ID)), Ok(true));
+		assert_noop!(
+			Nft::rent(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 5, 10),
+			Error::<Runtime>::NftIsRented
+		);
+
+		// rental expires at the expiry block
+		System::set_block_number(6);
+		assert_eq!(
+			<Nft as NFTTrait<AccountId, Balance>>::get_user_of(&(CLASS_ID, TOKEN_ID)),
+			Ok(ALICE)
+		);
 	})
 }
 
 #[test]
-fn do_check_nft_ownership_should_fail() {
+fn rented_nft_should_not_be_moved_until_rental_expires() {
 	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(<Runtime as Config>::Currency::transfer(
+			&ALICE,
+			&BOB,
+			100,
+			ExistenceRequirement::KeepAlive
+		));
+		assert_ok!(Nft::list_for_rent(origin.clone(), (CLASS_ID, TOKEN_ID), 2, 10));
+		assert_ok!(Nft::rent(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 5, 10));
+
 		assert_noop!(
-			Nft::check_nft_ownership(&ALICE, &(CLASS_ID, TOKEN_ID)),
-			Error::<Runtime>::AssetInfoNotFound
+			Nft::transfer(origin.clone(), BOB, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NftIsRented
+		);
+		assert_noop!(
+			Nft::transfer_batch(origin.clone(), vec![(BOB, (CLASS_ID, TOKEN_ID))]),
+			Error::<Runtime>::NftIsRented
+		);
+		assert_noop!(
+			Nft::burn(origin.clone(), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NftIsRented
+		);
+		assert_eq!(
+			<Nft as NFTTrait<AccountId, Balance>>::is_transferable(&(CLASS_ID, TOKEN_ID)),
+			Ok(false)
+		);
+
+		System::set_block_number(6);
+		assert_ok!(Nft::transfer(origin, BOB, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(Nft::get_rental((CLASS_ID, TOKEN_ID)), None);
+		assert_eq!(Nft::get_rental_listing((CLASS_ID, TOKEN_ID)), None);
+		assert_eq!(
+			<Nft as NFTTrait<AccountId, Balance>>::get_user_of(&(CLASS_ID, TOKEN_ID)),
+			Ok(BOB)
+		);
+	})
+}
+
+#[test]
+fn bound_to_address_nft_should_not_be_transferred() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_bound_to_address_nft(origin.clone());
+
+		assert_noop!(
+			Nft::transfer(origin.clone(), BOB, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NonTransferable
+		);
+		assert_noop!(
+			Nft::transfer_batch(origin.clone(), vec![(BOB, (CLASS_ID, TOKEN_ID))]),
+			Error::<Runtime>::NonTransferable
+		);
+		assert_noop!(
+			Nft::approve(origin.clone(), (CLASS_ID, TOKEN_ID), Some(BOB)),
+			Error::<Runtime>::NonTransferable
+		);
+		assert_ok!(Nft::set_approval_for_all(origin, BOB, true));
+		assert_noop!(
+			Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NonTransferable
+		);
+		assert_noop!(
+			<Nft as NFTTrait<AccountId, Balance>>::transfer_nft(&ALICE, &BOB, &(CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NonTransferable
+		);
+		assert_eq!(Nft::is_transferable(&(CLASS_ID, TOKEN_ID)), Ok(false));
+	})
+}
+
+#[test]
+fn bound_to_address_nft_should_be_recovered_by_governance() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_bound_to_address_nft(origin.clone());
+
+		assert_noop!(Nft::force_transfer(origin, ALICE, BOB, (CLASS_ID, TOKEN_ID)), BadOrigin);
+		assert_ok!(Nft::force_transfer(Origin::root(), ALICE, BOB, (CLASS_ID, TOKEN_ID)));
+
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, TOKEN_ID)), Ok(true));
+	})
+}
+
+#[test]
+fn bound_to_address_nft_should_be_burned_by_holder() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_bound_to_address_nft(origin.clone());
+
+		assert_noop!(
+			Nft::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			orml_nft::Error::<Runtime>::NoPermission
+		);
+		assert_ok!(Nft::burn(origin, (CLASS_ID, TOKEN_ID)));
+
+		assert_eq!(OrmlNft::tokens(CLASS_ID, TOKEN_ID), None);
+	})
+}
+
+#[test]
+fn destroy_class_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::destroy_class(Origin::signed(BOB), CLASS_ID),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::destroy_class(origin.clone(), CLASS_ID),
+			Error::<Runtime>::ClassIsNotEmpty
+		);
+		assert_noop!(Nft::destroy_class(origin, 1), Error::<Runtime>::ClassIdNotFound);
+	})
+}
+
+#[test]
+fn transfer_batch_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 4));
+		assert_ok!(Nft::transfer_batch(origin, vec![(BOB, (1, 0)), (BOB, (1, 1))]));
+		let event = mock::Event::Nft(crate::Event::TransferedNft(ALICE, BOB, 1, (1, 1)));
+		assert_eq!(last_event(), event);
+	})
+}
+
+#[test]
+fn transfer_batch_exceed_length_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 4));
+		assert_noop!(
+			Nft::transfer_batch(origin, vec![(BOB, (0, 0)), (BOB, (0, 1)), (BOB, (0, 2)), (BOB, (0, 3))]),
+			Error::<Runtime>::ExceedMaximumBatchTransfer
+		);
+	})
+}
+
+#[test]
+fn transfer_batch_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 1));
+		assert_noop!(
+			Nft::transfer_batch(origin.clone(), vec![(BOB, (0, 3)), (BOB, (0, 4))]),
+			Error::<Runtime>::AssetInfoNotFound
+		);
+	})
+}
+
+#[test]
+fn do_create_group_collection_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Nft::do_create_group_collection(vec![1], vec![1]));
+		let collection_data = NftGroupCollectionData {
+			name: vec![1],
+			properties: vec![1],
+		};
+		assert_eq!(Nft::get_group_collection(0), Some(collection_data));
+	})
+}
+
+#[test]
+fn do_transfer_should_work() {
+	let origin = Origin::signed(ALICE);
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::do_transfer(&ALICE, &BOB, (0, 0)));
+		assert_eq!(OrmlNft::tokens_by_owner((BOB, 0, 0)), ());
+	})
+}
+
+#[test]
+fn do_transfer_should_fail() {
+	let origin = Origin::signed(ALICE);
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Nft::do_transfer(&ALICE, &BOB, (0, 0)),
+			Error::<Runtime>::ClassIdNotFound
+		);
+
+		init_test_nft(origin.clone());
+
+		assert_noop!(Nft::do_transfer(&BOB, &ALICE, (0, 0)), Error::<Runtime>::NoPermission);
+
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::BoundToAddress,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 1));
+
+		assert_noop!(
+			Nft::do_transfer(&ALICE, &BOB, (0, 1)),
+			Error::<Runtime>::AssetInfoNotFound
+		);
+	})
+}
+
+#[test]
+fn do_check_nft_ownership_should_work() {
+	let origin = Origin::signed(ALICE);
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::check_nft_ownership(&ALICE, &(CLASS_ID, TOKEN_ID)), true);
+		assert_ok!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, TOKEN_ID)), false);
+	})
+}
+
+#[test]
+fn do_check_nft_ownership_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Nft::check_nft_ownership(&ALICE, &(CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::AssetInfoNotFound
+		);
+	})
+}
+
+fn init_avatar_and_wearables(owner: Origin) {
+	init_test_nft(owner.clone());
+	assert_ok!(Nft::create_class(
+		owner.clone(),
+		vec![1],
+		test_attributes(1),
+		COLLECTION_ID,
+		TokenType::Transferable,
+		CollectionType::Wearable,
+		Perbill::from_percent(0u32)
+	));
+	assert_ok!(Nft::mint(owner.clone(), 1, vec![1], test_attributes(1), 2));
+}
+
+#[test]
+fn equip_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_avatar_and_wearables(origin.clone());
+
+		assert_ok!(Nft::set_wearable_slot(origin.clone(), 1, b"head".to_vec()));
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::WearableSlotSet(1, b"head".to_vec()))
+		);
+
+		assert_ok!(Nft::equip(origin.clone(), (1, 0), (0, 0)));
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::NftEquipped((1, 0), (0, 0), b"head".to_vec()))
+		);
+		assert_eq!(Nft::get_equipped_item((0, 0), b"head".to_vec()), Some((1, 0)));
+		assert_eq!(Nft::get_equipped_to((1, 0)), Some(((0, 0), b"head".to_vec())));
+		assert_eq!(
+			Nft::check_nft_ownership(&Nft::get_avatar_account(&(0, 0)), &(1, 0)),
+			Ok(true)
+		);
+		assert_eq!(Nft::is_transferable(&(1, 0)), Ok(false));
+
+		// wearable travels with the avatar
+		assert_ok!(Nft::transfer(origin, BOB, (0, 0)));
+		assert_eq!(Nft::get_equipped_item((0, 0), b"head".to_vec()), Some((1, 0)));
+
+		assert_ok!(Nft::unequip(Origin::signed(BOB), (1, 0)));
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::NftUnequipped((1, 0), (0, 0)))
+		);
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(1, 0)), Ok(true));
+		assert_eq!(Nft::get_equipped_item((0, 0), b"head".to_vec()), None);
+		assert_eq!(Nft::get_equipped_to((1, 0)), None);
+	})
+}
+
+#[test]
+fn equip_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_avatar_and_wearables(origin.clone());
+
+		assert_noop!(
+			Nft::equip(origin.clone(), (1, 0), (0, 0)),
+			Error::<Runtime>::WearableSlotNotSet
+		);
+		assert_noop!(
+			Nft::set_wearable_slot(origin.clone(), 0, b"head".to_vec()),
+			Error::<Runtime>::OnlyForWearableCollectionType
+		);
+		assert_noop!(
+			Nft::set_wearable_slot(Origin::signed(BOB), 1, b"head".to_vec()),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::set_wearable_slot(origin.clone(), 1, vec![]),
+			Error::<Runtime>::InvalidWearableSlot
+		);
+
+		assert_ok!(Nft::set_wearable_slot(origin.clone(), 1, b"head".to_vec()));
+		assert_noop!(
+			Nft::equip(origin.clone(), (0, 0), (1, 0)),
+			Error::<Runtime>::OnlyForWearableCollectionType
+		);
+		assert_noop!(
+			Nft::equip(origin.clone(), (1, 0), (1, 1)),
+			Error::<Runtime>::InvalidAvatar
+		);
+		assert_noop!(
+			Nft::equip(Origin::signed(BOB), (1, 0), (0, 0)),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(Nft::equip(origin.clone(), (1, 0), (0, 0)));
+		assert_noop!(
+			Nft::equip(origin.clone(), (1, 1), (0, 0)),
+			Error::<Runtime>::WearableSlotOccupied
+		);
+		assert_noop!(
+			Nft::transfer(origin.clone(), BOB, (1, 0)),
+			Error::<Runtime>::NftIsEquipped
+		);
+		assert_noop!(
+			Nft::burn(origin.clone(), (0, 0)),
+			Error::<Runtime>::AvatarHasEquippedItems
+		);
+		assert_noop!(
+			Nft::unequip(Origin::signed(BOB), (1, 0)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(Nft::unequip(origin, (1, 1)), Error::<Runtime>::NftIsNotEquipped);
+	})
+}
+
+fn executable_payload(call: mock::Call, burn_on_use: bool, cooldown: u64) -> Vec<u8> {
+	ExecutableNftData {
+		call,
+		burn_on_use,
+		cooldown,
+	}
+	.encode()
+}
+
+fn transfer_to_bob_call() -> mock::Call {
+	mock::Call::Balances(pallet_balances::Call::transfer { dest: BOB, value: 10 })
+}
+
+fn init_executable_nft(owner: Origin, payload: Vec<u8>) {
+	assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1],));
+	assert_ok!(Nft::create_class(
+		owner.clone(),
+		vec![1],
+		test_attributes(1),
+		COLLECTION_ID,
+		TokenType::Transferable,
+		CollectionType::Executable(payload),
+		Perbill::from_percent(0u32)
+	));
+	assert_ok!(Nft::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+}
+
+#[test]
+fn use_nft_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_executable_nft(origin.clone(), executable_payload(transfer_to_bob_call(), false, 10));
+
+		assert_ok!(Nft::use_nft(origin.clone(), (CLASS_ID, TOKEN_ID)));
+		assert_eq!(free_native_balance(BOB), 10);
+		assert_eq!(Nft::get_last_used((CLASS_ID, TOKEN_ID)), Some(1));
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::NftUsed((CLASS_ID, TOKEN_ID), ALICE))
+		);
+
+		assert_noop!(
+			Nft::use_nft(origin.clone(), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NftIsOnCooldown
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Nft::use_nft(origin, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(free_native_balance(BOB), 20);
+		assert_eq!(Nft::get_last_used((CLASS_ID, TOKEN_ID)), Some(11));
+	})
+}
+
+#[test]
+fn use_nft_should_burn_on_use() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_executable_nft(origin.clone(), executable_payload(transfer_to_bob_call(), true, 0));
+
+		assert_ok!(Nft::use_nft(origin.clone(), (CLASS_ID, TOKEN_ID)));
+		assert_eq!(free_native_balance(BOB), 10);
+		assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID), None);
+		assert_eq!(Nft::get_last_used((CLASS_ID, TOKEN_ID)), None);
+
+		assert_noop!(
+			Nft::use_nft(origin, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::AssetInfoNotFound
+		);
+	})
+}
+
+#[test]
+fn use_nft_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::use_nft(origin.clone(), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::OnlyForExecutableCollectionType
+		);
+		assert_noop!(
+			Nft::create_class(
+				origin.clone(),
+				vec![1],
+				test_attributes(1),
+				COLLECTION_ID,
+				TokenType::Transferable,
+				CollectionType::Executable(vec![1, 2, 3]),
+				Perbill::from_percent(0u32)
+			),
+			Error::<Runtime>::InvalidExecutablePayload
+		);
+		let remark_call = mock::Call::System(frame_system::Call::remark { remark: vec![1] });
+		assert_noop!(
+			Nft::create_class(
+				origin.clone(),
+				vec![1],
+				test_attributes(1),
+				COLLECTION_ID,
+				TokenType::Transferable,
+				CollectionType::Executable(executable_payload(remark_call, false, 0)),
+				Perbill::from_percent(0u32)
+			),
+			Error::<Runtime>::ExecutableCallNotAllowed
+		);
+
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Executable(executable_payload(transfer_to_bob_call(), false, 0)),
+			Perbill::from_percent(0u32)
+		));
+		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 1));
+		assert_noop!(
+			Nft::use_nft(Origin::signed(BOB), (1, 0)),
+			Error::<Runtime>::NoPermission
+		);
+	})
+}
+
+#[test]
+fn set_token_attribute_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		let class_fund_reserved = reserved_balance(&class_id_account());
+		let class_fund_free = free_native_balance(class_id_account());
+		let alice_balance = free_native_balance(ALICE);
+
+		assert_ok!(Nft::set_token_attribute(origin.clone(), (0, 0), vec![9], vec![9, 9]));
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::TokenAttributeSet((0, 0), vec![9], vec![9, 9]))
+		);
+		let token_info = NftModule::<Runtime>::tokens(0, 0).unwrap();
+		assert_eq!(token_info.data.attributes.get(&vec![9]), Some(&vec![9, 9]));
+		// deposit increases by 3 bytes
+		assert_eq!(reserved_balance(&class_id_account()), class_fund_reserved + 3);
+		assert_eq!(free_native_balance(ALICE), alice_balance - 3);
+
+		assert_ok!(Nft::remove_token_attribute(origin.clone(), (0, 0), vec![9]));
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::TokenAttributeRemoved((0, 0), vec![9]))
+		);
+		let token_info = NftModule::<Runtime>::tokens(0, 0).unwrap();
+		assert_eq!(token_info.data.attributes, test_attributes(1));
+		// lowered deposit is refunded to the token owner
+		assert_eq!(reserved_balance(&class_id_account()), class_fund_reserved);
+		assert_eq!(free_native_balance(class_id_account()), class_fund_free);
+		assert_eq!(free_native_balance(ALICE), alice_balance);
+
+		assert_noop!(
+			Nft::remove_token_attribute(origin, (0, 0), vec![9]),
+			Error::<Runtime>::AttributeNotFound
+		);
+	})
+}
+
+#[test]
+fn set_token_attribute_should_respect_update_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::transfer(origin.clone(), BOB, (0, 0)));
+
+		// class owner is the default authority
+		assert_noop!(
+			Nft::set_token_attribute(Origin::signed(BOB), (0, 0), vec![9], vec![9]),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_noop!(
+			Nft::set_attribute_update_authority(Origin::signed(BOB), 0, AttributeUpdateAuthority::TokenOwner),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(Nft::set_attribute_update_authority(
+			origin.clone(),
+			0,
+			AttributeUpdateAuthority::TokenOwner
+		));
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::AttributeUpdateAuthoritySet(
+				0,
+				AttributeUpdateAuthority::TokenOwner
+			))
+		);
+		assert_noop!(
+			Nft::remove_token_attribute(origin.clone(), (0, 0), vec![1, 6]),
+			Error::<Runtime>::NoPermission
+		);
+		// deposit of the removed attribute is refunded to the token owner
+		let class_fund_free = free_native_balance(class_id_account());
+		assert_ok!(Nft::remove_token_attribute(Origin::signed(BOB), (0, 0), vec![1, 6]));
+		assert_eq!(free_native_balance(BOB), 4);
+		assert_eq!(free_native_balance(class_id_account()), class_fund_free);
+
+		assert_ok!(Nft::set_attribute_update_authority(
+			origin.clone(),
+			0,
+			AttributeUpdateAuthority::Frozen
+		));
+		assert_noop!(
+			Nft::set_token_attribute(Origin::signed(BOB), (0, 0), vec![9], vec![9]),
+			Error::<Runtime>::AttributesAreFrozen
+		);
+		assert_noop!(
+			Nft::set_attribute_update_authority(origin, 0, AttributeUpdateAuthority::ClassOwner),
+			Error::<Runtime>::AttributesAreFrozen
+		);
+	})
+}
+
+fn test_mint_settings(
+) -> MintSettings<<Runtime as orml_nft::Config>::TokenId, Balance, <Runtime as frame_system::Config>::BlockNumber> {
+	MintSettings {
+		max_supply: Some(3),
+		start_block: Some(2),
+		end_block: Some(5),
+		currency_id: FungibleTokenId::NativeToken(0),
+		price: 10,
+		per_account_limit: Some(1),
+		allowlist_only: false,
+	}
+}
+
+#[test]
+fn set_mint_settings_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::set_mint_settings(Origin::signed(BOB), CLASS_ID, Some(test_mint_settings())),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::set_mint_settings(
+				origin.clone(),
+				CLASS_ID,
+				Some(MintSettings {
+					max_supply: Some(0),
+					..test_mint_settings()
+				})
+			),
+			Error::<Runtime>::InvalidMaxSupply
+		);
+
+		assert_ok!(Nft::set_mint_settings(
+			origin.clone(),
+			CLASS_ID,
+			Some(test_mint_settings())
+		));
+		assert_eq!(Nft::get_mint_settings(CLASS_ID), Some(test_mint_settings()));
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::MintSettingsUpdated(CLASS_ID, Some(test_mint_settings())))
+		);
+		assert_eq!(Nft::get_max_supply(CLASS_ID), Some(3));
+
+		// max supply can only be lowered once set
+		assert_noop!(
+			Nft::set_mint_settings(
+				origin.clone(),
+				CLASS_ID,
+				Some(MintSettings {
+					max_supply: Some(4),
+					..test_mint_settings()
+				})
+			),
+			Error::<Runtime>::MaxSupplyCanOnlyBeLowered
+		);
+		assert_noop!(
+			Nft::set_mint_settings(
+				origin.clone(),
+				CLASS_ID,
+				Some(MintSettings {
+					max_supply: None,
+					..test_mint_settings()
+				})
+			),
+			Error::<Runtime>::MaxSupplyCanOnlyBeLowered
+		);
+		assert_ok!(Nft::set_mint_settings(
+			origin.clone(),
+			CLASS_ID,
+			Some(MintSettings {
+				max_supply: Some(2),
+				..test_mint_settings()
+			})
+		));
+		assert_eq!(Nft::get_max_supply(CLASS_ID), Some(2));
+
+		// closing the public mint keeps the max supply
+		assert_ok!(Nft::set_mint_settings(origin, CLASS_ID, None));
+		assert_eq!(Nft::get_mint_settings(CLASS_ID), None);
+		assert_eq!(Nft::get_max_supply(CLASS_ID), Some(2));
+	})
+}
+
+#[test]
+fn mint_from_class_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(<Runtime as Config>::Currency::transfer(
+			&ALICE,
+			&BOB,
+			100,
+			ExistenceRequirement::KeepAlive
+		));
+
+		assert_noop!(
+			Nft::mint_from_class(Origin::signed(BOB), CLASS_ID, 1),
+			Error::<Runtime>::PublicMintNotEnabled
+		);
+		assert_ok!(Nft::set_mint_settings(
+			origin.clone(),
+			CLASS_ID,
+			Some(test_mint_settings())
+		));
+		assert_noop!(
+			Nft::mint_from_class(Origin::signed(BOB), CLASS_ID, 1),
+			Error::<Runtime>::PublicMintNotStarted
+		);
+
+		System::set_block_number(2);
+		let class_fund_balance = free_native_balance(class_id_account());
+		assert_ok!(Nft::mint_from_class(Origin::signed(BOB), CLASS_ID, 1));
+
+		// minter pays the price into the class fund and the token deposit
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, 1)), Ok(true));
+		assert_eq!(free_native_balance(class_id_account()), class_fund_balance + 10);
+		assert_eq!(free_native_balance(BOB), 85);
+		assert_eq!(Nft::get_minted_count(CLASS_ID, BOB), 1);
+		assert_noop!(
+			Nft::mint_from_class(Origin::signed(BOB), CLASS_ID, 1),
+			Error::<Runtime>::ExceedPerAccountMintLimit
+		);
+
+		// max supply applies to the class owner too
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+		assert_noop!(
+			Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1),
+			Error::<Runtime>::ExceedMaxSupply
+		);
+		// burnt tokens still count towards the max supply
+		assert_ok!(Nft::burn(origin.clone(), (CLASS_ID, 2)));
+		assert_noop!(
+			Nft::mint(origin, CLASS_ID, vec![1], test_attributes(1), 1),
+			Error::<Runtime>::ExceedMaxSupply
+		);
+
+		System::set_block_number(5);
+		assert_noop!(
+			Nft::mint_from_class(Origin::signed(BOB), CLASS_ID, 1),
+			Error::<Runtime>::PublicMintEnded
+		);
+	})
+}
+
+#[test]
+fn mint_from_class_should_respect_allowlist() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(<Runtime as Config>::Currency::transfer(
+			&ALICE,
+			&BOB,
+			100,
+			ExistenceRequirement::KeepAlive
+		));
+		assert_ok!(Nft::set_mint_settings(
+			origin.clone(),
+			CLASS_ID,
+			Some(MintSettings {
+				start_block: None,
+				allowlist_only: true,
+				..test_mint_settings()
+			})
+		));
+
+		assert_noop!(
+			Nft::mint_from_class(Origin::signed(BOB), CLASS_ID, 1),
+			Error::<Runtime>::NotOnMintAllowlist
+		);
+		assert_noop!(
+			Nft::set_mint_allowlist(Origin::signed(BOB), CLASS_ID, vec![BOB], true),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(Nft::set_mint_allowlist(origin.clone(), CLASS_ID, vec![BOB], true));
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::MintAllowlistUpdated(CLASS_ID, vec![BOB], true))
+		);
+		assert_ok!(Nft::mint_from_class(Origin::signed(BOB), CLASS_ID, 1));
+
+		assert_ok!(Nft::set_mint_allowlist(origin, CLASS_ID, vec![BOB], false));
+		assert_eq!(Nft::get_mint_allowlist(CLASS_ID, BOB), None);
+	})
+}
+
+#[test]
+fn withdraw_class_fund_token_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::withdraw_class_fund_token(origin.clone(), CLASS_ID, mining_resource_id()),
+			Error::<Runtime>::ClassFundIsEmpty
+		);
+
+		// mint proceeds paid in a fungible token
+		assert_ok!(Currencies::deposit(mining_resource_id(), &class_id_account(), 50));
+		assert_noop!(
+			Nft::withdraw_class_fund_token(Origin::signed(BOB), CLASS_ID, mining_resource_id()),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(Nft::withdraw_class_fund_token(origin, CLASS_ID, mining_resource_id()));
+
+		assert_eq!(Currencies::free_balance(mining_resource_id(), &ALICE), 50);
+		assert_eq!(
+			last_event(),
+			mock::Event::Nft(crate::Event::ClassFundTokenWithdrawn(
+				CLASS_ID,
+				ALICE,
+				mining_resource_id(),
+				50
+			))
 		);
 	})
 }
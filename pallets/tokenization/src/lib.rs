@@ -32,8 +32,9 @@ use frame_system::pallet_prelude::*;
 use orml_traits::{LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency};
 use scale_info::TypeInfo;
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
 	traits::{AccountIdConversion, AtLeast32Bit, One, StaticLookup, Zero},
-	DispatchError,
+	DispatchError, RuntimeDebug,
 };
 use sp_runtime::{FixedPointNumber, SaturatedConversion};
 use sp_std::convert::TryInto;
@@ -44,7 +45,7 @@ use core_primitives::*;
 pub use pallet::*;
 pub use pallet::*;
 use primitives::dex::Price;
-use primitives::{Balance, FungibleTokenId, MetaverseId, VestingSchedule};
+use primitives::{Balance, ClassId, FungibleTokenId, MetaverseId, TokenId, VestingSchedule};
 
 #[cfg(test)]
 mod mock;
@@ -64,6 +65,30 @@ pub struct Token<Balance> {
 	pub total_supply: Balance,
 }
 
+/// Buyout bid on a fractionalized NFT, the highest bidder wins the NFT when the buyout ends
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Buyout<AccountId, BlockNumber> {
+	pub bidder: AccountId,
+	pub bid: Balance,
+	pub end: BlockNumber,
+}
+
+/// NFT locked in a vault account and split into a fungible token supply
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct FractionalVault<AccountId, BlockNumber> {
+	/// Account that fractionalized the NFT
+	pub curator: AccountId,
+	/// Vault account holding the NFT and the buyout bid
+	pub vault: AccountId,
+	/// Fungible token representing the fractions
+	pub currency_id: FungibleTokenId,
+	/// Minimum buyout bid, the NFT can only be bought out when set
+	pub buyout_reserve_price: Option<Balance>,
+	pub buyout: Option<Buyout<AccountId, BlockNumber>>,
+	/// Buyout proceeds not yet claimed by fraction holders, set once the buyout is settled
+	pub buyout_proceeds: Option<Balance>,
+}
+
 /// The maximum number of vesting schedules an account can have.
 pub const MAX_VESTINGS: usize = 20;
 
@@ -71,7 +96,7 @@ pub const VESTING_LOCK_ID: LockIdentifier = *b"bcstvest";
 
 #[frame_support::pallet]
 pub mod pallet {
-	use primitives::{FungibleTokenId, TokenId, VestingSchedule};
+	use primitives::{ClassId, FungibleTokenId, TokenId, VestingSchedule};
 
 	use super::*;
 
@@ -103,6 +128,11 @@ pub mod pallet {
 		type MinVestedTransfer: Get<Balance>;
 		/// Required origin for vested transfer.
 		type VestedTransferOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+		/// NFT handler to lock fractionalized NFTs in their vault.
+		type NFTHandler: NFTTrait<Self::AccountId, Balance, ClassId = ClassId, TokenId = TokenId>;
+		#[pallet::constant]
+		/// The number of blocks a buyout of a fractionalized NFT stays open for higher bids.
+		type BuyoutPeriod: Get<Self::BlockNumber>;
 	}
 
 	#[pallet::storage]
@@ -124,6 +154,13 @@ pub mod pallet {
 	pub(super) type MetaverseTreasury<T: Config> =
 		StorageMap<_, Blake2_128Concat, MetaverseId, MetaverseFund<T::AccountId, Balance>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn get_fractional_vault)]
+	/// Vaults of fractionalized NFTs.
+	/// (class_id, token_id) -> Vault details [returns FractionalVault struct]
+	pub(super) type FractionalVaults<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassId, TokenId), FractionalVault<T::AccountId, T::BlockNumber>, OptionQuery>;
+
 	/// Vesting schedules of an account.
 	#[pallet::storage]
 	#[pallet::getter(fn vesting_schedules)]
@@ -166,6 +203,30 @@ pub mod pallet {
 		InvalidVestingSchedule,
 		/// Invalid request
 		InvalidRequest,
+		/// No permission to fractionalize the NFT
+		NoPermission,
+		/// NFT is not transferable
+		NftIsNotTransferable,
+		/// NFT is already fractionalized
+		NftAlreadyFractionalized,
+		/// NFT is not fractionalized
+		FractionalVaultNotFound,
+		/// The whole fraction supply is required to redeem the NFT
+		InsufficientFractionsToRedeem,
+		/// Fractionalized NFT has no buyout reserve price
+		BuyoutNotEnabled,
+		/// Buyout of the fractionalized NFT is in progress
+		BuyoutInProgress,
+		/// Buyout bid is below the reserve price or the highest bid
+		BuyoutBidTooLow,
+		/// Buyout has ended
+		BuyoutHasEnded,
+		/// Buyout has no bid or has not ended yet
+		BuyoutNotEnded,
+		/// Buyout is already settled
+		BuyoutAlreadySettled,
+		/// Buyout is not settled
+		BuyoutNotSettled,
 	}
 
 	#[pallet::call]
@@ -258,6 +319,199 @@ pub mod pallet {
 			Self::deposit_event(Event::VestingSchedulesUpdated(currency_id, account));
 			Ok(().into())
 		}
+
+		/// Lock an NFT or estate token in a vault and issue `total_supply` fractions of a new
+		/// fungible token to the owner. A buyout of the NFT can be enabled with a reserve price.
+		#[pallet::weight(100_000)]
+		#[transactional]
+		pub fn fractionalize_nft(
+			origin: OriginFor<T>,
+			asset_id: (ClassId, TokenId),
+			ticker: Ticker,
+			total_supply: Balance,
+			buyout_reserve_price: Option<Balance>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::NFTHandler::check_ownership(&who, &asset_id)?,
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				T::NFTHandler::is_transferable(&asset_id)?,
+				Error::<T>::NftIsNotTransferable
+			);
+			ensure!(
+				!FractionalVaults::<T>::contains_key(&asset_id),
+				Error::<T>::NftAlreadyFractionalized
+			);
+			ensure!(!total_supply.is_zero(), Error::<T>::AmountZero);
+			ensure!(
+				buyout_reserve_price.map_or(true, |price| !price.is_zero()),
+				Error::<T>::AmountZero
+			);
+
+			let vault = Self::get_fractional_vault_id(asset_id);
+			T::NFTHandler::transfer_nft(&who, &vault, &asset_id)?;
+
+			let currency_id = Self::next_fungible_token_id()?;
+			FungibleTokens::<T>::insert(currency_id, Token { ticker, total_supply });
+			T::MetaverseMultiCurrency::deposit(currency_id, &who, total_supply)?;
+
+			FractionalVaults::<T>::insert(
+				asset_id,
+				FractionalVault {
+					curator: who.clone(),
+					vault,
+					currency_id,
+					buyout_reserve_price,
+					buyout: None,
+					buyout_proceeds: None,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::NftFractionalized(asset_id, who, currency_id, total_supply));
+			Ok(().into())
+		}
+
+		/// Burn the whole fraction supply and take the NFT out of its vault
+		#[pallet::weight(100_000)]
+		#[transactional]
+		pub fn redeem_nft(origin: OriginFor<T>, asset_id: (ClassId, TokenId)) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let fractional_vault = Self::get_fractional_vault(&asset_id).ok_or(Error::<T>::FractionalVaultNotFound)?;
+			ensure!(fractional_vault.buyout.is_none(), Error::<T>::BuyoutInProgress);
+
+			let total_issuance = T::MetaverseMultiCurrency::total_issuance(fractional_vault.currency_id);
+			ensure!(
+				T::MetaverseMultiCurrency::free_balance(fractional_vault.currency_id, &who) == total_issuance,
+				Error::<T>::InsufficientFractionsToRedeem
+			);
+
+			T::MetaverseMultiCurrency::withdraw(fractional_vault.currency_id, &who, total_issuance)?;
+			T::NFTHandler::transfer_nft(&fractional_vault.vault, &who, &asset_id)?;
+
+			FractionalVaults::<T>::remove(&asset_id);
+			FungibleTokens::<T>::remove(fractional_vault.currency_id);
+
+			Self::deposit_event(Event::<T>::FractionalizedNftRedeemed(asset_id, who));
+			Ok(().into())
+		}
+
+		/// Bid on the buyout of a fractionalized NFT. The first bid opens the buyout for
+		/// `BuyoutPeriod` blocks and every bid refunds the previous highest bidder.
+		#[pallet::weight(100_000)]
+		#[transactional]
+		pub fn bid_buyout(
+			origin: OriginFor<T>,
+			asset_id: (ClassId, TokenId),
+			bid: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			FractionalVaults::<T>::try_mutate(&asset_id, |maybe_vault| -> DispatchResultWithPostInfo {
+				let fractional_vault = maybe_vault.as_mut().ok_or(Error::<T>::FractionalVaultNotFound)?;
+				let reserve_price = fractional_vault
+					.buyout_reserve_price
+					.ok_or(Error::<T>::BuyoutNotEnabled)?;
+				let now = <frame_system::Pallet<T>>::block_number();
+
+				let end = match &fractional_vault.buyout {
+					Some(buyout) => {
+						ensure!(now < buyout.end, Error::<T>::BuyoutHasEnded);
+						ensure!(bid > buyout.bid, Error::<T>::BuyoutBidTooLow);
+						buyout.end
+					}
+					None => {
+						ensure!(bid >= reserve_price, Error::<T>::BuyoutBidTooLow);
+						now + T::BuyoutPeriod::get()
+					}
+				};
+
+				T::MetaverseMultiCurrency::transfer(
+					FungibleTokenId::NativeToken(0),
+					&who,
+					&fractional_vault.vault,
+					bid,
+				)?;
+				if let Some(previous) = &fractional_vault.buyout {
+					T::MetaverseMultiCurrency::transfer(
+						FungibleTokenId::NativeToken(0),
+						&fractional_vault.vault,
+						&previous.bidder,
+						previous.bid,
+					)?;
+				}
+
+				fractional_vault.buyout = Some(Buyout {
+					bidder: who.clone(),
+					bid,
+					end,
+				});
+
+				Self::deposit_event(Event::<T>::BuyoutBidPlaced(asset_id, who, bid, end));
+				Ok(().into())
+			})
+		}
+
+		/// Hand a fractionalized NFT to the winner of an ended buyout, fraction holders can
+		/// then claim their share of the winning bid
+		#[pallet::weight(100_000)]
+		#[transactional]
+		pub fn settle_buyout(origin: OriginFor<T>, asset_id: (ClassId, TokenId)) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			FractionalVaults::<T>::try_mutate(&asset_id, |maybe_vault| -> DispatchResultWithPostInfo {
+				let fractional_vault = maybe_vault.as_mut().ok_or(Error::<T>::FractionalVaultNotFound)?;
+				ensure!(
+					fractional_vault.buyout_proceeds.is_none(),
+					Error::<T>::BuyoutAlreadySettled
+				);
+				let buyout = fractional_vault.buyout.clone().ok_or(Error::<T>::BuyoutNotEnded)?;
+				ensure!(
+					<frame_system::Pallet<T>>::block_number() >= buyout.end,
+					Error::<T>::BuyoutNotEnded
+				);
+
+				T::NFTHandler::transfer_nft(&fractional_vault.vault, &buyout.bidder, &asset_id)?;
+				fractional_vault.buyout_proceeds = Some(buyout.bid);
+
+				Self::deposit_event(Event::<T>::BuyoutSettled(asset_id, buyout.bidder, buyout.bid));
+				Ok(().into())
+			})
+		}
+
+		/// Burn all fractions of the caller for its share of the settled buyout
+		#[pallet::weight(100_000)]
+		#[transactional]
+		pub fn claim_buyout_proceeds(origin: OriginFor<T>, asset_id: (ClassId, TokenId)) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut fractional_vault =
+				Self::get_fractional_vault(&asset_id).ok_or(Error::<T>::FractionalVaultNotFound)?;
+			let proceeds = fractional_vault.buyout_proceeds.ok_or(Error::<T>::BuyoutNotSettled)?;
+
+			let fractions = T::MetaverseMultiCurrency::free_balance(fractional_vault.currency_id, &who);
+			ensure!(!fractions.is_zero(), Error::<T>::BalanceZero);
+			let total_issuance = T::MetaverseMultiCurrency::total_issuance(fractional_vault.currency_id);
+			let share =
+				multiply_by_rational(proceeds, fractions, total_issuance).map_err(|_| Error::<T>::NumOverflow)?;
+
+			T::MetaverseMultiCurrency::withdraw(fractional_vault.currency_id, &who, fractions)?;
+			T::MetaverseMultiCurrency::transfer(FungibleTokenId::NativeToken(0), &fractional_vault.vault, &who, share)?;
+
+			if fractions == total_issuance {
+				FractionalVaults::<T>::remove(&asset_id);
+				FungibleTokens::<T>::remove(fractional_vault.currency_id);
+			} else {
+				fractional_vault.buyout_proceeds = Some(proceeds.saturating_sub(share));
+				FractionalVaults::<T>::insert(&asset_id, fractional_vault);
+			}
+
+			Self::deposit_event(Event::<T>::BuyoutProceedsClaimed(asset_id, who, fractions, share));
+			Ok(().into())
+		}
 	}
 
 	#[pallet::event]
@@ -275,6 +529,16 @@ pub mod pallet {
 		Claimed(FungibleTokenId, T::AccountId, Balance),
 		/// Updated vesting schedules. [token, who]
 		VestingSchedulesUpdated(FungibleTokenId, T::AccountId),
+		/// NFT is locked in its vault and split into fractions. [asset_id, owner, token, total_supply]
+		NftFractionalized((ClassId, TokenId), T::AccountId, FungibleTokenId, Balance),
+		/// Fractionalized NFT is redeemed by burning the whole supply. [asset_id, who]
+		FractionalizedNftRedeemed((ClassId, TokenId), T::AccountId),
+		/// Buyout bid is placed on a fractionalized NFT. [asset_id, bidder, bid, end]
+		BuyoutBidPlaced((ClassId, TokenId), T::AccountId, Balance, T::BlockNumber),
+		/// Buyout is settled and the NFT is handed to the winner. [asset_id, winner, bid]
+		BuyoutSettled((ClassId, TokenId), T::AccountId, Balance),
+		/// Fraction holder claimed its share of the buyout. [asset_id, who, burned_fractions, share]
+		BuyoutProceedsClaimed((ClassId, TokenId), T::AccountId, Balance, Balance),
 	}
 
 	#[pallet::hooks]
@@ -300,16 +564,7 @@ impl<T: Config> Pallet<T> {
 		// Remaining balance for metaverse owner
 		let owner_supply = total_supply.saturating_sub(initial_pool_supply);
 		// Generate new TokenId
-		let currency_id = NextTokenId::<T>::mutate(|id| -> Result<FungibleTokenId, DispatchError> {
-			let current_id = *id;
-			if current_id == 0 {
-				*id = 2;
-				Ok(FungibleTokenId::FungibleToken(One::one()))
-			} else {
-				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableTokenId)?;
-				Ok(FungibleTokenId::FungibleToken(current_id))
-			}
-		})?;
+		let currency_id = Self::next_fungible_token_id()?;
 		let fund_id: T::AccountId = T::FungibleTokenTreasury::get().into_sub_account(metaverse_id);
 
 		// Metaverse Network treasury
@@ -376,6 +631,19 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn next_fungible_token_id() -> Result<FungibleTokenId, DispatchError> {
+		NextTokenId::<T>::mutate(|id| -> Result<FungibleTokenId, DispatchError> {
+			let current_id = *id;
+			if current_id == 0 {
+				*id = 2;
+				Ok(FungibleTokenId::FungibleToken(One::one()))
+			} else {
+				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableTokenId)?;
+				Ok(FungibleTokenId::FungibleToken(current_id))
+			}
+		})
+	}
+
 	fn transfer_from(
 		currency_id: FungibleTokenId,
 		from: &T::AccountId,
@@ -412,6 +680,10 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	pub fn get_fractional_vault_id(asset_id: (ClassId, TokenId)) -> T::AccountId {
+		T::FungibleTokenTreasury::get().into_sub_account(asset_id)
+	}
+
 	fn do_claim(who: &T::AccountId, currency_id: FungibleTokenId) -> Balance {
 		let locked = Self::locked_balance(who, currency_id.clone());
 		if locked.is_zero() {
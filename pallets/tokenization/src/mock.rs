@@ -12,7 +12,9 @@ use sp_runtime::{
 };
 
 use primitives::FungibleTokenId::FungibleToken;
-use primitives::{Amount, CurrencyId, FungibleTokenId};
+use primitives::{
+	Amount, Attributes, ClassId, CurrencyId, FungibleTokenId, GroupCollectionId, NftMetadata, TokenId, ESTATE_CLASS_ID,
+};
 
 use crate as tokenization;
 use crate::{Config, Module};
@@ -27,10 +29,15 @@ pub type BlockNumber = u64;
 
 pub const ALICE: AccountId = 4;
 pub const BOB: AccountId = 5;
+pub const CHARLIE: AccountId = 6;
 pub const METAVERSE_ID: MetaverseId = 1;
 pub const METAVERSE_ID_NOT_EXIST: MetaverseId = 1;
 pub const NUUM: CurrencyId = 0;
 pub const METAVERSE_FUND: FungibleTokenId = FungibleTokenId::FungibleToken(1);
+pub const NFT_ASSET_ID: (ClassId, TokenId) = (0, 0);
+pub const ESTATE_ASSET_ID: (ClassId, TokenId) = (ESTATE_CLASS_ID, 1);
+pub const BOUND_CLASS_ID: ClassId = 1;
+pub const BOUND_ASSET_ID: (ClassId, TokenId) = (BOUND_CLASS_ID, 0);
 
 ord_parameter_types! {
 	pub const One: AccountId = ALICE;
@@ -134,6 +141,10 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 	fn update_metaverse_token(metaverse_id: u64, currency_id: FungibleTokenId) -> Result<(), DispatchError> {
 		Ok(())
 	}
+
+	fn get_land_connectivity(_metaverse_id: u64) -> primitives::estate::LandConnectivity {
+		primitives::estate::LandConnectivity::FourConnected
+	}
 }
 
 pub struct DEXManager {}
@@ -174,8 +185,96 @@ impl currencies::Config for Runtime {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 }
 
+pub struct MockNFTHandler;
+
+impl NFTTrait<AccountId, Balance> for MockNFTHandler {
+	type TokenId = TokenId;
+	type ClassId = ClassId;
+
+	fn check_ownership(who: &AccountId, asset_id: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
+		Ok(*who == ALICE)
+	}
+
+	fn check_nft_ownership(who: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
+		Ok(*who == ALICE)
+	}
+
+	fn get_nft_detail(
+		asset_id: (Self::ClassId, Self::TokenId),
+	) -> Result<NftClassData<Balance, AccountId>, DispatchError> {
+		Ok(NftClassData {
+			deposit: 0,
+			attributes: Attributes::new(),
+			token_type: TokenType::Transferable,
+			collection_type: CollectionType::Collectable,
+			royalty_fee: Perbill::from_percent(0),
+			royalty_recipient: ALICE,
+		})
+	}
+
+	fn get_nft_group_collection(nft_collection: &Self::ClassId) -> Result<GroupCollectionId, DispatchError> {
+		Ok(0)
+	}
+
+	fn check_collection_and_class(
+		collection_id: GroupCollectionId,
+		class_id: Self::ClassId,
+	) -> Result<bool, DispatchError> {
+		Ok(true)
+	}
+
+	fn mint_land_nft(
+		account: AccountId,
+		metadata: NftMetadata,
+		attributes: Attributes,
+	) -> Result<TokenId, DispatchError> {
+		Ok(1)
+	}
+
+	fn mint_estate_nft(
+		account: AccountId,
+		metadata: NftMetadata,
+		attributes: Attributes,
+	) -> Result<TokenId, DispatchError> {
+		Ok(1)
+	}
+
+	fn burn_nft(account: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult {
+		Ok(())
+	}
+
+	fn check_item_on_listing(class_id: Self::ClassId, token_id: Self::TokenId) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn transfer_nft(sender: &AccountId, to: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult {
+		Ok(())
+	}
+
+	fn is_transferable(nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
+		Ok(nft.0 != BOUND_CLASS_ID)
+	}
+
+	fn get_class_fund(class_id: &Self::ClassId) -> AccountId {
+		ALICE
+	}
+
+	fn get_user_of(nft: &(Self::ClassId, Self::TokenId)) -> Result<AccountId, DispatchError> {
+		Ok(ALICE)
+	}
+
+	fn get_nft_owner(nft: &(Self::ClassId, Self::TokenId)) -> Result<AccountId, DispatchError> {
+		Ok(ALICE)
+	}
+
+	fn get_royalty_info(class_id: &Self::ClassId) -> Result<(Perbill, AccountId), DispatchError> {
+		Ok((Perbill::from_percent(0), ALICE))
+	}
+}
+
 parameter_types! {
 	pub const MinVestedTransfer: Balance = 100;
+	pub const BuyoutPeriod: BlockNumber = 10;
 }
 
 impl Config for Runtime {
@@ -187,6 +286,8 @@ impl Config for Runtime {
 	type LiquidityPoolManager = SwapModule;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureSignedBy<One, AccountId>;
+	type NFTHandler = MockNFTHandler;
+	type BuyoutPeriod = BuyoutPeriod;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -222,7 +323,7 @@ impl ExtBuilder {
 			.unwrap();
 
 		pallet_balances::GenesisConfig::<Runtime> {
-			balances: vec![(ALICE, 100000)],
+			balances: vec![(ALICE, 100000), (BOB, 100000), (CHARLIE, 100000)],
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
@@ -166,3 +166,196 @@ fn metaverse_treasury_pool_transfer_should_work() {
 		assert_eq!(Currencies::free_balance(METAVERSE_FUND, &ALICE), 380); // 120 has been vested
 	});
 }
+
+fn native_balance(who: AccountId) -> Balance {
+	Currencies::free_balance(FungibleTokenId::NativeToken(0), &who)
+}
+
+#[test]
+fn fractionalize_nft_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokenizationModule::fractionalize_nft(
+			Origin::signed(ALICE),
+			NFT_ASSET_ID,
+			vec![1],
+			1000,
+			Some(500)
+		));
+
+		let fraction_token = FungibleTokenId::FungibleToken(1);
+		assert_eq!(Currencies::free_balance(fraction_token, &ALICE), 1000);
+		assert_eq!(
+			TokenizationModule::get_fractional_vault(NFT_ASSET_ID),
+			Some(FractionalVault {
+				curator: ALICE,
+				vault: TokenizationModule::get_fractional_vault_id(NFT_ASSET_ID),
+				currency_id: fraction_token,
+				buyout_reserve_price: Some(500),
+				buyout: None,
+				buyout_proceeds: None,
+			})
+		);
+
+		let event = mock::Event::TokenizationModule(crate::Event::NftFractionalized(
+			NFT_ASSET_ID,
+			ALICE,
+			fraction_token,
+			1000,
+		));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn fractionalize_nft_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TokenizationModule::fractionalize_nft(Origin::signed(BOB), NFT_ASSET_ID, vec![1], 1000, None),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			TokenizationModule::fractionalize_nft(Origin::signed(ALICE), BOUND_ASSET_ID, vec![1], 1000, None),
+			Error::<Runtime>::NftIsNotTransferable
+		);
+		assert_noop!(
+			TokenizationModule::fractionalize_nft(Origin::signed(ALICE), NFT_ASSET_ID, vec![1], 0, None),
+			Error::<Runtime>::AmountZero
+		);
+
+		assert_ok!(TokenizationModule::fractionalize_nft(
+			Origin::signed(ALICE),
+			NFT_ASSET_ID,
+			vec![1],
+			1000,
+			None
+		));
+		assert_noop!(
+			TokenizationModule::fractionalize_nft(Origin::signed(ALICE), NFT_ASSET_ID, vec![1], 1000, None),
+			Error::<Runtime>::NftAlreadyFractionalized
+		);
+	});
+}
+
+#[test]
+fn redeem_fractionalized_estate_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokenizationModule::fractionalize_nft(
+			Origin::signed(ALICE),
+			ESTATE_ASSET_ID,
+			vec![1],
+			1000,
+			None
+		));
+
+		let fraction_token = FungibleTokenId::FungibleToken(1);
+		assert_ok!(TokenizationModule::transfer(
+			Origin::signed(ALICE),
+			BOB,
+			fraction_token,
+			100
+		));
+		assert_noop!(
+			TokenizationModule::redeem_nft(Origin::signed(ALICE), ESTATE_ASSET_ID),
+			Error::<Runtime>::InsufficientFractionsToRedeem
+		);
+
+		// BOB buys out the co-owners and redeems the estate
+		assert_ok!(TokenizationModule::transfer(
+			Origin::signed(ALICE),
+			BOB,
+			fraction_token,
+			900
+		));
+		assert_ok!(TokenizationModule::redeem_nft(Origin::signed(BOB), ESTATE_ASSET_ID));
+
+		assert_eq!(Currencies::total_issuance(fraction_token), 0);
+		assert_eq!(TokenizationModule::get_fractional_vault(ESTATE_ASSET_ID), None);
+
+		let event = mock::Event::TokenizationModule(crate::Event::FractionalizedNftRedeemed(ESTATE_ASSET_ID, BOB));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn buyout_fractionalized_nft_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokenizationModule::fractionalize_nft(
+			Origin::signed(ALICE),
+			NFT_ASSET_ID,
+			vec![1],
+			1000,
+			Some(500)
+		));
+
+		let fraction_token = FungibleTokenId::FungibleToken(1);
+		assert_ok!(TokenizationModule::transfer(
+			Origin::signed(ALICE),
+			BOB,
+			fraction_token,
+			250
+		));
+
+		assert_noop!(
+			TokenizationModule::bid_buyout(Origin::signed(CHARLIE), NFT_ASSET_ID, 400),
+			Error::<Runtime>::BuyoutBidTooLow
+		);
+		assert_ok!(TokenizationModule::bid_buyout(
+			Origin::signed(CHARLIE),
+			NFT_ASSET_ID,
+			500
+		));
+		assert_noop!(
+			TokenizationModule::bid_buyout(Origin::signed(BOB), NFT_ASSET_ID, 500),
+			Error::<Runtime>::BuyoutBidTooLow
+		);
+
+		// higher bid refunds the previous bidder
+		assert_ok!(TokenizationModule::bid_buyout(Origin::signed(BOB), NFT_ASSET_ID, 600));
+		assert_eq!(native_balance(CHARLIE), 100000);
+		assert_eq!(
+			native_balance(TokenizationModule::get_fractional_vault_id(NFT_ASSET_ID)),
+			600
+		);
+		let event = mock::Event::TokenizationModule(crate::Event::BuyoutBidPlaced(NFT_ASSET_ID, BOB, 600, 11));
+		assert_eq!(last_event(), event);
+
+		assert_noop!(
+			TokenizationModule::redeem_nft(Origin::signed(ALICE), NFT_ASSET_ID),
+			Error::<Runtime>::BuyoutInProgress
+		);
+		assert_noop!(
+			TokenizationModule::settle_buyout(Origin::signed(ALICE), NFT_ASSET_ID),
+			Error::<Runtime>::BuyoutNotEnded
+		);
+		assert_noop!(
+			TokenizationModule::claim_buyout_proceeds(Origin::signed(ALICE), NFT_ASSET_ID),
+			Error::<Runtime>::BuyoutNotSettled
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			TokenizationModule::bid_buyout(Origin::signed(CHARLIE), NFT_ASSET_ID, 700),
+			Error::<Runtime>::BuyoutHasEnded
+		);
+		assert_ok!(TokenizationModule::settle_buyout(Origin::signed(CHARLIE), NFT_ASSET_ID));
+		let event = mock::Event::TokenizationModule(crate::Event::BuyoutSettled(NFT_ASSET_ID, BOB, 600));
+		assert_eq!(last_event(), event);
+
+		// fraction holders claim their share of the winning bid
+		assert_ok!(TokenizationModule::claim_buyout_proceeds(
+			Origin::signed(BOB),
+			NFT_ASSET_ID
+		));
+		assert_eq!(native_balance(BOB), 100000 - 600 + 150);
+		assert_eq!(Currencies::free_balance(fraction_token, &BOB), 0);
+
+		let alice_balance = native_balance(ALICE);
+		assert_ok!(TokenizationModule::claim_buyout_proceeds(
+			Origin::signed(ALICE),
+			NFT_ASSET_ID
+		));
+		assert_eq!(native_balance(ALICE), alice_balance + 450);
+		assert_eq!(TokenizationModule::get_fractional_vault(NFT_ASSET_ID), None);
+		assert_eq!(Currencies::total_issuance(fraction_token), 0);
+	});
+}
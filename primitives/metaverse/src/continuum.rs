@@ -4,4 +4,6 @@ use sp_runtime::DispatchError;
 pub trait Continuum<AccountId> {
 	fn transfer_spot(spot_id: SpotId, from: &AccountId, to: &(AccountId, MetaverseId))
 		-> Result<SpotId, DispatchError>;
+
+	fn check_spot_owner(spot_id: SpotId, who: &AccountId) -> Result<bool, DispatchError>;
 }
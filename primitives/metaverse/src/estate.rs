@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use sp_runtime::DispatchError;
 use sp_runtime::{Perbill, RuntimeDebug};
 
-use crate::{EstateId, MetaverseId, TokenId};
+use crate::{EstateId, FungibleTokenId, MetaverseId, RoundIndex, TokenId};
 
 pub trait Estate<AccountId> {
 	fn transfer_estate(estate_id: EstateId, from: &AccountId, to: &AccountId) -> Result<EstateId, DispatchError>;
@@ -16,13 +16,41 @@ pub trait Estate<AccountId> {
 		to: &(AccountId, MetaverseId),
 	) -> Result<(i32, i32), DispatchError>;
 
+	/// Transfer an estate sold outside of an auction, e.g. through an accepted offer
+	fn transfer_estate_by_offer(
+		estate_id: EstateId,
+		from: &AccountId,
+		to: &AccountId,
+	) -> Result<EstateId, DispatchError>;
+
+	/// Transfer a land unit sold outside of an auction, e.g. through an accepted offer
+	fn transfer_landunit_by_offer(
+		coordinate: (i32, i32),
+		from: &AccountId,
+		to: &(AccountId, MetaverseId),
+	) -> Result<(i32, i32), DispatchError>;
+
 	fn check_estate(estate_id: EstateId) -> Result<bool, DispatchError>;
 
 	fn check_landunit(metaverse_id: MetaverseId, coordinate: (i32, i32)) -> Result<bool, DispatchError>;
 
+	fn check_estate_ownership(owner: &AccountId, estate_id: EstateId) -> Result<bool, DispatchError>;
+
+	fn check_landunit_ownership(
+		owner: &AccountId,
+		metaverse_id: MetaverseId,
+		coordinate: (i32, i32),
+	) -> Result<bool, DispatchError>;
+
 	fn get_total_land_units() -> u64;
 
 	fn get_total_undeploy_land_units() -> u64;
+
+	/// Whether the estate is leased or offered for lease
+	fn is_estate_leased(estate_id: EstateId) -> bool;
+
+	/// Whether the land unit is leased or offered for lease
+	fn is_landunit_leased(metaverse_id: MetaverseId, coordinate: (i32, i32)) -> bool;
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -34,6 +62,22 @@ pub struct EstateInfo {
 	pub land_units: Vec<(i32, i32)>,
 }
 
+/// Which neighbouring land units count as connected when validating estate shape
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum LandConnectivity {
+	/// Land units sharing an edge
+	FourConnected,
+	/// Land units sharing an edge or a corner
+	EightConnected,
+}
+
+impl Default for LandConnectivity {
+	fn default() -> Self {
+		LandConnectivity::FourConnected
+	}
+}
+
 #[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum OwnerId<AccountId, TokenId> {
 	Account(AccountId),
@@ -45,3 +89,79 @@ pub enum LandUnitStatus<AccountId> {
 	NonExisting,
 	Existing(AccountId),
 }
+
+/// Estate or land unit that can be leased
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum LeaseAsset {
+	Estate(EstateId),
+	LandUnit(MetaverseId, (i32, i32)),
+}
+
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LeaseOffer<AccountId, Balance> {
+	/// Owner receiving the rent
+	pub lessor: AccountId,
+	/// Native token or the social token of the metaverse
+	pub currency_id: FungibleTokenId,
+	/// Rent paid by the tenant each round
+	pub rent_per_round: Balance,
+	/// Number of rounds the lease lasts
+	pub duration: RoundIndex,
+}
+
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LeaseInfo<AccountId, Balance> {
+	/// Owner receiving the rent
+	pub lessor: AccountId,
+	/// Tenant holding the builder rights
+	pub tenant: AccountId,
+	/// Native token or the social token of the metaverse
+	pub currency_id: FungibleTokenId,
+	/// Rent paid by the tenant each round
+	pub rent_per_round: Balance,
+	/// Round at which the lease ends
+	pub end_round: RoundIndex,
+	/// Consecutive rounds the rent could not be collected
+	pub missed_payments: u32,
+	/// Last round the rent was collected for
+	pub collected_round: RoundIndex,
+}
+
+/// Land area given by its (min x, min y) and (max x, max y) corners, both inclusive
+pub type LandArea = ((i32, i32), (i32, i32));
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+pub struct LandBounds {
+	/// Inclusive (min, max) range of the x axis
+	pub x: (i32, i32),
+	/// Inclusive (min, max) range of the y axis
+	pub y: (i32, i32),
+	/// Areas inside the bounds that can never be minted, e.g. roads and plazas
+	pub exclusion_zones: Vec<LandArea>,
+}
+
+impl LandBounds {
+	/// Whether every range and exclusion zone has its min not greater than its max
+	pub fn is_valid(&self) -> bool {
+		self.x.0 <= self.x.1
+			&& self.y.0 <= self.y.1
+			&& self
+				.exclusion_zones
+				.iter()
+				.all(|(min, max)| min.0 <= max.0 && min.1 <= max.1)
+	}
+
+	/// Whether the coordinate lies within the x and y ranges
+	pub fn contains(&self, coordinate: (i32, i32)) -> bool {
+		self.x.0 <= coordinate.0 && coordinate.0 <= self.x.1 && self.y.0 <= coordinate.1 && coordinate.1 <= self.y.1
+	}
+
+	/// Whether the coordinate lies inside any exclusion zone
+	pub fn is_excluded(&self, coordinate: (i32, i32)) -> bool {
+		self.exclusion_zones.iter().any(|(min, max)| {
+			min.0 <= coordinate.0 && coordinate.0 <= max.0 && min.1 <= coordinate.1 && coordinate.1 <= max.1
+		})
+	}
+}
@@ -100,6 +100,8 @@ pub type ReferendumId = u64;
 pub type LandId = u64;
 /// EstateId
 pub type EstateId = u64;
+/// BundleId
+pub type BundleId = u64;
 /// Social Token Id type
 pub type TokenId = u64;
 /// Undeployed LandBlock Id type
@@ -135,6 +137,7 @@ pub enum ItemId {
 	Block(u64),
 	Estate(EstateId),
 	LandUnit((i32, i32), MetaverseId),
+	Bundle(BundleId),
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, MaxEncodedLen, PartialOrd, Ord, TypeInfo)]
@@ -450,11 +450,22 @@ impl currencies::Config for Runtime {
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 }
 
+/// Calls executable NFTs may dispatch with the holder's origin. Only calls that can not move
+/// the holder's assets or wrap other calls are allowed, e.g. remarks used as redeemable tickets.
+pub struct ExecutableNftCallFilter;
+
+impl Contains<Call> for ExecutableNftCallFilter {
+	fn contains(c: &Call) -> bool {
+		matches!(c, Call::System(frame_system::Call::remark_with_event { .. }))
+	}
+}
+
 parameter_types! {
 	pub MetadataDepositPerByte: Balance = 1 * CENTS;
 	pub MaxBatchTransfer: u32 = 100;
 	pub MaxBatchMinting: u32 = 1000;
 	pub MaxNftMetadata: u32 = 1024;
+	pub MaxExecutableCallWeight: Weight = 500_000_000; // Max weight of the call an executable NFT dispatches
 }
 
 impl nft::Config for Runtime {
@@ -469,6 +480,9 @@ impl nft::Config for Runtime {
 	type MaxMetadata = MaxNftMetadata;
 	type MiningResourceId = MiningResourceCurrencyId;
 	type DataDepositPerByte = MetadataDepositPerByte;
+	type Call = Call;
+	type ExecutableCallFilter = ExecutableNftCallFilter;
+	type MaxExecutableCallWeight = MaxExecutableCallWeight;
 }
 
 parameter_types! {
@@ -479,7 +493,7 @@ parameter_types! {
 impl orml_nft::Config for Runtime {
 	type ClassId = ClassId;
 	type TokenId = NftId;
-	type ClassData = NftClassData<Balance>;
+	type ClassData = NftClassData<Balance, AccountId>;
 	type TokenData = NftAssetData<Balance>;
 	type MaxClassMetadata = MaxClassMetadata;
 	type MaxTokenMetadata = MaxTokenMetadata;
@@ -511,6 +525,11 @@ parameter_types! {
 	pub const MinBlocksPerLandIssuanceRound: u32 = 20;
 	pub const MinimumStake: Balance = 100 * DOLLARS;
 	pub const RewardPaymentDelay: u32 = 1;
+	pub const MaxExclusionZones: u32 = 10;
+	pub const MaxMissedRentPayments: u32 = 3;
+	pub const MaxLeases: u32 = 2000;
+	pub const MaxRentCollectionsPerBlock: u32 = 100;
+	pub const MaxEstateCommission: Perbill = Perbill::from_percent(50);
 }
 
 impl estate::Config for Runtime {
@@ -526,6 +545,12 @@ impl estate::Config for Runtime {
 	type MinimumStake = MinimumStake;
 	type RewardPaymentDelay = RewardPaymentDelay;
 	type NFTTokenizationSource = Nft;
+	type MaxExclusionZones = MaxExclusionZones;
+	type MultiCurrency = Currencies;
+	type MaxMissedRentPayments = MaxMissedRentPayments;
+	type MaxLeases = MaxLeases;
+	type MaxRentCollectionsPerBlock = MaxRentCollectionsPerBlock;
+	type MaxEstateCommission = MaxEstateCommission;
 }
 
 parameter_types! {
@@ -533,8 +558,14 @@ parameter_types! {
 	pub const ContinuumSessionDuration: BlockNumber = 100; // Default 43200 Blocks
 	pub const SpotAuctionChillingDuration: BlockNumber = 100; // Default 43200 Blocks
 	pub const MinimumAuctionDuration: BlockNumber = 30; // Minimum duration is 300 blocks
-	pub const RoyaltyFee: u16 = 10; // Loyalty fee 0.1%
 	pub const MaxFinality: u32 = 100; // Maximum finalize auctions per block
+	pub const AntiSnipeDuration: BlockNumber = 10; // Bids within 10 blocks before the end extend the auction
+	pub const SealedBidDeposit: Balance = 1 * DOLLARS; // Deposit per sealed bid, slashed if never revealed
+	pub const SealedBidRevealDuration: BlockNumber = 100; // Reveal phase after sealed bidding ends
+	pub const MaxSealedBids: u32 = 100; // Maximum sealed bids per auction
+	pub const MaxBundleItems: u32 = 20; // Maximum items per bundle listing
+	pub const SettlementTimeout: BlockNumber = 7 * DAYS; // Retry window of a failed settlement
+	pub const MaxOffersPerBlock: u32 = 100; // Maximum offers expiring in the same block
 }
 
 impl auction::Config for Runtime {
@@ -543,13 +574,20 @@ impl auction::Config for Runtime {
 	type Handler = Auction;
 	type Currency = Balances;
 	type ContinuumHandler = Continuum;
-	type FungibleTokenCurrency = Tokens;
+	type FungibleTokenCurrency = Currencies;
 	type MetaverseInfoSource = Metaverse;
 	type MinimumAuctionDuration = MinimumAuctionDuration;
 	type EstateHandler = Estate;
-	type RoyaltyFee = RoyaltyFee;
 	type MaxFinality = MaxFinality;
 	type NFTHandler = Nft;
+	type AntiSnipeDuration = AntiSnipeDuration;
+	type SealedBidDeposit = SealedBidDeposit;
+	type SealedBidRevealDuration = SealedBidRevealDuration;
+	type MaxSealedBids = MaxSealedBids;
+	type MaxBundleItems = MaxBundleItems;
+	type SettlementTimeout = SettlementTimeout;
+	type MaxOffersPerBlock = MaxOffersPerBlock;
+	type WeightInfo = weights::module_auction::WeightInfo<Runtime>;
 }
 
 impl continuum::Config for Runtime {
@@ -564,6 +602,10 @@ impl continuum::Config for Runtime {
 	type MetaverseInfoSource = Metaverse;
 }
 
+parameter_types! {
+	pub const FractionalBuyoutPeriod: BlockNumber = 7 * DAYS;
+}
+
 impl tokenization::Config for Runtime {
 	type Event = Event;
 	type TokenId = u64;
@@ -573,6 +615,8 @@ impl tokenization::Config for Runtime {
 	type LiquidityPoolManager = Swap;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureRootOrMetaverseTreasury;
+	type NFTHandler = Nft;
+	type BuyoutPeriod = FractionalBuyoutPeriod;
 }
 
 parameter_types! {
@@ -1062,7 +1106,7 @@ construct_runtime!(
 		Swap: swap:: {Pallet, Call, Storage ,Event<T>},
 		Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>, Config<T>},
 		Mining: mining:: {Pallet, Call, Storage ,Event<T>},
-		Estate: estate::{Pallet, Call, Storage, Event<T>, Config},
+		Estate: estate::{Pallet, Call, Storage, Event<T>, Config<T>},
 		Economy: economy::{Pallet, Call, Storage, Event<T>},
 		Emergency: emergency::{Pallet, Call, Storage, Event<T>},
 		RewardOracle: orml_oracle::<Instance1>::{Pallet, Storage, Call, Event<T>},
@@ -1452,6 +1496,26 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl auction_manager::AuctionApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn active_auctions(
+			listing_level: Option<auction_manager::ListingLevel<AccountId>>,
+		) -> Vec<(primitives::AuctionId, auction_manager::AuctionItem<AccountId, BlockNumber, Balance>)> {
+			Auction::active_auctions(listing_level)
+		}
+
+		fn highest_bid(auction_id: primitives::AuctionId) -> Option<(AccountId, Balance)> {
+			Auction::highest_bid(auction_id)
+		}
+
+		fn auctions_bid_by(who: AccountId) -> Vec<primitives::AuctionId> {
+			Auction::auctions_bid_by(who)
+		}
+
+		fn is_item_listed(item_id: primitives::ItemId) -> bool {
+			<Auction as auction_manager::CheckAuctionItemHandler>::check_item_in_auction(item_id)
+		}
+	}
+
 	impl pallet_contracts_rpc_runtime_api::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash> for Runtime
 	{
 		fn call(
@@ -86,4 +86,9 @@ impl<T: frame_system::Config> metaverse::WeightInfo for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(7 as Weight))
             .saturating_add(T::DbWeight::get().writes(5 as Weight))
     }
+    fn set_land_connectivity() -> Weight {
+        (14_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
 }
@@ -1064,7 +1064,7 @@ parameter_types! {
 impl orml_nft::Config for Runtime {
 	type ClassId = ClassId;
 	type TokenId = NftId;
-	type ClassData = nft::NftClassData<Balance>;
+	type ClassData = nft::NftClassData<Balance, AccountId>;
 	type TokenData = nft::NftAssetData<Balance>;
 	type MaxClassMetadata = MaxClassMetadata;
 	type MaxTokenMetadata = MaxTokenMetadata;
@@ -1097,6 +1097,11 @@ parameter_types! {
 	pub const MinimumStake: Balance = 5 * DOLLARS;
 	/// Reward payments are delayed by 2 hours (2 * 300 * block_time)
 	pub const RewardPaymentDelay: u32 = 2;
+	pub const MaxExclusionZones: u32 = 10;
+	pub const MaxMissedRentPayments: u32 = 3;
+	pub const MaxLeases: u32 = 2000;
+	pub const MaxRentCollectionsPerBlock: u32 = 100;
+	pub const MaxEstateCommission: Perbill = Perbill::from_percent(50);
 }
 
 impl estate::Config for Runtime {
@@ -1112,6 +1117,12 @@ impl estate::Config for Runtime {
 	type MinimumStake = MinimumStake;
 	type RewardPaymentDelay = RewardPaymentDelay;
 	type NFTTokenizationSource = Nft;
+	type MaxExclusionZones = MaxExclusionZones;
+	type MultiCurrency = Currencies;
+	type MaxMissedRentPayments = MaxMissedRentPayments;
+	type MaxLeases = MaxLeases;
+	type MaxRentCollectionsPerBlock = MaxRentCollectionsPerBlock;
+	type MaxEstateCommission = MaxEstateCommission;
 }
 
 parameter_types! {
@@ -1119,7 +1130,6 @@ parameter_types! {
 	pub const ContinuumSessionDuration: BlockNumber = 43200; // Default 43200 Blocks
 	pub const SpotAuctionChillingDuration: BlockNumber = 43200; // Default 43200 Blocks
 	pub const MinimumAuctionDuration: BlockNumber = 300; // Minimum duration is 300 blocks
-	pub const RoyaltyFee: u16 = 10; // Loyalty fee 0.1%
 }
 
 impl auction::Config for Runtime {
@@ -1132,7 +1142,6 @@ impl auction::Config for Runtime {
 	type MetaverseInfoSource = Metaverse;
 	type MinimumAuctionDuration = MinimumAuctionDuration;
 	type EstateHandler = Estate;
-	type RoyaltyFee = RoyaltyFee;
 }
 
 impl continuum::Config for Runtime {
@@ -1147,6 +1156,10 @@ impl continuum::Config for Runtime {
 	type MetaverseInfoSource = Metaverse;
 }
 
+parameter_types! {
+	pub const FractionalBuyoutPeriod: BlockNumber = 7 * DAYS;
+}
+
 impl tokenization::Config for Runtime {
 	type Event = Event;
 	type TokenId = u64;
@@ -1156,6 +1169,8 @@ impl tokenization::Config for Runtime {
 	type LiquidityPoolManager = Swap;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureRootOrMetaverseTreasury;
+	type NFTHandler = Nft;
+	type BuyoutPeriod = FractionalBuyoutPeriod;
 }
 
 parameter_types! {
@@ -1381,7 +1396,7 @@ construct_runtime!(
 		Swap: swap:: {Pallet, Call, Storage ,Event<T>},
 		Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>, Config<T>},
 		Mining: mining:: {Pallet, Call, Storage ,Event<T>},
-		Estate: estate::{Pallet, Call, Storage, Event<T>, Config},
+		Estate: estate::{Pallet, Call, Storage, Event<T>, Config<T>},
 
 		// Governance
 		Council: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
@@ -1607,6 +1622,26 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl auction_manager::AuctionApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn active_auctions(
+			listing_level: Option<auction_manager::ListingLevel<AccountId>>,
+		) -> Vec<(primitives::AuctionId, auction_manager::AuctionItem<AccountId, BlockNumber, Balance>)> {
+			Auction::active_auctions(listing_level)
+		}
+
+		fn highest_bid(auction_id: primitives::AuctionId) -> Option<(AccountId, Balance)> {
+			Auction::highest_bid(auction_id)
+		}
+
+		fn auctions_bid_by(who: AccountId) -> Vec<primitives::AuctionId> {
+			Auction::auctions_bid_by(who)
+		}
+
+		fn is_item_listed(item_id: primitives::ItemId) -> bool {
+			<Auction as auction_manager::CheckAuctionItemHandler>::check_item_in_auction(item_id)
+		}
+	}
+
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -3,7 +3,7 @@
 // Ref: https://github.com/open-web3-stack/open-runtime-module-library
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::FullCodec;
+use codec::{Codec, FullCodec};
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResult;
 use scale_info::TypeInfo;
@@ -31,6 +31,10 @@ pub enum Change<Value> {
 pub enum AuctionType {
 	Auction,
 	BuyNow,
+	/// Descending price listing, the first buyer pays the current price
+	Dutch,
+	/// Commit-reveal listing, the highest revealed bid wins at the second highest price
+	SealedBid,
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
@@ -43,6 +47,7 @@ pub enum ListingLevel<AccountId> {
 }
 
 #[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
 pub struct AuctionItem<AccountId, BlockNumber, Balance> {
 	pub item_id: ItemId,
@@ -58,6 +63,41 @@ pub struct AuctionItem<AccountId, BlockNumber, Balance> {
 	pub currency_id: FungibleTokenId,
 }
 
+/// Price schedule of a Dutch auction. The price starts at the item initial amount and falls to
+/// `floor_price` by the auction end, dropping every `price_step` blocks.
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct DutchAuctionConfig<Balance, BlockNumber> {
+	/// Lowest price of the listing, reached at the auction end
+	pub floor_price: Balance,
+	/// Number of blocks between price drops
+	pub price_step: BlockNumber,
+}
+
+/// Offer on an item that is not listed.
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct Offer<Balance, BlockNumber> {
+	/// Amount reserved from the offeror
+	pub amount: Balance,
+	/// Currency of the offer
+	pub currency_id: FungibleTokenId,
+	/// Offer expires at this block
+	pub end_block: BlockNumber,
+}
+
+/// Sealed bid of a commit-reveal auction.
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct SealedBid<Hash, Balance> {
+	/// Hash of the bidder, bid amount and salt
+	pub commitment: Hash,
+	/// Deposit reserved on commit, slashed if the bid is never revealed
+	pub deposit: Balance,
+	/// Bid amount once revealed
+	pub revealed: Option<Balance>,
+}
+
 /// Auction info.
 #[cfg_attr(feature = "std", derive(PartialEq, Eq))]
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -96,6 +136,7 @@ pub trait Auction<AccountId, BlockNumber> {
 		initial_amount: Self::Balance,
 		start: BlockNumber,
 		listing_level: ListingLevel<AccountId>,
+		currency_id: FungibleTokenId,
 	) -> Result<AuctionId, DispatchError>;
 
 	/// Remove auction by `id`
@@ -162,3 +203,23 @@ pub trait SwapManager<AccountId, CurrencyId, Balance> {
 		max_amount_b: Balance,
 	) -> DispatchResult;
 }
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to query auctions and listings without decoding raw storage
+	pub trait AuctionApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Active auctions with their item, only the ones at `listing_level` if given
+		fn active_auctions(
+			listing_level: Option<ListingLevel<AccountId>>,
+		) -> Vec<(AuctionId, AuctionItem<AccountId, BlockNumber, Balance>)>;
+		/// Current highest bidder and bid price of an auction
+		fn highest_bid(auction_id: AuctionId) -> Option<(AccountId, Balance)>;
+		/// Active auctions `who` holds the highest bid or a sealed bid on
+		fn auctions_bid_by(who: AccountId) -> Vec<AuctionId>;
+		/// Whether an item is listed, on its own or as part of a bundle
+		fn is_item_listed(item_id: ItemId) -> bool;
+	}
+}
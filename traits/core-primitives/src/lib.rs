@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use sp_runtime::{DispatchError, DispatchResult, Perbill, RuntimeDebug};
 use sp_std::{collections::btree_map::BTreeMap, prelude::*, vec::Vec};
 
+use primitives::estate::LandConnectivity;
 use primitives::staking::RoundInfo;
 use primitives::{
 	AssetId, ClassId, FungibleTokenId, GroupCollectionId, ItemId, MetaverseId, TokenId, UndeployedLandBlockId,
@@ -35,6 +36,21 @@ impl Default for TokenType {
 	}
 }
 
+/// Who can update the attributes of the tokens of a class
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AttributeUpdateAuthority {
+	ClassOwner,
+	TokenOwner,
+	Frozen,
+}
+
+impl Default for AttributeUpdateAuthority {
+	fn default() -> Self {
+		AttributeUpdateAuthority::ClassOwner
+	}
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum CollectionType {
@@ -85,12 +101,16 @@ pub struct NftGroupCollectionData {
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct NftClassData<Balance> {
+pub struct NftClassData<Balance, AccountId> {
 	// Minimum balance to create a collection of Asset
 	pub deposit: Balance,
 	pub attributes: Attributes,
 	pub token_type: TokenType,
 	pub collection_type: CollectionType,
+	// Royalty fee paid by the seller on every sale of the class tokens
+	pub royalty_fee: Perbill,
+	// Account receiving the royalty fee
+	pub royalty_recipient: AccountId,
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
@@ -141,6 +161,8 @@ pub trait MetaverseTrait<AccountId> {
 	fn get_metaverse_token(metaverse_id: MetaverseId) -> Option<FungibleTokenId>;
 	/// Update metaverse token, this only use once per metaverse
 	fn update_metaverse_token(metaverse_id: MetaverseId, currency_id: FungibleTokenId) -> Result<(), DispatchError>;
+	/// Get the rule deciding which land units of an estate count as connected
+	fn get_land_connectivity(metaverse_id: MetaverseId) -> LandConnectivity;
 }
 
 pub trait MetaverseLandTrait<AccountId> {
@@ -148,6 +170,10 @@ pub trait MetaverseLandTrait<AccountId> {
 	fn get_user_land_units(who: &AccountId, metaverse_id: &MetaverseId) -> Vec<(i32, i32)>;
 	/// Check if this user own the metaverse
 	fn is_user_own_metaverse_land(who: &AccountId, metaverse_id: &MetaverseId) -> bool;
+	/// Get land units leased by account, directly or as part of a leased estate
+	fn get_user_leased_land_units(who: &AccountId, metaverse_id: &MetaverseId) -> Vec<(i32, i32)>;
+	/// Get the tenant holding builder rights on the land unit
+	fn get_land_unit_tenant(metaverse_id: &MetaverseId, coordinate: &(i32, i32)) -> Option<AccountId>;
 }
 
 pub trait UndeployedLandBlocksTrait<AccountId> {
@@ -183,7 +209,9 @@ pub trait NFTTrait<AccountId, Balance> {
 	/// Check the ownership of this nft tuple
 	fn check_nft_ownership(who: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError>;
 	/// Get the detail of this nft
-	fn get_nft_detail(asset_id: (Self::ClassId, Self::TokenId)) -> Result<(NftClassData<Balance>), DispatchError>;
+	fn get_nft_detail(
+		asset_id: (Self::ClassId, Self::TokenId),
+	) -> Result<(NftClassData<Balance, AccountId>), DispatchError>;
 	/// Get the detail of this nft
 	fn get_nft_group_collection(nft_collection: &Self::ClassId) -> Result<GroupCollectionId, DispatchError>;
 	/// Check if collection and class exist
@@ -213,6 +241,12 @@ pub trait NFTTrait<AccountId, Balance> {
 	fn is_transferable(nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError>;
 	/// Get collection account fund
 	fn get_class_fund(class_id: &Self::ClassId) -> AccountId;
+	/// Get the user of a token, the active renter if rented, otherwise the owner
+	fn get_user_of(nft: &(Self::ClassId, Self::TokenId)) -> Result<AccountId, DispatchError>;
+	/// Get the owner of a token, even while it is rented
+	fn get_nft_owner(nft: &(Self::ClassId, Self::TokenId)) -> Result<AccountId, DispatchError>;
+	/// Get the royalty fee and royalty recipient of a class
+	fn get_royalty_info(class_id: &Self::ClassId) -> Result<(Perbill, AccountId), DispatchError>;
 }
 
 pub trait RoundTrait<BlockNumber> {